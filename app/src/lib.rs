@@ -1,13 +1,45 @@
+use todo_backups::Backups;
 use todo_clock::Clock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
 use todo_model::TodoList;
+use todo_printing::JsonFormat;
 use todo_text_editing::TextEditor;
 
 pub trait Application {
     type Result<'a>: todo_printing::Printable<'a>;
-    fn run<'a>(
+    fn run<'a, 'ser>(
         self,
-        list: &'a mut TodoList,
+        list: &'a mut TodoList<'ser>,
+        journal: &mut Journal<'ser>,
+        config: &mut ConfigModel,
+        backups: &impl Backups,
         text_editor: &impl TextEditor,
         clock: &impl Clock,
     ) -> Self::Result<'a>;
+
+    /// Whether running the application will never modify the list. Read-only
+    /// applications may run alongside each other; any other application gets
+    /// exclusive access to the list until its changes are saved.
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
+    /// The JSON format to print output in, if the user asked for one instead
+    /// of text.
+    fn output_format(&self) -> Option<JsonFormat> {
+        None
+    }
+
+    /// Whether the user asked to see what the application would do without
+    /// saving its changes.
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+
+    /// Whether the application takes over the whole terminal while it runs,
+    /// so its output must not go through the paginator.
+    fn is_full_screen(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,110 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backup {
+    /// 1 for the most recent backup, 2 for the one before that, etc.
+    pub index: usize,
+    pub modified: DateTime<Utc>,
+}
+
+/// Previous versions of the list, most recent first.
+pub trait Backups {
+    fn list(&self) -> Vec<Backup>;
+    fn read(&self, index: usize) -> std::io::Result<String>;
+}
+
+/// The path of the |index|th most recent backup of the file at |path|, e.g.
+/// "data.json.1" for the version of "data.json" before the last save.
+pub fn backup_path(path: &Path, index: usize) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(format!(".{index}"));
+    PathBuf::from(path)
+}
+
+/// Shifts the existing backups of |path| up by one, keeping at most |count|
+/// of them, and makes the current contents of |path| the most recent backup.
+/// Does nothing if there is no file at |path| yet.
+///
+/// This should be called right before |path| is replaced by renaming a new
+/// version over it.
+pub fn rotate_backups(path: &Path, count: usize) -> std::io::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    // Make room for the oldest backup that's kept, and forget any beyond the
+    // limit, which may have been lowered since they were made.
+    let mut index = count.max(1);
+    while backup_path(path, index).exists() {
+        std::fs::remove_file(backup_path(path, index))?;
+        index += 1;
+    }
+    if count == 0 {
+        return Ok(());
+    }
+    for index in (1..count).rev() {
+        let from = backup_path(path, index);
+        if from.exists() {
+            std::fs::rename(&from, backup_path(path, index + 1))?;
+        }
+    }
+    // A hard link is cheap, and keeps pointing at the old contents once the
+    // new version is renamed over |path|.
+    let first = backup_path(path, 1);
+    std::fs::hard_link(path, &first)
+        .or_else(|_| std::fs::copy(path, &first).map(|_| ()))
+}
+
+/// The backups that sit next to a file on disk.
+pub struct FileBackups<'a>(pub &'a Path);
+
+impl Backups for FileBackups<'_> {
+    fn list(&self) -> Vec<Backup> {
+        (1..)
+            .map_while(|index| {
+                let metadata = std::fs::metadata(backup_path(self.0, index));
+                Some(Backup {
+                    index,
+                    modified: metadata.ok()?.modified().ok()?.into(),
+                })
+            })
+            .collect()
+    }
+
+    fn read(&self, index: usize) -> std::io::Result<String> {
+        std::fs::read_to_string(backup_path(self.0, index))
+    }
+}
+
+#[derive(Default)]
+pub struct FakeBackups {
+    /// The modification time and contents of each backup, most recent first.
+    pub backups: Vec<(DateTime<Utc>, String)>,
+}
+
+impl Backups for FakeBackups {
+    fn list(&self) -> Vec<Backup> {
+        self.backups
+            .iter()
+            .enumerate()
+            .map(|(i, &(modified, _))| Backup {
+                index: i + 1,
+                modified,
+            })
+            .collect()
+    }
+
+    fn read(&self, index: usize) -> std::io::Result<String> {
+        index
+            .checked_sub(1)
+            .and_then(|i| self.backups.get(i))
+            .map(|(_, contents)| contents.clone())
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+    }
+}
+
+#[cfg(test)]
+mod test;
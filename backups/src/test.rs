@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use super::*;
+
+fn write(path: &Path, contents: &str) {
+    std::fs::write(path, contents).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+// Mimics how the runner saves: rotate, then replace the file.
+fn save(path: &Path, contents: &str, count: usize) {
+    rotate_backups(path, count).unwrap();
+    let tmp = path.with_extension("tmp");
+    write(&tmp, contents);
+    std::fs::rename(&tmp, path).unwrap();
+}
+
+#[test]
+fn backup_path_appends_index() {
+    assert_eq!(
+        backup_path(Path::new("/a/data.json"), 3),
+        Path::new("/a/data.json.3")
+    );
+}
+
+#[test]
+fn rotate_without_file_does_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    rotate_backups(&path, 3).unwrap();
+    assert!(FileBackups(&path).list().is_empty());
+}
+
+#[test]
+fn rotate_keeps_previous_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    save(&path, "v1", 3);
+    save(&path, "v2", 3);
+    save(&path, "v3", 3);
+    assert_eq!(read(&path), "v3");
+    let backups = FileBackups(&path);
+    assert_eq!(backups.list().len(), 2);
+    assert_eq!(backups.read(1).unwrap(), "v2");
+    assert_eq!(backups.read(2).unwrap(), "v1");
+}
+
+#[test]
+fn rotate_forgets_oldest_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    for contents in ["v1", "v2", "v3", "v4", "v5"] {
+        save(&path, contents, 2);
+    }
+    let backups = FileBackups(&path);
+    assert_eq!(backups.list().len(), 2);
+    assert_eq!(backups.read(1).unwrap(), "v4");
+    assert_eq!(backups.read(2).unwrap(), "v3");
+}
+
+#[test]
+fn lowering_count_forgets_extra_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    for contents in ["v1", "v2", "v3", "v4"] {
+        save(&path, contents, 5);
+    }
+    save(&path, "v5", 1);
+    let backups = FileBackups(&path);
+    assert_eq!(backups.list().len(), 1);
+    assert_eq!(backups.read(1).unwrap(), "v4");
+}
+
+#[test]
+fn zero_count_keeps_no_backups() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    save(&path, "v1", 0);
+    save(&path, "v2", 0);
+    assert!(FileBackups(&path).list().is_empty());
+}
+
+#[test]
+fn read_missing_backup() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    assert!(FileBackups(&path).read(1).is_err());
+}
+
+#[test]
+fn fake_backups_are_numbered_from_one() {
+    let time = DateTime::<Utc>::default();
+    let backups = FakeBackups {
+        backups: vec![(time, "v2".to_string()), (time, "v1".to_string())],
+    };
+    assert_eq!(
+        backups.list(),
+        vec![
+            Backup {
+                index: 1,
+                modified: time
+            },
+            Backup {
+                index: 2,
+                modified: time
+            },
+        ]
+    );
+    assert_eq!(backups.read(2).unwrap(), "v1");
+    assert!(backups.read(0).is_err());
+    assert!(backups.read(3).is_err());
+}
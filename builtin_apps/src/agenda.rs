@@ -0,0 +1,70 @@
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use todo_cli::Agenda;
+use todo_model::TodoList;
+use todo_printing::AgendaHeading;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+
+use super::util::format_task;
+
+// The heading for |date|, counting days in the local time zone. Only due
+// dates can be overdue, since snoozed tasks wake up in the future.
+fn heading_for(
+    now: DateTime<Utc>,
+    date: DateTime<Utc>,
+    days: u32,
+) -> AgendaHeading {
+    if date < now {
+        return AgendaHeading::Overdue;
+    }
+    let today = now.with_timezone(&Local).date_naive();
+    let day = date.with_timezone(&Local).date_naive();
+    match (day - today).num_days() {
+        n if n >= days as i64 => AgendaHeading::Later,
+        0 => AgendaHeading::Today,
+        1 => AgendaHeading::Tomorrow,
+        _ => AgendaHeading::Day(day),
+    }
+}
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Agenda,
+) -> PrintableResult<'list> {
+    let mut entries = Vec::new();
+    for id in list.incomplete_tasks() {
+        let Some(task) = list.get(id) else {
+            continue;
+        };
+        let due_date = list.implicit_due_date(id).flatten();
+        let wake_date = Some(task.start_date).filter(|&date| date > now);
+        let mut headings: Vec<_> = [due_date, wake_date]
+            .into_iter()
+            .flatten()
+            .map(|date| heading_for(now, date, cmd.days))
+            .collect();
+        headings.dedup();
+        entries.extend(headings.into_iter().map(|heading| (heading, id)));
+    }
+    // The sort is stable, so tasks under each heading stay in list order.
+    entries.sort_by_key(|&(heading, _)| heading);
+    let mut previous = None;
+    Ok(PrintableAppSuccess {
+        tasks: entries
+            .into_iter()
+            .map(|(heading, id)| {
+                let task = format_task(list, id);
+                if previous == Some(heading) {
+                    task
+                } else {
+                    previous = Some(heading);
+                    task.agenda_heading(heading)
+                }
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
@@ -1,37 +1,61 @@
 use todo_app::Application;
+use todo_backups::Backups;
 use todo_cli::Options;
+use todo_cli::OutputFormat;
 use todo_cli::SubCommand::*;
 use todo_clock::Clock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
 use todo_model::TodoList;
+use todo_printing::JsonFormat;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
 use todo_printing::PrintableResult;
 use todo_text_editing::TextEditor;
 
+use super::agenda;
+use super::batch;
 use super::block;
 use super::bottom;
 use super::budget;
 use super::chain;
 use super::check;
 use super::clean;
+use super::complete_keys;
+use super::completions;
+use super::config;
 use super::due;
 use super::edit;
+use super::export;
 use super::find;
 use super::get;
+use super::import;
 use super::log;
+use super::ls;
 use super::merge;
 use super::new;
+use super::note;
 use super::path;
 use super::priority;
 use super::punt;
 use super::put;
+use super::recover;
 use super::restore;
 use super::rm;
+use super::schedule;
 use super::snooze;
 use super::snoozed;
 use super::split;
+use super::stats;
 use super::status;
 use super::tag;
+use super::time;
+use super::timer;
 use super::top;
+use super::tree;
+use super::tui;
 use super::unblock;
+use super::undo;
 use super::unsnooze;
 
 fn status_options(options: Options) -> status::Status {
@@ -43,45 +67,87 @@ fn status_options(options: Options) -> status::Status {
 
 /// Runs the 'todo' command line application. Returns whether the list was
 /// modified; if so, the caller should save the list.
-fn todo<'list>(
-    list: &'list mut TodoList,
+pub(crate) fn todo<'list, 'ser>(
+    list: &'list mut TodoList<'ser>,
+    journal: &mut Journal<'ser>,
+    config: &mut ConfigModel,
+    backups: &impl Backups,
     text_editor: &impl TextEditor,
     clock: &impl Clock,
     options: Options,
 ) -> PrintableResult<'list> {
     let now = clock.now();
     match options.cmd {
+        Some(Agenda(cmd)) => agenda::run(list, now, &cmd),
+        Some(Batch(cmd)) => {
+            batch::run(list, journal, config, backups, text_editor, clock, &cmd)
+        }
         Some(Block(cmd)) => block::run(list, &cmd),
         Some(Bottom(cmd)) => bottom::run(list, &cmd),
         Some(Budget(cmd)) => budget::run(list, &cmd),
         Some(Chain(cmd)) => chain::run(list, &cmd),
         Some(Check(cmd)) => check::run(list, now, &cmd),
         Some(Clean(_)) => clean::run(list, now),
-        Some(Config(_)) => unimplemented!(),
+        Some(CompleteKeys(cmd)) => complete_keys::run(list, now, &cmd),
+        Some(Completions(cmd)) => completions::run(&cmd),
+        Some(Config(cmd)) => config::run(config, &cmd),
         Some(Due(cmd)) => due::run(list, now, &cmd),
         Some(Edit(cmd)) => edit::run(list, text_editor, &cmd),
+        Some(Export(cmd)) => export::run(list, now, &cmd),
         Some(Find(cmd)) => find::run(list, &cmd),
         Some(Get(cmd)) => get::run(list, &cmd),
+        Some(Import(cmd)) => import::run(list, now, &cmd),
         Some(Log) => log::run(list),
+        Some(Ls(cmd)) => ls::run(list, now, &cmd),
         Some(Merge(cmd)) => merge::run(list, now, &cmd),
         Some(New(cmd)) => new::run(list, now, &cmd),
+        Some(Note(cmd)) => note::run(list, text_editor, &cmd),
         Some(Path(cmd)) => path::run(list, &cmd),
         Some(Priority(cmd)) => priority::run(list, &cmd),
         Some(Punt(cmd)) => punt::run(list, &cmd),
         Some(Put(cmd)) => put::run(list, &cmd),
+        Some(Recover(cmd)) => recover::run(list, backups, &cmd),
+        Some(Redo) => undo::run_redo(list, journal),
         Some(Restore(cmd)) => restore::run(list, &cmd),
         Some(Rm(cmd)) => rm::run(list, cmd),
+        Some(Schedule(cmd)) => schedule::run(list, now, &cmd),
+        // The runner starts the shell before any application runs, so this is
+        // only reached from inside the shell.
+        Some(Shell(_)) => Err(vec![PrintableError::AlreadyInShell]),
         Some(Snooze(cmd)) => snooze::run(list, now, &cmd),
         Some(Snoozed(cmd)) => snoozed::run(list, now, &cmd),
         Some(Split(cmd)) => split::run(list, cmd),
+        Some(Start(cmd)) => timer::run_start(list, now, &cmd),
+        Some(Stats(cmd)) => stats::run(list, now, &cmd),
+        Some(Stop) => timer::run_stop(list, now),
         Some(Tag(cmd)) => tag::run(list, &cmd),
+        Some(Time(cmd)) => time::run(list, now, &cmd),
         Some(Top(cmd)) => top::run(list, &cmd),
+        Some(Tree(cmd)) => tree::run(list, &cmd),
+        Some(Tui(cmd)) => tui::run(list, text_editor, clock, config, &cmd),
         Some(Unblock(cmd)) => unblock::run(list, &cmd),
+        Some(Undo(cmd)) => undo::run(list, journal, &cmd),
         Some(Unsnooze(cmd)) => unsnooze::run(list, &cmd),
         None => status::run(list, now, &status_options(options)),
     }
 }
 
+// Commands that can never modify the list or the config. Anything not listed
+// here is assumed to modify them, including the status display, which
+// unsnoozes tasks whose snooze date has passed.
+fn is_read_only(options: &Options) -> bool {
+    match &options.cmd {
+        Some(Agenda(_) | Bottom(_) | Find(_) | Get(_) | Log) => true,
+        Some(CompleteKeys(_) | Completions(_) | Path(_) | Schedule(_)) => true,
+        Some(Export(_) | Ls(_) | Snoozed(_) | Time(_)) => true,
+        Some(Stats(_) | Top(_) | Tree(_)) => true,
+        Some(Config(cmd)) => cmd.value.is_empty() && !cmd.reset,
+        Some(Recover(cmd)) => cmd.backup.is_none(),
+        Some(Undo(cmd)) => cmd.list,
+        _ => false,
+    }
+}
+
 pub struct App {
     options: Options,
 }
@@ -94,12 +160,47 @@ impl App {
 
 impl Application for App {
     type Result<'a> = PrintableResult<'a>;
-    fn run<'a>(
+    fn run<'a, 'ser>(
         self,
-        list: &'a mut TodoList,
+        list: &'a mut TodoList<'ser>,
+        journal: &mut Journal<'ser>,
+        config: &mut ConfigModel,
+        backups: &impl Backups,
         text_editor: &impl TextEditor,
         clock: &impl Clock,
     ) -> Self::Result<'a> {
-        todo(list, text_editor, clock, self.options)
+        let dry_run = self.options.dry_run;
+        let mut result = todo(
+            list,
+            journal,
+            config,
+            backups,
+            text_editor,
+            clock,
+            self.options,
+        );
+        if let (true, Ok(success)) = (dry_run, &mut result) {
+            success.infos.insert(0, PrintableInfo::DryRun);
+        }
+        result
+    }
+
+    fn is_read_only(&self) -> bool {
+        is_read_only(&self.options)
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.options.dry_run
+    }
+
+    fn is_full_screen(&self) -> bool {
+        matches!(self.options.cmd, Some(Tui(_)))
+    }
+
+    fn output_format(&self) -> Option<JsonFormat> {
+        self.options.format.map(|format| match format {
+            OutputFormat::Json => JsonFormat::Json,
+            OutputFormat::Jsonl => JsonFormat::Jsonl,
+        })
     }
 }
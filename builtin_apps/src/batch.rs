@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+use clap::Parser;
+use todo_backups::Backups;
+use todo_cli::Batch;
+use todo_cli::Options;
+use todo_cli::SubCommand;
+use todo_clock::Clock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_printing::Action;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableReport;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+use todo_text_editing::TextEditor;
+
+use super::all::todo;
+use super::util::format_task;
+
+fn read_commands(file: &Path) -> Result<String, Vec<PrintableError>> {
+    let is_stdin = file == Path::new("-");
+    let result = if is_stdin {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(file)
+    };
+    result.map_err(|e| {
+        vec![PrintableError::CannotReadFile {
+            path: if is_stdin {
+                "standard input".to_string()
+            } else {
+                file.display().to_string()
+            },
+            explanation: e.to_string(),
+        }]
+    })
+}
+
+fn cannot_parse(explanation: impl Into<String>) -> Vec<PrintableError> {
+    vec![PrintableError::CannotParseBatchCommand {
+        explanation: explanation.into(),
+    }]
+}
+
+// The name in an argument like '$name', if it is one.
+fn name_of(arg: &str) -> Option<&str> {
+    let name = arg.strip_prefix('$')?;
+    let mut chars = name.chars();
+    let starts_well = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    let rest_is_valid =
+        chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    (starts_well && rest_is_valid).then_some(name)
+}
+
+/// Parses a line of the batch into the options to run it with, and the name
+/// to give the task it creates, if '--as' is given. Names of tasks created
+/// earlier in the batch are replaced with the tasks' short IDs.
+fn parse_command(
+    command: &str,
+    names: &HashMap<String, String>,
+) -> Result<(Options, Option<String>), Vec<PrintableError>> {
+    let mut args = shlex::split(command)
+        .ok_or_else(|| cannot_parse("unbalanced quotes"))?;
+    if args.first().is_some_and(|arg| arg == "todo") {
+        args.remove(0);
+    }
+    let name = match args.iter().position(|arg| arg == "--as") {
+        Some(i) => {
+            let name = args
+                .get(i + 1)
+                .map(|arg| arg.strip_prefix('$').unwrap_or(arg).to_string())
+                .ok_or_else(|| cannot_parse("'--as' needs a name"))?;
+            args.drain(i..i + 2);
+            Some(name)
+        }
+        None => None,
+    };
+    let args = args
+        .into_iter()
+        .map(|arg| match name_of(&arg) {
+            Some(name) => match names.get(name) {
+                Some(short_id) => Ok(format!("#{short_id}")),
+                None => Err(vec![PrintableError::UnknownBatchName {
+                    name: name.to_string(),
+                }]),
+            },
+            None => Ok(arg),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = Options::try_parse_from(
+        std::iter::once("todo".to_string()).chain(args),
+    )
+    .map_err(|e| {
+        let rendered = e.render().to_string();
+        let first_line = rendered.lines().next().unwrap_or_default();
+        cannot_parse(first_line.strip_prefix("error: ").unwrap_or(first_line))
+    })?;
+    // These would need the journal or the terminal to be restored when the
+    // batch fails, or would run a batch within the batch.
+    let unsupported = match &options.cmd {
+        Some(SubCommand::Batch(_)) => Some("batch"),
+        Some(SubCommand::Redo) => Some("redo"),
+        Some(SubCommand::Shell(_)) => Some("shell"),
+        Some(SubCommand::Tui(_)) => Some("tui"),
+        Some(SubCommand::Undo(_)) => Some("undo"),
+        _ => None,
+    };
+    if options.dry_run {
+        return Err(cannot_parse(
+            "use 'todo batch --dry-run' to preview the whole batch",
+        ));
+    }
+    if let Some(subcommand) = unsupported {
+        return Err(cannot_parse(format!(
+            "'todo {subcommand}' cannot be used in a batch"
+        )));
+    }
+    Ok((options, name))
+}
+
+fn short_ids(list: &TodoList) -> HashSet<String> {
+    list.all_tasks()
+        .filter_map(|id| list.get(id))
+        .map(|task| task.short_id.to_string())
+        .collect()
+}
+
+// What a command in the batch printed, without borrowing the list, so that
+// the next command can change it.
+struct Output {
+    // The short IDs of the tasks the command printed, with their actions.
+    tasks: Vec<(String, Action)>,
+    warnings: Vec<PrintableWarning>,
+    infos: Vec<PrintableInfo>,
+    document: Option<String>,
+    reports: Vec<PrintableReport>,
+    mutated: bool,
+}
+
+impl Output {
+    fn new(success: PrintableAppSuccess) -> Self {
+        Output {
+            tasks: success
+                .tasks
+                .iter()
+                .filter_map(|task| {
+                    task.short_id
+                        .map(|short_id| (short_id.to_string(), task.action))
+                })
+                .collect(),
+            warnings: success.warnings,
+            infos: success.infos,
+            document: success.document,
+            reports: success.reports,
+            mutated: success.mutated,
+        }
+    }
+}
+
+// Gives |name| to the one task in |list| whose short ID is not in
+// |existing|.
+fn name_new_task(
+    list: &TodoList,
+    existing: &HashSet<String>,
+    name: String,
+    names: &mut HashMap<String, String>,
+) -> Result<(), Vec<PrintableError>> {
+    let created: Vec<_> =
+        short_ids(list).difference(existing).cloned().collect();
+    match created.as_slice() {
+        [short_id] => {
+            names.insert(name, short_id.clone());
+            Ok(())
+        }
+        _ => Err(vec![PrintableError::CannotNameBatchTasks {
+            name,
+            created: created.len(),
+        }]),
+    }
+}
+
+pub fn run<'list, 'ser>(
+    list: &'list mut TodoList<'ser>,
+    journal: &mut Journal<'ser>,
+    config: &mut ConfigModel,
+    backups: &impl Backups,
+    text_editor: &impl TextEditor,
+    clock: &impl Clock,
+    cmd: &Batch,
+) -> PrintableResult<'list> {
+    let text = read_commands(&cmd.file)?;
+    let original_list = list.clone();
+    let original_config = config.clone();
+    let mut names = HashMap::new();
+    let mut printed: Vec<(String, Action)> = Vec::new();
+    let mut warnings = Vec::new();
+    let mut infos = Vec::new();
+    let mut document: Option<String> = None;
+    let mut reports = Vec::new();
+    let mut mutated = false;
+    for (i, line) in text.lines().enumerate() {
+        let command = line.trim();
+        if command.is_empty() || command.starts_with('#') {
+            continue;
+        }
+        // A failed command is skipped with '--keep-going', so undo anything
+        // it did before it failed.
+        let before = cmd.keep_going.then(|| (list.clone(), config.clone()));
+        let result =
+            parse_command(command, &names).and_then(|(options, name)| {
+                let existing = name.as_ref().map(|_| short_ids(list));
+                let output = Output::new(todo(
+                    list,
+                    journal,
+                    config,
+                    backups,
+                    text_editor,
+                    clock,
+                    options,
+                )?);
+                if let (Some(name), Some(existing)) = (name, existing) {
+                    name_new_task(list, &existing, name, &mut names)?;
+                }
+                Ok(output)
+            });
+        match result {
+            Ok(output) => {
+                printed.extend(output.tasks);
+                warnings.extend(output.warnings);
+                infos.extend(output.infos);
+                if let Some(doc) = output.document {
+                    document.get_or_insert_with(String::new).push_str(&doc);
+                }
+                reports.extend(output.reports);
+                mutated |= output.mutated;
+            }
+            Err(errors) => match before {
+                Some((list_before, config_before)) => {
+                    *list = list_before;
+                    *config = config_before;
+                    warnings.push(PrintableWarning::SkippedBatchCommand {
+                        line: i + 1,
+                        command: command.to_string(),
+                        errors,
+                    });
+                }
+                None => {
+                    *list = original_list;
+                    *config = original_config;
+                    let mut errors = errors;
+                    errors.push(PrintableError::BatchCommandFailed {
+                        line: i + 1,
+                        command: command.to_string(),
+                    });
+                    return Err(errors);
+                }
+            },
+        }
+    }
+
+    // Print each task once, where it ended up after the whole batch, with
+    // the last thing that was done to it.
+    let mut tasks = TaskSet::default();
+    let mut actions = HashMap::new();
+    for (short_id, action) in printed {
+        if let Some(id) = list.lookup_by_short_id(&short_id) {
+            tasks.push(id);
+            let last_action = actions.entry(id).or_insert(Action::None);
+            if action != Action::None {
+                *last_action = action;
+            }
+        }
+    }
+    Ok(PrintableAppSuccess {
+        warnings,
+        infos,
+        tasks: tasks
+            .iter_sorted(list)
+            .map(|id| format_task(list, id).action(actions[&id]))
+            .collect(),
+        document,
+        reports,
+        mutated,
+    })
+}
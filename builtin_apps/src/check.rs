@@ -117,6 +117,8 @@ pub fn run<'list>(
     cmd: &Check,
 ) -> PrintableResult<'list> {
     let tasks_to_check = lookup_tasks(list, &cmd.keys);
+    // Checking a recurring task adds its next instance to the list.
+    let tasks_before = list.all_tasks().collect::<TaskSet>();
     let Checked {
         checked,
         unlocked,
@@ -150,6 +152,7 @@ pub fn run<'list>(
             cannot_check: format_task_brief(list, id),
         })
         .collect();
+    let respawned = list.all_tasks().collect::<TaskSet>() - tasks_before;
     let tasks_to_print = checked
         .iter_sorted(list)
         .map(|id| format_task(list, id).action(Action::Check))
@@ -158,6 +161,11 @@ pub fn run<'list>(
                 .iter_sorted(list)
                 .map(|id| format_task(list, id).action(Action::Unlock)),
         )
+        .chain(
+            respawned
+                .iter_sorted(list)
+                .map(|id| format_task(list, id).action(Action::New)),
+        )
         .collect();
     Ok(PrintableAppSuccess {
         warnings,
@@ -0,0 +1,52 @@
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::CompleteKeys;
+use todo_model::TaskId;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+
+// The tasks worth suggesting as keys to the given subcommand.
+fn relevant_tasks<'a>(
+    list: &'a TodoList,
+    now: DateTime<Utc>,
+    subcommand: Option<&str>,
+) -> Box<dyn Iterator<Item = TaskId> + 'a> {
+    let incomplete = list.incomplete_tasks();
+    match subcommand {
+        Some("restore") => Box::new(list.complete_tasks()),
+        Some("unsnooze") => Box::new(incomplete.filter(move |&id| {
+            list.get(id).is_some_and(|task| task.start_date > now)
+        })),
+        Some("check" | "punt" | "snooze" | "start" | "unblock") => {
+            Box::new(incomplete)
+        }
+        _ => Box::new(incomplete.chain(list.complete_tasks())),
+    }
+}
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &CompleteKeys,
+) -> PrintableResult<'list> {
+    let mut document = String::new();
+    for id in relevant_tasks(list, now, cmd.subcommand.as_deref()) {
+        let (Some(task), Some(position)) = (list.get(id), list.position(id))
+        else {
+            continue;
+        };
+        let desc = if cmd.raw {
+            task.desc.to_string()
+        } else {
+            shlex::try_quote(&task.desc)
+                .map(|quoted| quoted.into_owned())
+                .unwrap_or_else(|_| task.desc.to_string())
+        };
+        document.push_str(&format!("{position}\n{desc}\n"));
+    }
+    Ok(PrintableAppSuccess {
+        document: Some(document),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,86 @@
+use clap::Command;
+use clap::CommandFactory;
+use todo_cli::CompletionShell;
+use todo_cli::Completions;
+use todo_cli::Options;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+
+// Each script wraps the completion function that clap generates, adding the
+// keys that 'todo __complete-keys' prints for the subcommand being typed when
+// the current word isn't a flag.
+
+const BASH_KEYS: &str = r#"
+_todo_keys() {
+    _todo "$@"
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local subcommand="" i
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        if [[ "${COMP_WORDS[i]}" != -* ]]; then
+            subcommand="${COMP_WORDS[i]}"
+            break
+        fi
+    done
+    if [[ -z "$subcommand" || "$cur" == -* ]]; then
+        return
+    fi
+    local word="${cur#[\'\"]}" key
+    while IFS= read -r key; do
+        if [[ "${key#\'}" == "$word"* ]]; then
+            COMPREPLY+=("$key")
+        fi
+    done < <(todo __complete-keys "$subcommand" 2>/dev/null)
+}
+complete -F _todo_keys -o bashdefault -o default todo
+"#;
+
+const ZSH_KEYS: &str = r#"
+_todo_keys() {
+    _todo "$@"
+    local subcommand=${${words[2,CURRENT-1]:#-*}[1]}
+    if [[ -n $subcommand && $PREFIX != -* ]]; then
+        local -a keys
+        keys=("${(@f)$(todo __complete-keys $subcommand --raw 2>/dev/null)}")
+        compadd -a keys
+    fi
+}
+compdef _todo_keys todo
+"#;
+
+const FISH_KEYS: &str = r#"
+complete -c todo -n "not __fish_use_subcommand" -f \
+    -a "(todo __complete-keys (commandline -opc)[2] --raw 2>/dev/null)"
+"#;
+
+// clap_complete completes hidden subcommands too, so build a copy of the
+// command line parser without '__complete-keys'.
+fn visible_command() -> Command {
+    let full = Options::command();
+    let mut command = Command::new(full.get_name().to_string())
+        .args(full.get_arguments().cloned())
+        .subcommands(
+            full.get_subcommands()
+                .filter(|sub| !sub.is_hide_set())
+                .cloned(),
+        );
+    if let Some(version) = full.get_version() {
+        command = command.version(version.to_string()).propagate_version(true);
+    }
+    command
+}
+
+pub fn run<'list>(cmd: &Completions) -> PrintableResult<'list> {
+    let (shell, keys) = match cmd.shell {
+        CompletionShell::Bash => (clap_complete::Shell::Bash, BASH_KEYS),
+        CompletionShell::Zsh => (clap_complete::Shell::Zsh, ZSH_KEYS),
+        CompletionShell::Fish => (clap_complete::Shell::Fish, FISH_KEYS),
+    };
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut visible_command(), "todo", &mut script);
+    let mut script = String::from_utf8_lossy(&script).into_owned();
+    script.push_str(keys);
+    Ok(PrintableAppSuccess {
+        document: Some(script),
+        ..Default::default()
+    })
+}
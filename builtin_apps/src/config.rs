@@ -0,0 +1,70 @@
+use todo_cli::Config;
+use todo_config::ConfigError;
+use todo_config::ConfigModel;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableResult;
+
+fn format_config_error(error: ConfigError) -> PrintableError {
+    match error {
+        ConfigError::UnknownKey(key) => PrintableError::UnknownConfigKey {
+            key,
+            valid_keys: ConfigModel::KEYS
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+        },
+        ConfigError::CannotParseValue {
+            key,
+            value,
+            explanation,
+        } => PrintableError::CannotParseConfigValue {
+            key,
+            value,
+            explanation,
+        },
+    }
+}
+
+fn config_value(
+    config: &ConfigModel,
+    key: &str,
+) -> Result<PrintableInfo, PrintableError> {
+    config
+        .get(key)
+        .map(|value| PrintableInfo::ConfigValue {
+            key: key.to_string(),
+            value,
+        })
+        .map_err(format_config_error)
+}
+
+/// Shows, sets, or resets config values. This never mutates the list; the
+/// caller is responsible for noticing changes to the config and saving it.
+pub fn run<'list>(
+    config: &mut ConfigModel,
+    cmd: &Config,
+) -> PrintableResult<'list> {
+    let infos = match &cmd.key {
+        None => ConfigModel::KEYS
+            .iter()
+            .map(|key| config_value(config, key))
+            .collect::<Result<Vec<_>, _>>(),
+        Some(key) => if cmd.reset {
+            config.reset(key)
+        } else if !cmd.value.is_empty() {
+            config.set(key, &cmd.value)
+        } else {
+            Ok(())
+        }
+        .map_err(format_config_error)
+        .and_then(|()| config_value(config, key))
+        .map(|info| vec![info]),
+    }
+    .map_err(|e| vec![e])?;
+    Ok(PrintableAppSuccess {
+        infos,
+        ..Default::default()
+    })
+}
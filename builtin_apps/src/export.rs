@@ -0,0 +1,72 @@
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::Export;
+use todo_cli::ExportFormat;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+
+use super::util::lookup_task;
+use super::util::should_include_done;
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Export,
+) -> PrintableResult<'list> {
+    let mut warnings = Vec::new();
+    let (tasks, include_done) = if cmd.keys.is_empty() {
+        (list.all_tasks().collect::<TaskSet>(), cmd.include_done)
+    } else {
+        let requested =
+            cmd.keys.iter().fold(TaskSet::default(), |so_far, key| {
+                let tasks = lookup_task(list, key);
+                if tasks.is_empty() {
+                    warnings.push(PrintableWarning::NoMatchFoundForKey {
+                        requested_key: key.clone(),
+                    });
+                }
+                so_far | tasks
+            });
+        let include_done = should_include_done(
+            cmd.include_done,
+            list,
+            requested.iter_unsorted(),
+        );
+        let connected =
+            requested
+                .iter_unsorted()
+                .fold(requested.clone(), |so_far, id| {
+                    so_far
+                        | list.transitive_deps(id)
+                        | list.transitive_adeps(id)
+                });
+        (connected, include_done)
+    };
+    // Formats that 'todo import' reads keep complete tasks, so that
+    // exporting and importing a list keeps completion state and blocks on
+    // complete tasks.
+    let round_trips = matches!(
+        cmd.format,
+        ExportFormat::TodoTxt | ExportFormat::Ics | ExportFormat::Taskwarrior
+    );
+    let tasks = tasks.include_done(list, include_done || round_trips);
+    let document = match cmd.format {
+        ExportFormat::Dot => todo_interchange::to_dot(list, &tasks),
+        ExportFormat::Mermaid => todo_interchange::to_mermaid(list, &tasks),
+        ExportFormat::TodoTxt => todo_interchange::to_todo_txt(list, &tasks),
+        ExportFormat::Ics => todo_interchange::to_ics(list, &tasks, now),
+        ExportFormat::Taskwarrior => {
+            todo_interchange::to_taskwarrior(list, &tasks)
+        }
+        ExportFormat::Csv => todo_interchange::to_csv(list, &tasks),
+        ExportFormat::Tsv => todo_interchange::to_tsv(list, &tasks),
+    };
+    Ok(PrintableAppSuccess {
+        warnings,
+        document: Some(document),
+        ..Default::default()
+    })
+}
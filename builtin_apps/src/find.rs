@@ -18,10 +18,16 @@ pub fn run<'list>(list: &'list TodoList, cmd: &Find) -> PrintableResult<'list> {
                 {
                     return None;
                 }
+                let notes = cmd.notes.then(|| task.notes.to_lowercase());
                 cmd.terms
                     .iter()
                     .map(|term| term.to_lowercase())
-                    .any(|term| task.desc.to_lowercase().contains(&term))
+                    .any(|term| {
+                        task.desc.to_lowercase().contains(&term)
+                            || notes
+                                .as_ref()
+                                .is_some_and(|notes| notes.contains(&term))
+                    })
                     .then(|| format_task(list, id).action(Action::Select))
                     .or_else(|| {
                         task.implicit_tags
@@ -80,7 +80,12 @@ pub fn run<'list>(list: &'list TodoList, cmd: &Get) -> PrintableResult<'list> {
         .map(|id| {
             let task = format_task(list, id);
             if requested_tasks.contains(id) {
-                task.action(Action::Select).truncate_tags_if_needed(false)
+                // Spell out the notes of the requested tasks, if they have
+                // any, rather than just marking them.
+                let has_notes = task.notes.is_some();
+                task.action(Action::Select)
+                    .truncate_tags_if_needed(false)
+                    .show_notes(has_notes)
             } else {
                 task.action(Action::None)
             }
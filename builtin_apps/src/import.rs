@@ -0,0 +1,47 @@
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::Import;
+use todo_cli::ImportFormat;
+use todo_model::TodoList;
+use todo_printing::Action;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableResult;
+
+use super::util::format_task;
+
+pub fn run<'list>(
+    list: &'list mut TodoList,
+    now: DateTime<Utc>,
+    cmd: &Import,
+) -> PrintableResult<'list> {
+    let text = std::fs::read_to_string(&cmd.file).map_err(|e| {
+        vec![PrintableError::CannotReadFile {
+            path: cmd.file.display().to_string(),
+            explanation: e.to_string(),
+        }]
+    })?;
+    let tasks = match cmd.format {
+        ImportFormat::TodoTxt => todo_interchange::from_todo_txt(&text, now),
+        ImportFormat::Ics => todo_interchange::from_ics(&text, now),
+        ImportFormat::Md => todo_interchange::from_markdown(&text, now),
+        ImportFormat::Org => todo_interchange::from_org(&text, now),
+        ImportFormat::Taskwarrior => {
+            todo_interchange::from_taskwarrior(&text, now).map_err(|e| {
+                vec![PrintableError::CannotParseFile {
+                    path: cmd.file.display().to_string(),
+                    explanation: e.to_string(),
+                }]
+            })?
+        }
+    };
+    let created = todo_interchange::import(list, &tasks, now);
+    Ok(PrintableAppSuccess {
+        tasks: created
+            .iter_sorted(list)
+            .map(|id| format_task(list, id).action(Action::New))
+            .collect(),
+        mutated: !created.is_empty(),
+        ..Default::default()
+    })
+}
@@ -1,30 +1,47 @@
+mod agenda;
 mod all;
+mod batch;
 mod block;
 mod bottom;
 mod budget;
 mod chain;
 mod check;
 mod clean;
+mod complete_keys;
+mod completions;
+mod config;
 mod due;
 mod edit;
+mod export;
 mod find;
 mod get;
+mod import;
 mod log;
+mod ls;
 mod merge;
 mod new;
+mod note;
 mod path;
 mod priority;
 mod punt;
 mod put;
+mod recover;
 mod restore;
 mod rm;
+mod schedule;
 mod snooze;
 mod snoozed;
 mod split;
+mod stats;
 mod status;
 mod tag;
+mod time;
+mod timer;
 mod top;
+mod tree;
+mod tui;
 mod unblock;
+mod undo;
 mod unsnooze;
 mod util;
 
@@ -33,33 +50,51 @@ pub use all::App;
 #[cfg(test)]
 mod tests {
     use super::*;
+    mod agenda_test;
+    mod batch_test;
     mod block_test;
     mod bottom_test;
     mod budget_test;
     mod chain_test;
     mod check_test;
     mod clean_test;
+    mod complete_keys_test;
+    mod completions_test;
+    mod config_test;
     mod due_test;
     mod edit_test;
+    mod export_test;
     mod find_test;
     mod get_test;
+    mod import_test;
     mod log_test;
+    mod ls_test;
     mod merge_test;
     mod new_test;
+    mod note_test;
     mod path_test;
     mod priority_test;
     mod punt_test;
     mod put_test;
+    mod recover_test;
     mod restore_test;
     mod rm_test;
+    mod schedule_test;
+    mod shell_test;
     mod snooze_test;
     mod snoozed_test;
     mod split_test;
+    mod stats_test;
     mod status_test;
     mod tag_test;
     mod testing;
+    mod time_test;
+    mod timer_test;
     mod top_test;
+    mod tree_test;
+    mod tui_test;
     mod unblock_test;
+    mod undo_test;
     mod unsnooze_test;
     mod util_test;
 }
@@ -0,0 +1,45 @@
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::Ls;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableResult;
+
+use super::util::format_task;
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Ls,
+) -> PrintableResult<'list> {
+    let query_string = cmd.query.join(" ");
+    let query = if query_string.trim().is_empty() {
+        None
+    } else {
+        Some(todo_query::parse(&query_string, now).map_err(|e| {
+            vec![PrintableError::CannotParseQuery {
+                query: query_string.clone(),
+                explanation: e.to_string(),
+            }]
+        })?)
+    };
+    let include_done = cmd.include_done
+        || query
+            .as_ref()
+            .is_some_and(|query| query.mentions_completion());
+    Ok(PrintableAppSuccess {
+        tasks: list
+            .all_tasks()
+            .filter(|&id| {
+                include_done || list.status(id) != Some(TaskStatus::Complete)
+            })
+            .filter(|&id| {
+                query.as_ref().is_none_or(|query| query.matches(list, id))
+            })
+            .map(|id| format_task(list, id))
+            .collect(),
+        ..Default::default()
+    })
+}
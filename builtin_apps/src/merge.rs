@@ -58,6 +58,26 @@ pub fn run<'list>(
             deps_of: format_tasks_brief(list, &deps_of),
         }]);
     }
+    let recurring = tasks_to_merge
+        .iter_sorted(list)
+        .filter(|&id| list.get(id).unwrap().recurrence.is_some())
+        .collect::<TaskSet>();
+    // The merged task repeats if the tasks it was merged from did, as long
+    // as they agree on when.
+    let mut recurrences = recurring
+        .iter_sorted(list)
+        .flat_map(|id| list.get(id).unwrap().recurrence)
+        .collect::<Vec<_>>();
+    recurrences.dedup();
+    let recurrence = match recurrences[..] {
+        [] => None,
+        [recurrence] => Some(recurrence),
+        _ => {
+            return Err(vec![PrintableError::CannotMergeRecurrences {
+                recurring: format_tasks_brief(list, &recurring),
+            }]);
+        }
+    };
     let priority = tasks_to_merge
         .iter_unsorted()
         .map(|id| list.get(id).unwrap().priority)
@@ -91,7 +111,15 @@ pub fn run<'list>(
         budget,
         start_date,
         tag,
+        recurrence,
     });
+    let notes = tasks_to_merge
+        .iter_sorted(list)
+        .map(|id| list.get(id).unwrap().notes.trim_end())
+        .filter(|notes| !notes.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    list.set_notes(merged, notes);
     deps.iter_sorted(list).for_each(|dep| {
         // This shouldn't panic if we correctly detected cycles above.
         list.block(merged).on(dep).unwrap();
@@ -100,7 +128,15 @@ pub fn run<'list>(
         // This shouldn't panic if we correctly detected cycles above.
         list.block(adep).on(merged).unwrap();
     });
+    // Time logged on the merged tasks counts towards the merged task.
+    if list
+        .running_timer()
+        .is_some_and(|running| tasks_to_merge.contains(running))
+    {
+        list.stop_timer(now);
+    }
     tasks_to_merge.iter_sorted(list).for_each(|id| {
+        list.move_work(id, merged);
         list.remove(id);
     });
     let tasks_to_print = (deps | TaskSet::of(merged) | adeps)
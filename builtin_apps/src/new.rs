@@ -22,6 +22,7 @@ use super::util::format_tasks_brief;
 use super::util::lookup_tasks_by_keys;
 use super::util::parse_budget;
 use super::util::parse_due_date;
+use super::util::parse_recurrence;
 use super::util::parse_snooze_date;
 
 fn disambiguate(list: &TodoList, tasks: TaskSet) -> TaskSet {
@@ -60,6 +61,7 @@ pub fn run<'list>(
     let budget = parse_budget(&cmd.budget).map_err(|e| vec![e])?;
     let snooze_date =
         parse_snooze_date(now, &cmd.snooze).map_err(|e| vec![e])?;
+    let recurrence = parse_recurrence(&cmd.repeat).map_err(|e| vec![e])?;
     let deps = lookup_tasks_ambiguously(list, &cmd.blocked_by);
     let adeps = lookup_tasks_ambiguously(list, &cmd.blocking);
     let before = lookup_tasks_ambiguously(list, &cmd.before);
@@ -93,6 +95,7 @@ pub fn run<'list>(
                 budget,
                 start_date: snooze_date,
                 tag: cmd.tag,
+                recurrence,
             });
             to_print.insert(id);
             id
@@ -0,0 +1,67 @@
+use todo_cli::Note;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+use todo_text_editing::TextEditor;
+
+use super::util::format_task;
+use super::util::lookup_task;
+
+fn appended(notes: &str, line: &str) -> String {
+    if notes.is_empty() {
+        line.to_string()
+    } else {
+        format!("{notes}\n{line}")
+    }
+}
+
+pub fn run<'list>(
+    list: &'list mut TodoList,
+    text_editor: &impl TextEditor,
+    cmd: &Note,
+) -> PrintableResult<'list> {
+    let mut warnings = Vec::new();
+    let tasks = cmd.keys.iter().fold(TaskSet::default(), |so_far, key| {
+        let tasks = lookup_task(list, key);
+        if tasks.is_empty() {
+            warnings.push(PrintableWarning::NoMatchFoundForKey {
+                requested_key: key.clone(),
+            });
+        }
+        so_far | tasks
+    });
+    let mut mutated = false;
+    for id in tasks.iter_sorted(list) {
+        let notes = &list.get(id).unwrap().notes;
+        let new_notes = match &cmd.append {
+            Some(line) => appended(notes, line.trim()),
+            // Editors usually end the file with a newline, which isn't part
+            // of the notes.
+            None => text_editor
+                .edit_text(notes)
+                .map_err(|_| vec![PrintableError::FailedToUseTextEditor])?
+                .trim_end()
+                .to_string(),
+        };
+        if &new_notes != notes {
+            list.set_notes(id, new_notes);
+            mutated = true;
+        }
+    }
+    Ok(PrintableAppSuccess {
+        warnings,
+        tasks: tasks
+            .iter_sorted(list)
+            .map(|id| {
+                let task = format_task(list, id);
+                let has_notes = task.notes.is_some();
+                task.show_notes(has_notes)
+            })
+            .collect(),
+        mutated,
+        ..Default::default()
+    })
+}
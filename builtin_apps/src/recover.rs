@@ -0,0 +1,57 @@
+use todo_backups::Backups;
+use todo_cli::Recover;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+
+fn list_backups<'list>(backups: &impl Backups) -> PrintableResult<'list> {
+    let infos = backups
+        .list()
+        .into_iter()
+        .map(|backup| PrintableInfo::Backup {
+            index: backup.index,
+            modified: backup.modified,
+            num_tasks: backups.read(backup.index).ok().and_then(|contents| {
+                serde_json::from_str::<TodoList>(&contents)
+                    .ok()
+                    .map(|list| list.all_tasks().count())
+            }),
+        })
+        .collect::<Vec<_>>();
+    Ok(PrintableAppSuccess {
+        warnings: if infos.is_empty() {
+            vec![PrintableWarning::NoBackups]
+        } else {
+            vec![]
+        },
+        infos,
+        ..Default::default()
+    })
+}
+
+pub fn run<'list>(
+    list: &'list mut TodoList,
+    backups: &impl Backups,
+    cmd: &Recover,
+) -> PrintableResult<'list> {
+    let index = match cmd.backup {
+        Some(index) => index as usize,
+        None => return list_backups(backups),
+    };
+    let contents = backups
+        .read(index)
+        .map_err(|_| vec![PrintableError::NoSuchBackup { index }])?;
+    // The backup's text only lives as long as this function, so the recovered
+    // list can't borrow from it.
+    *list = serde_json::from_str::<TodoList>(&contents)
+        .map_err(|_| vec![PrintableError::CannotReadBackup { index }])?
+        .into_owned();
+    Ok(PrintableAppSuccess {
+        infos: vec![PrintableInfo::Recovered { index }],
+        mutated: true,
+        ..Default::default()
+    })
+}
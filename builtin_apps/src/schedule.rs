@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::Schedule;
+use todo_model::ScheduledTask;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+
+use super::util::format_task;
+use super::util::format_task_brief;
+use super::util::lookup_task;
+
+// Adds the critical path to |target| to |critical|: the tasks, among the
+// target and what it's transitively blocked on, with the least slack towards
+// the target. The slack only counts the target's latest finish, or if it has
+// none, its earliest finish, so the due dates of other tasks don't matter.
+fn add_critical_path(
+    list: &TodoList,
+    schedule: &HashMap<TaskId, ScheduledTask>,
+    target: TaskId,
+    critical: &mut TaskSet,
+) {
+    let Some(scheduled) = schedule.get(&target) else {
+        return;
+    };
+    let work = |scheduled: &ScheduledTask| {
+        scheduled.earliest_finish - scheduled.earliest_start
+    };
+    let deadline = scheduled.latest_finish.unwrap_or(scheduled.earliest_finish);
+    // Nothing the target is blocked on can have less slack towards it than
+    // the target itself.
+    let min_slack = deadline - scheduled.earliest_finish;
+    let mut latest_finish = HashMap::new();
+    // Deps come before their adeps in sorted order, so this visits each
+    // task after everything between it and the target.
+    let tasks = (list.transitive_deps(target) | TaskSet::of(target))
+        .iter_sorted(list)
+        .filter_map(|id| Some((id, schedule.get(&id)?)))
+        .collect::<Vec<_>>();
+    for &(id, scheduled) in tasks.iter().rev() {
+        let latest = if id == target {
+            Some(deadline)
+        } else {
+            list.adeps(id)
+                .iter_unsorted()
+                .filter_map(|adep| {
+                    let latest = *latest_finish.get(&adep)?;
+                    Some(latest - work(schedule.get(&adep)?))
+                })
+                .min()
+        };
+        let Some(latest) = latest else {
+            continue;
+        };
+        latest_finish.insert(id, latest);
+        if latest - scheduled.earliest_finish == min_slack {
+            critical.push(id);
+        }
+    }
+}
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Schedule,
+) -> PrintableResult<'list> {
+    let schedule = list.schedule(now);
+    let mut warnings = Vec::new();
+    let targets = if cmd.keys.is_empty() {
+        list.incomplete_tasks()
+            .filter(|&id| {
+                list.get(id).is_some_and(|task| task.due_date.is_some())
+            })
+            .collect::<TaskSet>()
+    } else {
+        cmd.keys.iter().fold(TaskSet::default(), |so_far, key| {
+            let tasks = lookup_task(list, key);
+            if tasks.is_empty() {
+                warnings.push(PrintableWarning::NoMatchFoundForKey {
+                    requested_key: key.clone(),
+                });
+            }
+            so_far | tasks
+        })
+    };
+    let tasks = if cmd.keys.is_empty() {
+        list.incomplete_tasks()
+            .filter(|id| {
+                schedule
+                    .get(id)
+                    .is_some_and(|scheduled| scheduled.latest_finish.is_some())
+            })
+            .collect::<TaskSet>()
+    } else {
+        targets
+            .iter_sorted(list)
+            .fold(targets.clone(), |so_far, id| {
+                so_far | list.transitive_deps(id)
+            })
+    };
+    let mut critical = TaskSet::default();
+    for id in targets.iter_sorted(list) {
+        add_critical_path(list, &schedule, id, &mut critical);
+    }
+    let tasks = tasks
+        .iter_sorted(list)
+        .filter_map(|id| Some((id, schedule.get(&id)?)))
+        .collect::<Vec<_>>();
+    for &(id, scheduled) in &tasks {
+        if let Some(latest_finish) = scheduled.latest_finish {
+            if scheduled.earliest_finish > latest_finish {
+                warnings.push(PrintableWarning::CannotMeetDueDate {
+                    late_task: format_task_brief(list, id),
+                    latest_finish,
+                    earliest_finish: scheduled.earliest_finish,
+                });
+            }
+        }
+    }
+    Ok(PrintableAppSuccess {
+        warnings,
+        tasks: tasks
+            .into_iter()
+            .map(|(id, scheduled)| {
+                let mut task = format_task(list, id)
+                    .earliest_finish(scheduled.earliest_finish)
+                    .critical(critical.contains(id));
+                if let Some(slack) = scheduled.slack() {
+                    task = task.slack(slack);
+                }
+                task
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
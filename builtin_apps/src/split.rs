@@ -65,8 +65,14 @@ fn split(
                     Some(value) => value,
                     None => !keep && original_is_tag,
                 },
+                // The original task keeps repeating if it's kept, otherwise
+                // each of the new tasks repeats in its place.
+                recurrence: if keep { None } else { task.recurrence },
             };
-            list.add(options)
+            let notes = task.notes.clone();
+            let shard = list.add(options);
+            list.set_notes(shard, notes);
+            shard
         })
         .collect::<Vec<_>>();
     shards.iter().copied().for_each(|shard| {
@@ -85,6 +91,10 @@ fn split(
         });
     }
     let kept = if !keep {
+        // Keep the time logged on the task, on the first shard.
+        if let Some(&first) = shards.first() {
+            list.move_work(id, first);
+        }
         list.remove(id);
         TaskSet::default()
     } else {
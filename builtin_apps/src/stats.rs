@@ -0,0 +1,478 @@
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::Utc;
+use serde_json::json;
+use serde_json::Value;
+use todo_cli::Stats;
+use todo_model::TaskId;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableReport;
+use todo_printing::PrintableResult;
+use todo_time_format::format_duration_laconic;
+
+// Periods longer than this are counted by week instead of by day.
+const MAX_DAILY_PERIOD_DAYS: i64 = 28;
+
+// From no tasks to the most tasks.
+const SPARKLINE_LEVELS: &[u8] = b"_.:-=+*#";
+
+// A duration counts back from now, and is snapped to the start of the day if
+// it's a day or more, the way durations in snooze dates are.
+fn parse_since(
+    now: DateTime<Utc>,
+    chunks: &[String],
+) -> Result<Option<DateTime<Utc>>, PrintableError> {
+    if chunks.is_empty() {
+        return Ok(None);
+    }
+    let since_string = chunks.join(" ");
+    let now = now.with_timezone(&Local);
+    let since = match todo_time_format::parse_duration(&since_string) {
+        Ok(duration) => Duration::from_std(duration)
+            .ok()
+            .and_then(|duration| now.checked_sub_signed(duration))
+            .map(|since| {
+                if duration.as_secs() >= 24 * 60 * 60 {
+                    start_of_day(since)
+                } else {
+                    since
+                }
+            }),
+        Err(_) => todo_time_format::parse_time(
+            Local,
+            now,
+            &since_string,
+            todo_time_format::Snap::ToStart,
+        )
+        .ok(),
+    };
+    match since {
+        Some(since) if since <= now => Ok(Some(since.with_timezone(&Utc))),
+        _ => Err(PrintableError::CannotParseSinceDate {
+            cannot_parse: since_string,
+        }),
+    }
+}
+
+// Midnight might not exist where the clocks change, in which case the time
+// is left as it is.
+fn start_of_day(time: DateTime<Local>) -> DateTime<Local> {
+    time.date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .unwrap_or(time)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bucket {
+    Day,
+    Week,
+}
+
+impl Bucket {
+    // The first day of the bucket that |time| falls in, in the local time
+    // zone. Weeks start on Monday.
+    fn start(self, time: DateTime<Utc>) -> NaiveDate {
+        let date = time.with_timezone(&Local).date_naive();
+        match self {
+            Bucket::Day => date,
+            Bucket::Week => {
+                date - Duration::days(
+                    date.weekday().num_days_from_monday() as i64
+                )
+            }
+        }
+    }
+
+    fn days(self) -> i64 {
+        match self {
+            Bucket::Day => 1,
+            Bucket::Week => 7,
+        }
+    }
+}
+
+// How many tasks were created and completed in each bucket of the period.
+struct Throughput {
+    bucket: Bucket,
+    first: NaiveDate,
+    created: Vec<usize>,
+    completed: Vec<usize>,
+}
+
+impl Throughput {
+    fn new(list: &TodoList, now: DateTime<Utc>, since: DateTime<Utc>) -> Self {
+        let bucket = if (now - since).num_days() > MAX_DAILY_PERIOD_DAYS {
+            Bucket::Week
+        } else {
+            Bucket::Day
+        };
+        let first = bucket.start(since);
+        let len = ((bucket.start(now) - first).num_days() / bucket.days() + 1)
+            as usize;
+        let mut throughput = Throughput {
+            bucket,
+            first,
+            created: vec![0; len],
+            completed: vec![0; len],
+        };
+        for task in list.all_tasks().filter_map(|id| list.get(id)) {
+            if let Some(i) = throughput.index(since, task.creation_time) {
+                throughput.created[i] += 1;
+            }
+            if let Some(i) = task
+                .completion_time
+                .and_then(|time| throughput.index(since, time))
+            {
+                throughput.completed[i] += 1;
+            }
+        }
+        throughput
+    }
+
+    fn index(
+        &self,
+        since: DateTime<Utc>,
+        time: DateTime<Utc>,
+    ) -> Option<usize> {
+        if time < since {
+            return None;
+        }
+        let i = (self.bucket.start(time) - self.first).num_days()
+            / self.bucket.days();
+        usize::try_from(i).ok().filter(|&i| i < self.created.len())
+    }
+
+    fn starts(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        (0..self.created.len())
+            .map(|i| self.first + Duration::days(i as i64 * self.bucket.days()))
+    }
+}
+
+// The numbers reported for the whole list, and for each tag.
+#[derive(Default)]
+struct Summary {
+    created: usize,
+    completed: usize,
+    open: usize,
+    lead_times: Vec<Duration>,
+    on_time: usize,
+    with_due_date: usize,
+}
+
+impl Summary {
+    fn new(
+        list: &TodoList,
+        since: DateTime<Utc>,
+        tasks: impl Iterator<Item = TaskId>,
+    ) -> Self {
+        let mut summary = Summary::default();
+        for id in tasks {
+            let Some(task) = list.get(id) else {
+                continue;
+            };
+            if task.creation_time >= since {
+                summary.created += 1;
+            }
+            match task.completion_time {
+                Some(completion_time) if completion_time >= since => {
+                    summary.completed += 1;
+                    summary
+                        .lead_times
+                        .push(completion_time - task.creation_time);
+                    if let Some(due_date) = list.implicit_due_date(id).flatten()
+                    {
+                        summary.with_due_date += 1;
+                        if completion_time <= due_date {
+                            summary.on_time += 1;
+                        }
+                    }
+                }
+                Some(_) => {}
+                None => summary.open += 1,
+            }
+        }
+        summary.lead_times.sort();
+        summary
+    }
+
+    fn median_lead_time(&self) -> Option<Duration> {
+        let n = self.lead_times.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(self.lead_times[n / 2]),
+            _ => {
+                Some((self.lead_times[n / 2 - 1] + self.lead_times[n / 2]) / 2)
+            }
+        }
+    }
+
+    fn format_median_lead_time(&self) -> String {
+        self.median_lead_time()
+            .map(|median| {
+                // Durations under a second are shown as "0 seconds".
+                format_duration_laconic(Duration::seconds(
+                    median.num_seconds().max(0),
+                ))
+            })
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    fn format_on_time(&self) -> String {
+        if self.with_due_date == 0 {
+            return "-".to_string();
+        }
+        format!(
+            "{} of {} ({}%)",
+            self.on_time,
+            self.with_due_date,
+            self.on_time * 100 / self.with_due_date
+        )
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "created": self.created,
+            "completed": self.completed,
+            "open": self.open,
+            "median_secs_to_complete":
+                self.median_lead_time().map(|d| d.num_seconds()),
+            "on_time": self.on_time,
+            "with_due_date": self.with_due_date,
+        })
+    }
+}
+
+fn sparkline(counts: &[usize]) -> String {
+    let max = counts.iter().copied().max().unwrap_or(0);
+    let top = SPARKLINE_LEVELS.len() - 1;
+    counts
+        .iter()
+        .map(|&count| {
+            // Round up, so that any tasks at all show up above the baseline.
+            let level = if max == 0 {
+                0
+            } else {
+                (count * top).div_ceil(max)
+            };
+            SPARKLINE_LEVELS[level] as char
+        })
+        .collect()
+}
+
+// Lines up the columns of |rows|, with the first column aligned left and the
+// others aligned right.
+fn format_table(rows: &[Vec<String>]) -> String {
+    let num_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..num_columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| match i {
+                0 => format!("{cell:<width$}"),
+                _ => format!("{cell:>width$}"),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+fn cells<const N: usize>(cells: [&str; N]) -> Vec<String> {
+    cells.iter().map(|cell| cell.to_string()).collect()
+}
+
+struct Report<'a> {
+    since: Option<DateTime<Utc>>,
+    throughput: Throughput,
+    backlog: [(&'static str, usize); 3],
+    overall: Summary,
+    tags: Vec<(&'a str, Summary)>,
+}
+
+impl Report<'_> {
+    fn to_text(&self) -> String {
+        let throughput = &self.throughput;
+        let mut rows = vec![cells([
+            match throughput.bucket {
+                Bucket::Day => "Day",
+                Bucket::Week => "Week of",
+            },
+            "Created",
+            "Completed",
+        ])];
+        for ((start, created), completed) in throughput
+            .starts()
+            .zip(&throughput.created)
+            .zip(&throughput.completed)
+        {
+            rows.push(vec![
+                start.format("%Y-%m-%d").to_string(),
+                created.to_string(),
+                completed.to_string(),
+            ]);
+        }
+        let mut text = format_table(&rows);
+        text.push('\n');
+        text.push_str(&format_table(&[
+            vec![
+                "Created".to_string(),
+                sparkline(&throughput.created),
+                self.overall.created.to_string(),
+            ],
+            vec![
+                "Completed".to_string(),
+                sparkline(&throughput.completed),
+                self.overall.completed.to_string(),
+            ],
+        ]));
+        text.push('\n');
+        let mut rows = vec![cells(["Status", "Tasks"])];
+        for (status, count) in self.backlog {
+            rows.push(vec![status.to_string(), count.to_string()]);
+        }
+        text.push_str(&format_table(&rows));
+        text.push('\n');
+        text.push_str(&format!(
+            "Median time to complete: {}\nOn time: {}\n",
+            self.overall.format_median_lead_time(),
+            self.overall.format_on_time()
+        ));
+        if !self.tags.is_empty() {
+            text.push('\n');
+            let mut rows = vec![cells([
+                "Tag",
+                "Open",
+                "Created",
+                "Completed",
+                "Median",
+                "On time",
+            ])];
+            for (tag, summary) in &self.tags {
+                rows.push(vec![
+                    tag.to_string(),
+                    summary.open.to_string(),
+                    summary.created.to_string(),
+                    summary.completed.to_string(),
+                    summary.format_median_lead_time(),
+                    summary.format_on_time(),
+                ]);
+            }
+            text.push_str(&format_table(&rows));
+        }
+        text
+    }
+
+    fn to_json(&self) -> Value {
+        let throughput = &self.throughput;
+        let periods: Vec<Value> = throughput
+            .starts()
+            .zip(&throughput.created)
+            .zip(&throughput.completed)
+            .map(|((start, created), completed)| {
+                json!({
+                    "start": start.format("%Y-%m-%d").to_string(),
+                    "created": created,
+                    "completed": completed,
+                })
+            })
+            .collect();
+        json!({
+            "since": self.since,
+            "period": match throughput.bucket {
+                Bucket::Day => "day",
+                Bucket::Week => "week",
+            },
+            "periods": periods,
+            "backlog": self
+                .backlog
+                .iter()
+                .map(|(status, count)| (status.to_lowercase(), json!(count)))
+                .collect::<serde_json::Map<_, _>>(),
+            "overall": self.overall.to_json(),
+            "tags": self
+                .tags
+                .iter()
+                .map(|(tag, summary)| {
+                    let mut value = summary.to_json();
+                    if let Value::Object(map) = &mut value {
+                        map.insert("tag".to_string(), json!(tag));
+                    }
+                    value
+                })
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Stats,
+) -> PrintableResult<'list> {
+    let since = parse_since(now, &cmd.since).map_err(|e| vec![e])?;
+    let start = since.unwrap_or_else(|| {
+        list.all_tasks()
+            .filter_map(|id| list.get(id))
+            .map(|task| task.creation_time.min(now))
+            .min()
+            .unwrap_or(now)
+    });
+    let mut backlog = [("Incomplete", 0), ("Blocked", 0), ("Complete", 0)];
+    for id in list.all_tasks() {
+        match list.status(id) {
+            Some(TaskStatus::Incomplete) => backlog[0].1 += 1,
+            Some(TaskStatus::Blocked) => backlog[1].1 += 1,
+            Some(TaskStatus::Complete) => backlog[2].1 += 1,
+            None => {}
+        }
+    }
+    let tags = list
+        .all_tasks()
+        .filter_map(|tag| Some((tag, list.get(tag)?)))
+        .filter(|(_, task)| task.tag)
+        .map(|(tag, task)| {
+            let tagged = list.all_tasks().filter(move |&id| {
+                list.get(id)
+                    .is_some_and(|task| task.implicit_tags.contains(&tag))
+            });
+            (task.desc.as_ref(), Summary::new(list, start, tagged))
+        })
+        .filter(|(_, summary)| {
+            summary.open + summary.created + summary.completed > 0
+        })
+        .collect();
+    let report = Report {
+        since,
+        throughput: Throughput::new(list, now, start),
+        backlog,
+        overall: Summary::new(list, start, list.all_tasks()),
+        tags,
+    };
+    Ok(PrintableAppSuccess {
+        reports: vec![PrintableReport {
+            text: report.to_text(),
+            json: report.to_json(),
+        }],
+        ..Default::default()
+    })
+}
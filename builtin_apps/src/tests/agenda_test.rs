@@ -0,0 +1,167 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use todo_printing::AgendaHeading::*;
+use todo_printing::Plicit::*;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn agenda_empty_list() {
+    let mut fix = Fixture::default();
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn agenda_skips_tasks_without_dates() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn agenda_groups_by_due_date() {
+    let mut fix = Fixture::default();
+    // A Friday.
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --due 10 days");
+    fix.test("todo new b --due 4 days");
+    fix.test("todo new c --due tomorrow");
+    fix.test("todo new d --due 5pm");
+    fix.test("todo new e --due 1 hour");
+    fix.test("todo new f");
+    fix.clock.now += Duration::hours(2);
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("e", 1, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 01, 10, 00, 00)))
+                .agenda_heading(Overdue),
+        )
+        .printed_task(
+            &task("d", 2, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 01, 17, 00, 00)))
+                .agenda_heading(Today),
+        )
+        .printed_task(
+            &task("c", 3, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 02, 23, 59, 59)))
+                .agenda_heading(Tomorrow),
+        )
+        .printed_task(
+            &task("b", 4, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 05, 23, 59, 59)))
+                .agenda_heading(Day(date(2024, 03, 05))),
+        )
+        .printed_task(
+            &task("a", 5, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 11, 23, 59, 59)))
+                .agenda_heading(Later),
+        )
+        .end();
+}
+
+#[test]
+fn agenda_shows_heading_once_per_day() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b --due 5pm");
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 01, 17, 00, 00)))
+                .agenda_heading(Today),
+        )
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 01, 17, 00, 00))),
+        )
+        .end();
+}
+
+#[test]
+fn agenda_uses_implicit_due_date() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a");
+    fix.test("todo new b -p a --due tomorrow");
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .due_date(Implicit(ymdhms(2024, 03, 02, 23, 59, 59)))
+                .adeps_stats(1, 1)
+                .agenda_heading(Tomorrow),
+        )
+        .printed_task(
+            &task("b", 2, Blocked)
+                .due_date(Explicit(ymdhms(2024, 03, 02, 23, 59, 59)))
+                .deps_stats(1, 1),
+        )
+        .end();
+}
+
+#[test]
+fn agenda_shows_snoozed_task_on_wake_and_due_days() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --due 3 days");
+    fix.test("todo snooze a --until tomorrow");
+    fix.test("todo agenda")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Blocked)
+                .due_date(Explicit(ymdhms(2024, 03, 04, 23, 59, 59)))
+                .start_date(ymdhms(2024, 03, 02, 00, 00, 00))
+                .agenda_heading(Tomorrow),
+        )
+        .printed_task(
+            &task("a", 1, Blocked)
+                .due_date(Explicit(ymdhms(2024, 03, 04, 23, 59, 59)))
+                .start_date(ymdhms(2024, 03, 02, 00, 00, 00))
+                .agenda_heading(Day(date(2024, 03, 04))),
+        )
+        .end();
+}
+
+#[test]
+fn agenda_with_fewer_days() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --due tomorrow");
+    fix.test("todo new b --due 2 days");
+    fix.test("todo agenda --days 2")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 02, 23, 59, 59)))
+                .agenda_heading(Tomorrow),
+        )
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .due_date(Explicit(ymdhms(2024, 03, 03, 23, 59, 59)))
+                .agenda_heading(Later),
+        )
+        .end();
+}
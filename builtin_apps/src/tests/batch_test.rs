@@ -0,0 +1,224 @@
+use std::io::Write;
+
+use tempfile::NamedTempFile;
+use todo_printing::Action::*;
+use todo_printing::BriefPrintableTask;
+use todo_printing::PrintableError;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn file_with(contents: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(contents.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn batch_runs_each_line() {
+    let file = file_with("new a b\n\n# Finish the first one.\ncheck a\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a", 0, Complete).action(Check))
+        .printed_task(&task("b", 1, Incomplete).action(New))
+        .end();
+}
+
+#[test]
+fn batch_accepts_leading_todo() {
+    let file = file_with("todo new a\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).action(New))
+        .end();
+}
+
+#[test]
+fn batch_refers_to_named_tasks() {
+    let file = file_with(concat!(
+        "new 'write draft' --as $draft\n",
+        "new 'get feedback' --blocked-by $draft --as feedback\n",
+        "new publish --blocked-by $feedback\n",
+        "punt $draft\n",
+    ));
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("write draft", 1, Incomplete)
+                .action(Punt)
+                .adeps_stats(1, 2),
+        )
+        .printed_task(
+            &task("get feedback", 2, Blocked)
+                .action(New)
+                .deps_stats(1, 1),
+        )
+        .printed_task(&task("publish", 3, Blocked).action(New).deps_stats(1, 2))
+        .end();
+}
+
+#[test]
+fn batch_unknown_name_changes_nothing() {
+    let file = file_with("new a\ncheck $b\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::UnknownBatchName {
+            name: "b".to_string(),
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 2,
+            command: "check $b".to_string(),
+        })
+        .end();
+    fix.test("todo -a").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn batch_failed_command_rolls_back_earlier_commands() {
+    let file = file_with("new a b --chain\ncheck b\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotCheckBecauseBlocked {
+            cannot_check: BriefPrintableTask::new(2, Blocked),
+            blocked_by: vec![BriefPrintableTask::new(1, Incomplete)],
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 2,
+            command: "check b".to_string(),
+        })
+        .end();
+    fix.test("todo -a").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn batch_keep_going_skips_failed_commands() {
+    let file = file_with("new a b --chain\ncheck b\nnew c\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo batch --keep-going {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_warning(&PrintableWarning::SkippedBatchCommand {
+        line: 2,
+        command: "check b".to_string(),
+        errors: vec![PrintableError::CannotCheckBecauseBlocked {
+            cannot_check: BriefPrintableTask::new(2, Blocked),
+            blocked_by: vec![BriefPrintableTask::new(1, Incomplete)],
+        }],
+    })
+    .printed_task(&task("a", 1, Incomplete).action(New).adeps_stats(1, 1))
+    .printed_task(&task("c", 2, Incomplete).action(New))
+    .printed_task(&task("b", 3, Blocked).action(New).deps_stats(1, 1))
+    .end();
+}
+
+#[test]
+fn batch_cannot_parse_command() {
+    let file = file_with("new a\nfrobnicate\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseBatchCommand {
+            explanation: "unrecognized subcommand 'frobnicate'".to_string(),
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 2,
+            command: "frobnicate".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn batch_cannot_undo() {
+    let file = file_with("undo\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseBatchCommand {
+            explanation: "'todo undo' cannot be used in a batch".to_string(),
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 1,
+            command: "undo".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn batch_name_needs_exactly_one_new_task() {
+    let file = file_with("new a b --as $x\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotNameBatchTasks {
+            name: "x".to_string(),
+            created: 2,
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 1,
+            command: "new a b --as $x".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn batch_is_undone_at_once() {
+    let file = file_with("new a\nnew b\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::Yes);
+    fix.test("todo undo").modified(Mutated::Yes);
+    fix.test("todo -a").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn batch_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing.todo");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", path.display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotReadFile {
+            path: path.display().to_string(),
+            explanation: "No such file or directory (os error 2)".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn batch_cannot_dry_run_one_command() {
+    let file = file_with("rm a --dry-run\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo batch {}", file.path().display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseBatchCommand {
+            explanation: "use 'todo batch --dry-run' to preview the whole \
+                          batch"
+                .to_string(),
+        })
+        .printed_error(&PrintableError::BatchCommandFailed {
+            line: 1,
+            command: "rm a --dry-run".to_string(),
+        })
+        .end();
+}
@@ -1,8 +1,12 @@
+#![allow(clippy::zero_prefixed_literal)]
+
 use todo_printing::Action::*;
 use todo_printing::BriefPrintableTask;
+use todo_printing::Plicit::*;
 use todo_printing::PrintableError;
 use todo_printing::PrintableWarning;
 use todo_printing::Status::*;
+use todo_testing::ymdhms;
 
 use super::testing::task;
 use super::testing::Fixture;
@@ -30,6 +34,20 @@ fn check_by_name() {
         .end();
 }
 
+#[test]
+fn check_by_id() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    let b = fix.list.lookup_by_number(2).unwrap();
+    let short_id = fix.list.get(b).unwrap().short_id.clone();
+    fix.test("todo punt a");
+    fix.test(&format!("todo check '#{short_id}'"))
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("b", 0, Complete).action(Check).short_id(&short_id))
+        .end();
+}
+
 #[test]
 fn check_task_with_incomplete_dependencies() {
     let mut fix = Fixture::default();
@@ -242,3 +260,42 @@ fn check_blocking_chain() {
         .printed_task(&task("c", 0, Complete).action(Check))
         .end();
 }
+
+#[test]
+fn check_repeating_task_respawns_it() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 00, 00);
+    fix.test("todo new standup --repeat daily");
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 30, 00);
+    fix.test("todo check standup")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("standup", 0, Complete).action(Check))
+        .printed_task(
+            &task("standup", 1, Blocked)
+                .action(New)
+                .start_date(ymdhms(2024, 01, 02, 00, 00, 00)),
+        )
+        .end();
+}
+
+#[test]
+fn check_repeating_task_moves_due_date_forward() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 01, 01, 00, 00, 00);
+    fix.test("todo new report --repeat weekly --due 2024 jan 5");
+    fix.clock.now = ymdhms(2024, 01, 04, 12, 00, 00);
+    fix.test("todo check report")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("report", 0, Complete).action(Check).punctuality(
+            ymdhms(2024, 01, 04, 12, 00, 00) - ymdhms(2024, 01, 05, 23, 59, 59),
+        ))
+        .printed_task(
+            &task("report", 1, Blocked)
+                .action(New)
+                .start_date(ymdhms(2024, 01, 08, 00, 00, 00))
+                .due_date(Explicit(ymdhms(2024, 01, 12, 23, 59, 59))),
+        )
+        .end();
+}
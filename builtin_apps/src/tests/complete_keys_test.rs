@@ -0,0 +1,72 @@
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn complete_keys_empty_list() {
+    let mut fix = Fixture::default();
+    fix.test("todo __complete-keys check")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("")
+        .end();
+}
+
+#[test]
+fn complete_keys_quotes_descriptions() {
+    let mut fix = Fixture::default();
+    fix.test("todo new 'walk the dog' b");
+    fix.test("todo check b");
+    fix.test("todo __complete-keys get")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("1\n'walk the dog'\n0\nb\n")
+        .end();
+}
+
+#[test]
+fn complete_keys_raw_descriptions() {
+    let mut fix = Fixture::default();
+    fix.test("todo new 'walk the dog'");
+    fix.test("todo __complete-keys get --raw")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("1\nwalk the dog\n")
+        .end();
+}
+
+#[test]
+fn complete_keys_incomplete_tasks_for_check() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    fix.test("todo new c");
+    fix.test("todo check c");
+    fix.test("todo __complete-keys check")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("1\na\n2\nb\n")
+        .end();
+}
+
+#[test]
+fn complete_keys_complete_tasks_for_restore() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    fix.test("todo check a c");
+    fix.test("todo __complete-keys restore")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("0\nc\n-1\na\n")
+        .end();
+}
+
+#[test]
+fn complete_keys_snoozed_tasks_for_unsnooze() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo snooze b --until 1 day");
+    fix.test("todo __complete-keys unsnooze")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document("2\nb\n")
+        .end();
+}
@@ -0,0 +1,39 @@
+use todo_cli::CompletionShell;
+use todo_cli::Completions;
+
+fn script(shell: CompletionShell) -> String {
+    crate::completions::run(&Completions { shell })
+        .unwrap()
+        .document
+        .unwrap()
+}
+
+#[test]
+fn completions_bash_completes_keys() {
+    let script = script(CompletionShell::Bash);
+    assert!(script.contains("_todo()"));
+    assert!(script.contains("todo __complete-keys \"$subcommand\""));
+    assert!(script
+        .ends_with("complete -F _todo_keys -o bashdefault -o default todo\n"));
+}
+
+#[test]
+fn completions_zsh_completes_keys() {
+    let script = script(CompletionShell::Zsh);
+    assert!(script.starts_with("#compdef todo"));
+    assert!(script.contains("todo __complete-keys $subcommand --raw"));
+    assert!(script.ends_with("compdef _todo_keys todo\n"));
+}
+
+#[test]
+fn completions_fish_completes_keys() {
+    let script = script(CompletionShell::Fish);
+    assert!(script.contains("complete -c todo"));
+    assert!(script.contains("todo __complete-keys (commandline -opc)[2] --raw"));
+}
+
+#[test]
+fn completions_hide_complete_keys() {
+    let script = script(CompletionShell::Fish);
+    assert!(!script.contains("-a \"__complete-keys\""));
+}
@@ -0,0 +1,142 @@
+use todo_config::ConfigModel;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
+
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn config_value(key: &str, value: &str) -> PrintableInfo {
+    PrintableInfo::ConfigValue {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn valid_keys() -> Vec<String> {
+    ConfigModel::KEYS
+        .iter()
+        .map(|key| key.to_string())
+        .collect()
+}
+
+#[test]
+fn show_all_defaults() {
+    let mut fix = Fixture::default();
+    fix.test("todo config")
+        .modified(Mutated::No)
+        .read_only(true)
+        .validate()
+        .printed_info(&config_value("paginator_cmd", "less -rFX"))
+        .printed_info(&config_value("text_editor_cmd", "vim"))
+        .printed_info(&config_value("undo_limit", "20"))
+        .printed_info(&config_value("backup_count", "5"))
+        .printed_info(&config_value("lock_timeout_secs", "10"))
+        .printed_info(&config_value("show_ids", "false"))
+        .printed_info(&config_value("shell_autosave", "true"))
+        .end();
+}
+
+#[test]
+fn show_single_value() {
+    let mut fix = Fixture::default();
+    fix.test("todo config text_editor_cmd")
+        .modified(Mutated::No)
+        .read_only(true)
+        .validate()
+        .printed_info(&config_value("text_editor_cmd", "vim"))
+        .end();
+    assert_eq!(fix.config, ConfigModel::default());
+}
+
+#[test]
+fn set_value() {
+    let mut fix = Fixture::default();
+    fix.test("todo config text_editor_cmd nano")
+        .modified(Mutated::No)
+        .read_only(false)
+        .validate()
+        .printed_info(&config_value("text_editor_cmd", "nano"))
+        .end();
+    assert_ne!(fix.config, ConfigModel::default());
+    fix.test("todo config")
+        .modified(Mutated::No)
+        .validate()
+        .printed_info(&config_value("paginator_cmd", "less -rFX"))
+        .printed_info(&config_value("text_editor_cmd", "nano"))
+        .printed_info(&config_value("undo_limit", "20"))
+        .printed_info(&config_value("backup_count", "5"))
+        .printed_info(&config_value("lock_timeout_secs", "10"))
+        .printed_info(&config_value("show_ids", "false"))
+        .printed_info(&config_value("shell_autosave", "true"))
+        .end();
+}
+
+#[test]
+fn set_command_with_arguments() {
+    let mut fix = Fixture::default();
+    fix.test("todo config paginator_cmd 'more -d'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_info(&config_value("paginator_cmd", "more -d"))
+        .end();
+    fix.test("todo config paginator_cmd -- less -R")
+        .modified(Mutated::No)
+        .validate()
+        .printed_info(&config_value("paginator_cmd", "less -R"))
+        .end();
+}
+
+#[test]
+fn reset_value() {
+    let mut fix = Fixture::default();
+    fix.test("todo config text_editor_cmd nano");
+    fix.test("todo config text_editor_cmd --reset")
+        .modified(Mutated::No)
+        .read_only(false)
+        .validate()
+        .printed_info(&config_value("text_editor_cmd", "vim"))
+        .end();
+    assert_eq!(fix.config, ConfigModel::default());
+}
+
+#[test]
+fn show_unknown_key() {
+    let mut fix = Fixture::default();
+    fix.test("todo config pager")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::UnknownConfigKey {
+            key: "pager".to_string(),
+            valid_keys: valid_keys(),
+        })
+        .end();
+}
+
+#[test]
+fn set_unknown_key() {
+    let mut fix = Fixture::default();
+    fix.test("todo config pager more")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::UnknownConfigKey {
+            key: "pager".to_string(),
+            valid_keys: valid_keys(),
+        })
+        .end();
+    assert_eq!(fix.config, ConfigModel::default());
+}
+
+#[test]
+fn set_unparseable_value() {
+    let mut fix = Fixture::default();
+    fix.test("todo config paginator_cmd '\"less'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseConfigValue {
+            key: "paginator_cmd".to_string(),
+            value: "\"less".to_string(),
+            explanation: "unbalanced quotes".to_string(),
+        })
+        .end();
+    assert_eq!(fix.config, ConfigModel::default());
+}
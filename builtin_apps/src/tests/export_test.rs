@@ -0,0 +1,267 @@
+use todo_lookup_key::Key;
+use todo_printing::PrintableWarning;
+
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn node_id(fix: &Fixture, number: i32) -> String {
+    let id = fix.list.lookup_by_number(number).unwrap();
+    format!("task_{}", fix.list.get(id).unwrap().short_id)
+}
+
+fn local_time(fix: &Fixture) -> String {
+    let now = fix.clock.now.with_timezone(&chrono::Local);
+    now.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+#[test]
+fn export_empty_list_as_dot() {
+    let mut fix = Fixture::default();
+    fix.test("todo export --format dot")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(
+            "digraph todo {\n  node [shape=box, style=rounded];\n}\n",
+        )
+        .end();
+}
+
+#[test]
+fn export_dot() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    let (a, b) = (node_id(&fix, 1), node_id(&fix, 2));
+    fix.test("todo export --format dot")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {a} [label=\"1) a\", color=\"goldenrod\"];\n",
+                "  {b} [label=\"2) b\", color=\"red\"];\n",
+                "  {a} -> {b};\n",
+                "}}\n",
+            ),
+            a = a,
+            b = b,
+        ))
+        .end();
+}
+
+#[test]
+fn export_mermaid() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    let (a, b) = (node_id(&fix, 1), node_id(&fix, 2));
+    fix.test("todo export --format mermaid")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "flowchart TD\n",
+                "    {a}[\"1) a\"]:::incomplete\n",
+                "    {b}[\"2) b\"]:::blocked\n",
+                "    {a} --> {b}\n",
+                "    classDef incomplete stroke:#b8860b\n",
+                "    classDef blocked stroke:#ff0000\n",
+                "    classDef complete stroke:#008b00,color:#666666\n",
+            ),
+            a = a,
+            b = b,
+        ))
+        .end();
+}
+
+#[test]
+fn export_subgraph_of_keys() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    fix.test("todo new c d --chain");
+    let (c, d) = (node_id(&fix, 2), node_id(&fix, 4));
+    fix.test("todo export --format dot d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {c} [label=\"2) c\", color=\"goldenrod\"];\n",
+                "  {d} [label=\"4) d\", color=\"red\"];\n",
+                "  {c} -> {d};\n",
+                "}}\n",
+            ),
+            c = c,
+            d = d,
+        ))
+        .end();
+}
+
+#[test]
+fn export_excludes_complete_tasks_by_default() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo check a");
+    let b = node_id(&fix, 1);
+    fix.test("todo export --format dot")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {b} [label=\"1) b\", color=\"goldenrod\"];\n",
+                "}}\n",
+            ),
+            b = b,
+        ))
+        .end();
+}
+
+#[test]
+fn export_include_done() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo check a");
+    let (a, b) = (node_id(&fix, 0), node_id(&fix, 1));
+    fix.test("todo export --format dot -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {a} [label=\"0) a\", color=\"green4\", ",
+                "fontcolor=\"gray40\"];\n",
+                "  {b} [label=\"1) b\", color=\"goldenrod\"];\n",
+                "}}\n",
+            ),
+            a = a,
+            b = b,
+        ))
+        .end();
+}
+
+#[test]
+fn export_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo export --format mermaid b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: Key::ByName("b".to_string()),
+        })
+        .printed_document(concat!(
+            "flowchart TD\n",
+            "    classDef incomplete stroke:#b8860b\n",
+            "    classDef blocked stroke:#ff0000\n",
+            "    classDef complete stroke:#008b00,color:#666666\n",
+        ))
+        .end();
+}
+
+#[test]
+fn export_todo_txt() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo new work --tag -p a");
+    fix.test("todo priority b --is 2");
+    fix.test("todo check a");
+    fix.test("todo export --format todo.txt -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(concat!(
+            "(Y) 2000-01-01 b\n",
+            "x 2000-01-01 2000-01-01 a +work\n",
+        ))
+        .end();
+}
+
+#[test]
+fn export_ics() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    let uid = |number| format!("{}@todo", &node_id(&fix, number)[5..]);
+    let (a, b) = (uid(1), uid(2));
+    fix.clock.now += chrono::Duration::days(1);
+    fix.test("todo export --format ics")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "BEGIN:VCALENDAR\r\n",
+                "VERSION:2.0\r\n",
+                "PRODID:-//todo//EN\r\n",
+                "BEGIN:VTODO\r\n",
+                "UID:{a}\r\n",
+                "DTSTAMP:20000102T000000Z\r\n",
+                "CREATED:20000101T000000Z\r\n",
+                "SUMMARY:a\r\n",
+                "DTSTART:20000101T000000Z\r\n",
+                "STATUS:NEEDS-ACTION\r\n",
+                "END:VTODO\r\n",
+                "BEGIN:VTODO\r\n",
+                "UID:{b}\r\n",
+                "DTSTAMP:20000102T000000Z\r\n",
+                "CREATED:20000101T000000Z\r\n",
+                "SUMMARY:b\r\n",
+                "DTSTART:20000101T000000Z\r\n",
+                "STATUS:NEEDS-ACTION\r\n",
+                "RELATED-TO;RELTYPE=DEPENDS-ON:{a}\r\n",
+                "END:VTODO\r\n",
+                "END:VCALENDAR\r\n",
+            ),
+            a = a,
+            b = b,
+        ))
+        .end();
+}
+
+#[test]
+fn export_csv_excludes_complete_tasks_by_default() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo check a");
+    let b = node_id(&fix, 1)[5..].to_string();
+    let now = local_time(&fix);
+    fix.test("todo export --format csv")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "position,id,description,status,creation_time,",
+                "completion_time,priority,implicit_priority,due_date,",
+                "implicit_due_date,budget,start_date,tag,implicit_tags,deps,",
+                "adeps\r\n",
+                "1,{b},b,incomplete,{now},,0,0,,,0,{now},false,,,\r\n",
+            ),
+            b = b,
+            now = now,
+        ))
+        .end();
+}
+
+#[test]
+fn export_csv_include_done() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo check a");
+    let a = node_id(&fix, 0)[5..].to_string();
+    let now = local_time(&fix);
+    fix.test("todo export --format csv --include-done")
+        .modified(Mutated::No)
+        .validate()
+        .printed_document(&format!(
+            concat!(
+                "position,id,description,status,creation_time,",
+                "completion_time,priority,implicit_priority,due_date,",
+                "implicit_due_date,budget,start_date,tag,implicit_tags,deps,",
+                "adeps\r\n",
+                "0,{a},a,complete,{now},{now},0,0,,,0,{now},false,,,\r\n",
+            ),
+            a = a,
+            now = now,
+        ))
+        .end();
+}
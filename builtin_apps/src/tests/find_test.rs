@@ -157,3 +157,26 @@ fn find_incomplete_matches_with_tag() {
         )
         .end();
 }
+
+#[test]
+fn find_ignores_notes_by_default() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo note a --append xyz");
+    fix.test("todo find y")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn find_in_notes() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo note a --append XYZ");
+    fix.test("todo find y --notes")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).notes("XYZ").action(Select))
+        .end();
+}
@@ -490,3 +490,25 @@ fn show_complete_ambiguous_tasks_when_requested() {
         )
         .end();
 }
+
+#[test]
+fn get_shows_notes_of_requested_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    fix.test("todo note a b --append hello");
+    fix.test("todo get b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete).notes("hello").adeps_stats(1, 1),
+        )
+        .printed_task(
+            &task("b", 2, Blocked)
+                .notes("hello")
+                .show_notes(true)
+                .action(Select)
+                .truncate_tags_if_needed(false)
+                .deps_stats(1, 1),
+        )
+        .end();
+}
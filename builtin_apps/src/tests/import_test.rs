@@ -0,0 +1,290 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use std::io::Write;
+
+use tempfile::NamedTempFile;
+use todo_printing::Action::*;
+use todo_printing::Plicit::*;
+use todo_printing::PrintableError;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn file_with(contents: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(contents.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn import_todo_txt() {
+    let file = file_with("(A) a\nb\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format todo.txt {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("a", 1, Incomplete).action(New).priority(Explicit(26)))
+    .printed_task(&task("b", 2, Incomplete).action(New))
+    .end();
+}
+
+#[test]
+fn import_todo_txt_projects_as_tags() {
+    let file = file_with("a +work\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format todo.txt {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(
+        &task("a", 1, Incomplete)
+            .action(New)
+            .tag("work")
+            .adeps_stats(1, 1),
+    )
+    .printed_task(
+        &task("work", 2, Blocked)
+            .action(New)
+            .as_tag()
+            .deps_stats(1, 1),
+    )
+    .end();
+}
+
+#[test]
+fn import_todo_txt_complete_task() {
+    let file = file_with("x 1999-12-31 a\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format todo.txt {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("a", 0, Complete).action(New))
+    .end();
+}
+
+#[test]
+fn import_ics() {
+    let file = file_with(concat!(
+        "BEGIN:VCALENDAR\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:1\r\n",
+        "SUMMARY:a\r\n",
+        "PRIORITY:8\r\n",
+        "END:VTODO\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:2\r\n",
+        "SUMMARY:b\r\n",
+        "RELATED-TO;RELTYPE=DEPENDS-ON:1\r\n",
+        "END:VTODO\r\n",
+        "END:VCALENDAR\r\n",
+    ));
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format ics {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(
+        &task("a", 1, Incomplete)
+            .action(New)
+            .priority(Explicit(2))
+            .adeps_stats(1, 1),
+    )
+    .printed_task(&task("b", 2, Blocked).action(New).deps_stats(1, 1))
+    .end();
+}
+
+#[test]
+fn import_md() {
+    let file = file_with("- [ ] a\n  - [ ] b\n  - [x] c\n");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format md {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("c", 0, Complete).action(New))
+    .printed_task(&task("b", 1, Incomplete).action(New).adeps_stats(1, 1))
+    .printed_task(&task("a", 2, Blocked).action(New).deps_stats(1, 2))
+    .end();
+}
+
+#[test]
+fn import_org() {
+    let file = file_with(concat!(
+        "* TODO a\n",
+        "** DONE b\n",
+        "* TODO c\n",
+        "  DEADLINE: <2000-01-03 Mon 12:00>\n",
+    ));
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format org {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("b", 0, Complete).action(New))
+    .printed_task(
+        &task("c", 1, Incomplete)
+            .action(New)
+            .due_date(Explicit(ymdhms(2000, 01, 03, 12, 00, 00))),
+    )
+    .printed_task(&task("a", 2, Incomplete).action(New))
+    .end();
+}
+
+#[test]
+fn import_taskwarrior() {
+    let file = file_with(concat!(
+        "[{\"uuid\":\"1\",\"description\":\"a\",\"priority\":\"M\"},",
+        "{\"uuid\":\"2\",\"description\":\"b\",\"depends\":[\"1\"]}]",
+    ));
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format taskwarrior {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(
+        &task("a", 1, Incomplete)
+            .action(New)
+            .priority(Explicit(2))
+            .adeps_stats(1, 1),
+    )
+    .printed_task(&task("b", 2, Blocked).action(New).deps_stats(1, 1))
+    .end();
+}
+
+#[test]
+fn import_invalid_taskwarrior_json() {
+    let file = file_with("[{\"uuid\":\"1\"}]");
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format taskwarrior {}",
+        file.path().display()
+    ))
+    .modified(Mutated::No)
+    .validate()
+    .printed_error(&PrintableError::CannotParseFile {
+        path: file.path().display().to_string(),
+        explanation: "missing field `description` at line 1 column 13"
+            .to_string(),
+    })
+    .end();
+}
+
+#[test]
+fn import_skips_existing_tasks() {
+    let file = file_with("a\nb\n");
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test(&format!(
+        "todo import --format todo.txt {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("b", 2, Incomplete).action(New))
+    .end();
+}
+
+#[test]
+fn import_nothing_new() {
+    let file = file_with("a\n");
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test(&format!(
+        "todo import --format todo.txt {}",
+        file.path().display()
+    ))
+    .modified(Mutated::No)
+    .validate()
+    .end();
+}
+
+#[test]
+fn import_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing.txt");
+    let mut fix = Fixture::default();
+    fix.test(&format!("todo import --format todo.txt {}", path.display()))
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotReadFile {
+            path: path.display().to_string(),
+            explanation: "No such file or directory (os error 2)".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn export_and_import_taskwarrior_round_trip() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo check a");
+    let file =
+        file_with(&fix.test("todo export --format taskwarrior").document());
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format taskwarrior {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("a", 0, Complete).action(New))
+    .printed_task(&task("b", 1, Incomplete).action(New).adeps_stats(1, 1))
+    .printed_task(&task("c", 2, Blocked).action(New).deps_stats(1, 2))
+    .end();
+    // The block on the complete task is kept.
+    fix.test("todo restore a")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete).action(Uncheck).adeps_stats(1, 2),
+        )
+        .printed_task(&task("b", 2, Blocked).action(Lock).deps_stats(1, 1))
+        .end();
+}
+
+#[test]
+fn export_and_import_ics_round_trip() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo check a");
+    let file = file_with(&fix.test("todo export --format ics").document());
+    let mut fix = Fixture::default();
+    fix.test(&format!(
+        "todo import --format ics {}",
+        file.path().display()
+    ))
+    .modified(Mutated::Yes)
+    .validate()
+    .printed_task(&task("a", 0, Complete).action(New))
+    .printed_task(&task("b", 1, Incomplete).action(New).adeps_stats(1, 1))
+    .printed_task(&task("c", 2, Blocked).action(New).deps_stats(1, 2))
+    .end();
+    // The block on the complete task is kept.
+    fix.test("todo restore a")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete).action(Uncheck).adeps_stats(1, 2),
+        )
+        .printed_task(&task("b", 2, Blocked).action(Lock).deps_stats(1, 1))
+        .end();
+}
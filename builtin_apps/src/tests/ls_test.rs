@@ -0,0 +1,166 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use todo_printing::Plicit::*;
+use todo_printing::PrintableError;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn ls_empty_list() {
+    let mut fix = Fixture::default();
+    fix.test("todo ls priority>1")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn ls_without_query_shows_incomplete_and_blocked_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    fix.test("todo block 3 --on 2");
+    fix.test("todo check 1");
+    fix.test("todo ls")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete).adeps_stats(1, 1))
+        .printed_task(&task("c", 2, Blocked).deps_stats(1, 1))
+        .end();
+}
+
+#[test]
+fn ls_by_priority() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a --priority 1");
+    fix.test("todo new b --priority 2");
+    fix.test("todo new c");
+    fix.test("todo ls 'priority>=2'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete).priority(Explicit(2)))
+        .end();
+}
+
+#[test]
+fn ls_by_due_date_and_priority() {
+    let mut fix = Fixture::default();
+    // Monday.
+    fix.clock.now = ymdhms(2021, 04, 12, 14, 00, 00);
+    let thursday = ymdhms(2021, 04, 15, 23, 59, 59);
+    let saturday = ymdhms(2021, 04, 17, 23, 59, 59);
+    fix.test("todo new a --due thursday --priority 2");
+    fix.test("todo new b --due thursday");
+    fix.test("todo new c --due saturday --priority 2");
+    fix.test("todo ls 'due<friday and priority>=2'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .priority(Explicit(2))
+                .due_date(Explicit(thursday)),
+        )
+        .end();
+    fix.test("todo ls 'due>friday'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("c", 2, Incomplete)
+                .priority(Explicit(2))
+                .due_date(Explicit(saturday)),
+        )
+        .end();
+}
+
+#[test]
+fn ls_by_tag_and_not_blocked() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo new work --tag -p a b");
+    fix.test("todo block b --on a");
+    fix.test("todo new c");
+    fix.test("todo ls tag:work and not blocked")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).adeps_stats(1, 2).tag("work"))
+        .end();
+}
+
+#[test]
+fn ls_by_budget() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a --budget 1 hour");
+    fix.test("todo new b --budget 3 hours");
+    fix.test("todo ls 'budget>2h'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 2, Incomplete).budget(Duration::hours(3)))
+        .end();
+}
+
+#[test]
+fn ls_by_regex() {
+    let mut fix = Fixture::default();
+    fix.test("todo new 'buy milk' 'buy bread' 'eat bread'");
+    fix.test("todo ls 'desc~/^buy/'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("buy milk", 1, Incomplete))
+        .printed_task(&task("buy bread", 2, Incomplete))
+        .end();
+}
+
+#[test]
+fn ls_excludes_complete_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new aa ab");
+    fix.test("todo check 1");
+    fix.test("todo ls desc:a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("ab", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn ls_include_done() {
+    let mut fix = Fixture::default();
+    fix.test("todo new aa ab");
+    fix.test("todo check 1");
+    fix.test("todo ls desc:a -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("aa", 0, Complete))
+        .printed_task(&task("ab", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn ls_includes_complete_tasks_if_query_mentions_completion() {
+    let mut fix = Fixture::default();
+    fix.test("todo new aa ab");
+    fix.test("todo check 1");
+    fix.test("todo ls done")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("aa", 0, Complete))
+        .end();
+}
+
+#[test]
+fn ls_invalid_query() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo ls 'due<someday'")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseQuery {
+            query: "due<someday".to_string(),
+            explanation: "cannot parse date \"someday\"".to_string(),
+        })
+        .end();
+}
@@ -1,9 +1,11 @@
 #![allow(clippy::zero_prefixed_literal)]
 
+use chrono::Duration;
 use todo_printing::Action::*;
 use todo_printing::BriefPrintableTask;
 use todo_printing::Plicit::*;
 use todo_printing::PrintableError;
+use todo_printing::PrintableWarning;
 use todo_printing::Status::*;
 use todo_testing::ymdhms;
 
@@ -287,3 +289,85 @@ fn trim_leading_and_trailing_whitespace_from_desc() {
         .printed_task(&task("abc", 1, Incomplete).action(Select))
         .end();
 }
+
+#[test]
+fn merge_keeps_logged_time() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b c");
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 30, 00);
+    fix.test("todo stop");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 00, 00);
+    fix.test("todo start b");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 15, 00);
+    fix.test("todo merge a b --into ab")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("ab", 2, Incomplete)
+                .time_spent(Duration::minutes(45))
+                .action(Select),
+        )
+        .end();
+    fix.test("todo stop")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoTimerRunning)
+        .end();
+}
+
+#[test]
+fn merge_joins_notes() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    fix.test("todo note a --append hello");
+    fix.test("todo note c --append goodbye");
+    fix.test("todo merge a b c --into abc")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("abc", 1, Incomplete)
+                .notes("hello\ngoodbye")
+                .action(Select),
+        )
+        .end();
+}
+
+#[test]
+fn merge_keeps_recurrence() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 00, 00);
+    fix.test("todo new a b --repeat daily");
+    fix.test("todo new c");
+    fix.test("todo merge a b c --into abc");
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 30, 00);
+    fix.test("todo check abc")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("abc", 0, Complete).action(Check))
+        .printed_task(
+            &task("abc", 1, Blocked)
+                .action(New)
+                .start_date(ymdhms(2024, 01, 02, 00, 00, 00)),
+        )
+        .end();
+}
+
+#[test]
+fn merge_tasks_with_different_recurrences() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a --repeat daily");
+    fix.test("todo new b --repeat weekly");
+    fix.test("todo new c");
+    fix.test("todo merge a b c --into abc")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotMergeRecurrences {
+            recurring: vec![
+                BriefPrintableTask::new(1, Incomplete),
+                BriefPrintableTask::new(2, Incomplete),
+            ],
+        })
+        .end();
+}
@@ -1008,3 +1008,25 @@ fn by_task_by_name_that_matches_multiple_incomplete_tasks_inserted_by_all_tasks(
         .printed_task(&task("c2", 7, Blocked).deps_stats(2, 4))
         .end();
 }
+
+#[test]
+fn new_invalid_repeat() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a --repeat sometimes")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseRecurrence {
+            cannot_parse: "sometimes".to_string(),
+        })
+        .end();
+}
+
+#[test]
+fn new_repeating_task() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a --repeat every weekday")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).action(New))
+        .end();
+}
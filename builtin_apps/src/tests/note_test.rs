@@ -0,0 +1,130 @@
+use todo_lookup_key::Key::*;
+use todo_printing::PrintableError;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_text_editing::FakeTextEditor;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn note_append_to_task_without_notes() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo note b --append hello")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("b", 2, Incomplete).notes("hello").show_notes(true))
+        .end();
+}
+
+#[test]
+fn note_append_adds_line() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note a --append hello");
+    fix.test("todo note a --append goodbye")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .notes("hello\ngoodbye")
+                .show_notes(true),
+        )
+        .end();
+}
+
+#[test]
+fn note_append_to_multiple_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo note a b --append x")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).notes("x").show_notes(true))
+        .printed_task(&task("b", 2, Incomplete).notes("x").show_notes(true))
+        .end();
+}
+
+#[test]
+fn note_with_text_editor() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note a --append hello");
+    fix.text_editor = FakeTextEditor::user_will_enter("hello\nworld\n\n");
+    fix.test("todo note a")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .notes("hello\nworld")
+                .show_notes(true),
+        )
+        .end();
+    assert_eq!(*fix.text_editor.recorded_input(), "hello");
+}
+
+#[test]
+fn note_with_text_editor_unchanged() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note a --append hello");
+    fix.text_editor = FakeTextEditor::user_will_enter("hello\n");
+    fix.test("todo note a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).notes("hello").show_notes(true))
+        .end();
+}
+
+#[test]
+fn note_with_text_editor_cleared() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note a --append hello");
+    fix.text_editor = FakeTextEditor::user_will_enter("\n");
+    fix.test("todo note a")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn note_text_editor_fails() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.text_editor = FakeTextEditor::no_user_output();
+    fix.test("todo note a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::FailedToUseTextEditor)
+        .end();
+}
+
+#[test]
+fn note_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note b --append hello")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: ByName("b".to_string()),
+        })
+        .end();
+}
+
+#[test]
+fn notes_are_marked_in_list() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo note a --append hello");
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).notes("hello"))
+        .printed_task(&task("b", 2, Incomplete))
+        .end();
+}
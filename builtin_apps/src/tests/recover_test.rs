@@ -0,0 +1,124 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use todo_model::TodoList;
+use todo_printing::PrintableError;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn list_of(descs: &[&'static str]) -> String {
+    let mut list = TodoList::default();
+    descs.iter().for_each(|&desc| {
+        list.add(desc);
+    });
+    serde_json::to_string(&list).unwrap()
+}
+
+#[test]
+fn recover_with_no_backups() {
+    let mut fix = Fixture::default();
+    fix.test("todo recover")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoBackups)
+        .end();
+}
+
+#[test]
+fn recover_lists_backups() {
+    let mut fix = Fixture::default();
+    fix.backups.backups = vec![
+        (ymdhms(2024, 01, 02, 09, 00, 00), list_of(&["a", "b"])),
+        (ymdhms(2024, 01, 01, 09, 00, 00), list_of(&["a"])),
+        (ymdhms(2023, 12, 31, 09, 00, 00), "{".to_string()),
+    ];
+    fix.test("todo recover")
+        .modified(Mutated::No)
+        .validate()
+        .printed_info(&PrintableInfo::Backup {
+            index: 1,
+            modified: ymdhms(2024, 01, 02, 09, 00, 00),
+            num_tasks: Some(2),
+        })
+        .printed_info(&PrintableInfo::Backup {
+            index: 2,
+            modified: ymdhms(2024, 01, 01, 09, 00, 00),
+            num_tasks: Some(1),
+        })
+        .printed_info(&PrintableInfo::Backup {
+            index: 3,
+            modified: ymdhms(2023, 12, 31, 09, 00, 00),
+            num_tasks: None,
+        })
+        .end();
+}
+
+#[test]
+fn recover_backup_replaces_list() {
+    let mut fix = Fixture::default();
+    fix.backups.backups = vec![
+        (ymdhms(2024, 01, 02, 09, 00, 00), list_of(&["a", "b"])),
+        (ymdhms(2024, 01, 01, 09, 00, 00), list_of(&["x", "y", "z"])),
+    ];
+    fix.test("todo new c");
+    fix.test("todo recover 2")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&PrintableInfo::Recovered { index: 2 })
+        .end();
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("x", 1, Incomplete))
+        .printed_task(&task("y", 2, Incomplete))
+        .printed_task(&task("z", 3, Incomplete))
+        .end();
+}
+
+#[test]
+fn recover_can_be_undone() {
+    let mut fix = Fixture::default();
+    fix.backups.backups =
+        vec![(ymdhms(2024, 01, 02, 09, 00, 00), list_of(&["a"]))];
+    fix.test("todo new c");
+    fix.test("todo recover 1");
+    fix.test("todo undo").modified(Mutated::Yes);
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("c", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn recover_nonexistent_backup() {
+    let mut fix = Fixture::default();
+    fix.test("todo recover 1")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::NoSuchBackup { index: 1 })
+        .end();
+}
+
+#[test]
+fn recover_corrupt_backup() {
+    let mut fix = Fixture::default();
+    fix.backups.backups =
+        vec![(ymdhms(2024, 01, 02, 09, 00, 00), "{\"tasks\"".to_string())];
+    fix.test("todo new a");
+    fix.test("todo recover 1")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotReadBackup { index: 1 })
+        .end();
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete))
+        .end();
+}
@@ -83,3 +83,18 @@ fn rm_complete_task() {
         .printed_info(&info_removed("a"))
         .end();
 }
+
+#[test]
+fn rm_dry_run_prints_banner_first() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    // The runner is what skips saving; the application still reports what
+    // it would change.
+    fix.test("todo rm a --dry-run")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&PrintableInfo::DryRun)
+        .printed_info(&info_removed("a"))
+        .printed_task(&task("b", 1, Incomplete))
+        .end();
+}
@@ -0,0 +1,219 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use todo_lookup_key::Key::*;
+use todo_printing::BriefPrintableTask;
+use todo_printing::Plicit::*;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn schedule_empty_list() {
+    let mut fix = Fixture::default();
+    fix.test("todo schedule")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn schedule_without_due_dates_shows_nothing() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --budget 1 hour");
+    fix.test("todo schedule")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn schedule_marks_critical_path() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --budget 2 hours");
+    fix.test("todo new b --budget 1 hour");
+    fix.test("todo new c -p a b --budget 1 hour --due 5 hours");
+    fix.test("todo schedule")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .budget(Duration::hours(2))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 13, 00, 00)))
+                .adeps_stats(0, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 11, 00, 00))
+                .slack(Duration::hours(2))
+                .critical(true),
+        )
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .budget(Duration::hours(1))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 13, 00, 00)))
+                .adeps_stats(0, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 10, 00, 00))
+                .slack(Duration::hours(3)),
+        )
+        .printed_task(
+            &task("c", 3, Blocked)
+                .budget(Duration::hours(1))
+                .due_date(Explicit(ymdhms(2024, 03, 01, 14, 00, 00)))
+                .deps_stats(2, 2)
+                .earliest_finish(ymdhms(2024, 03, 01, 12, 00, 00))
+                .slack(Duration::hours(2))
+                .critical(true),
+        )
+        .end();
+}
+
+#[test]
+fn schedule_marks_only_longest_chain_as_critical() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a1 a2 --chain --budget 1 hour");
+    fix.test("todo new b --budget 1 hour");
+    fix.test("todo new c -p a2 b --budget 1 hour --due 5 hours");
+    fix.test("todo schedule")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a1", 1, Incomplete)
+                .budget(Duration::hours(1))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 12, 00, 00)))
+                .adeps_stats(1, 2)
+                .earliest_finish(ymdhms(2024, 03, 01, 10, 00, 00))
+                .slack(Duration::hours(2))
+                .critical(true),
+        )
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .budget(Duration::hours(1))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 13, 00, 00)))
+                .adeps_stats(0, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 10, 00, 00))
+                .slack(Duration::hours(3)),
+        )
+        .printed_task(
+            &task("a2", 3, Blocked)
+                .budget(Duration::hours(1))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 13, 00, 00)))
+                .deps_stats(1, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 11, 00, 00))
+                .slack(Duration::hours(2))
+                .critical(true),
+        )
+        .printed_task(
+            &task("c", 4, Blocked)
+                .budget(Duration::hours(1))
+                .due_date(Explicit(ymdhms(2024, 03, 01, 14, 00, 00)))
+                .deps_stats(2, 3)
+                .earliest_finish(ymdhms(2024, 03, 01, 12, 00, 00))
+                .slack(Duration::hours(2))
+                .critical(true),
+        )
+        .end();
+}
+
+#[test]
+fn schedule_by_key_includes_deps() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b --chain --budget 1 hour");
+    fix.test("todo new c --budget 1 hour");
+    fix.test("todo schedule b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .budget(Duration::hours(1))
+                .adeps_stats(1, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 10, 00, 00))
+                .critical(true),
+        )
+        .printed_task(
+            &task("b", 3, Blocked)
+                .budget(Duration::hours(1))
+                .deps_stats(1, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 11, 00, 00))
+                .critical(true),
+        )
+        .end();
+}
+
+#[test]
+fn schedule_counts_time_already_spent() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --budget 2 hours");
+    fix.test("todo start a");
+    fix.clock.now += Duration::minutes(90);
+    fix.test("todo stop");
+    fix.test("todo schedule a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .budget(Duration::hours(2))
+                .time_spent(Duration::minutes(90))
+                .earliest_finish(ymdhms(2024, 03, 01, 11, 00, 00))
+                .critical(true),
+        )
+        .end();
+}
+
+#[test]
+fn schedule_warns_about_missed_due_date() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --budget 3 hours");
+    fix.test("todo new b -p a --budget 1 hour --due 2 hours");
+    fix.test("todo schedule")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::CannotMeetDueDate {
+            late_task: BriefPrintableTask::new(1, Incomplete),
+            latest_finish: ymdhms(2024, 03, 01, 10, 00, 00),
+            earliest_finish: ymdhms(2024, 03, 01, 12, 00, 00),
+        })
+        .printed_warning(&PrintableWarning::CannotMeetDueDate {
+            late_task: BriefPrintableTask::new(2, Blocked),
+            latest_finish: ymdhms(2024, 03, 01, 11, 00, 00),
+            earliest_finish: ymdhms(2024, 03, 01, 13, 00, 00),
+        })
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .budget(Duration::hours(3))
+                .due_date(Implicit(ymdhms(2024, 03, 01, 10, 00, 00)))
+                .adeps_stats(1, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 12, 00, 00))
+                .slack(-Duration::hours(2))
+                .critical(true),
+        )
+        .printed_task(
+            &task("b", 2, Blocked)
+                .budget(Duration::hours(1))
+                .due_date(Explicit(ymdhms(2024, 03, 01, 11, 00, 00)))
+                .deps_stats(1, 1)
+                .earliest_finish(ymdhms(2024, 03, 01, 13, 00, 00))
+                .slack(-Duration::hours(2))
+                .critical(true),
+        )
+        .end();
+}
+
+#[test]
+fn schedule_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo schedule b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: ByName("b".to_string()),
+        })
+        .end();
+}
@@ -0,0 +1,15 @@
+use todo_printing::PrintableError;
+
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn shell_inside_shell() {
+    let mut fix = Fixture::default();
+    fix.test("todo shell")
+        .modified(Mutated::No)
+        .read_only(false)
+        .validate()
+        .printed_error(&PrintableError::AlreadyInShell)
+        .end();
+}
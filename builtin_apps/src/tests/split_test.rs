@@ -297,3 +297,59 @@ fn trim_trailing_whitespace() {
         .printed_task(&task("a", 4, Blocked).action(Select).deps_stats(3, 3))
         .end();
 }
+
+#[test]
+fn split_keeps_logged_time_on_first_shard() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a");
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 30, 00);
+    fix.test("todo stop");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 00, 00);
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 15, 00);
+    fix.test("todo split a --into a1 a2")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a1", 1, Incomplete)
+                .time_spent(Duration::minutes(30))
+                .timer_start(ymdhms(2024, 03, 01, 10, 00, 00))
+                .action(New),
+        )
+        .printed_task(&task("a2", 2, Incomplete).action(New))
+        .end();
+}
+
+#[test]
+fn split_copies_notes() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo note a --append hello");
+    fix.test("todo split a --into a1 a2")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a1", 1, Incomplete).notes("hello").action(New))
+        .printed_task(&task("a2", 2, Incomplete).notes("hello").action(New))
+        .end();
+}
+
+#[test]
+fn split_keeps_recurrence() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 00, 00);
+    fix.test("todo new a --repeat daily");
+    fix.test("todo split a --into a1 a2");
+    fix.clock.now = ymdhms(2024, 01, 01, 09, 30, 00);
+    fix.test("todo check a1")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(&task("a1", 0, Complete).action(Check))
+        .printed_task(
+            &task("a1", 2, Blocked)
+                .action(New)
+                .start_date(ymdhms(2024, 01, 02, 00, 00, 00)),
+        )
+        .end();
+}
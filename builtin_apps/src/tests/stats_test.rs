@@ -0,0 +1,212 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use serde_json::json;
+use todo_printing::PrintableError;
+use todo_testing::ymdhms;
+
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn stats_empty_list() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo stats")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_text(concat!(
+            "Day         Created  Completed\n",
+            "2024-03-01        0          0\n",
+            "\n",
+            "Created    _  0\n",
+            "Completed  _  0\n",
+            "\n",
+            "Status      Tasks\n",
+            "Incomplete      0\n",
+            "Blocked         0\n",
+            "Complete        0\n",
+            "\n",
+            "Median time to complete: -\n",
+            "On time: -\n",
+        ))
+        .end();
+}
+
+#[test]
+fn stats_by_day() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b c d");
+    fix.test("todo block d --on c");
+    fix.clock.now = ymdhms(2024, 03, 02, 09, 00, 00);
+    fix.test("todo check a");
+    fix.test("todo new e");
+    fix.clock.now = ymdhms(2024, 03, 04, 12, 00, 00);
+    fix.test("todo check b e");
+    fix.test("todo stats")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_text(concat!(
+            "Day         Created  Completed\n",
+            "2024-03-01        4          0\n",
+            "2024-03-02        1          1\n",
+            "2024-03-03        0          0\n",
+            "2024-03-04        0          2\n",
+            "\n",
+            "Created    #:__  5\n",
+            "Completed  _=_#  3\n",
+            "\n",
+            "Status      Tasks\n",
+            "Incomplete      1\n",
+            "Blocked         1\n",
+            "Complete        3\n",
+            "\n",
+            "Median time to complete: 2 days\n",
+            "On time: -\n",
+        ))
+        .end();
+}
+
+#[test]
+fn stats_by_week_for_long_periods() {
+    let mut fix = Fixture::default();
+    // A Wednesday.
+    fix.clock.now = ymdhms(2024, 01, 03, 09, 00, 00);
+    fix.test("todo new a");
+    fix.clock.now = ymdhms(2024, 01, 24, 09, 00, 00);
+    fix.test("todo new b");
+    fix.clock.now = ymdhms(2024, 02, 05, 09, 00, 00);
+    fix.test("todo check a b");
+    fix.test("todo stats")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_text(concat!(
+            "Week of     Created  Completed\n",
+            "2024-01-01        1          0\n",
+            "2024-01-08        0          0\n",
+            "2024-01-15        0          0\n",
+            "2024-01-22        1          0\n",
+            "2024-01-29        0          0\n",
+            "2024-02-05        0          2\n",
+            "\n",
+            "Created    #__#__  2\n",
+            "Completed  _____#  2\n",
+            "\n",
+            "Status      Tasks\n",
+            "Incomplete      0\n",
+            "Blocked         0\n",
+            "Complete        2\n",
+            "\n",
+            "Median time to complete: 22 days\n",
+            "On time: -\n",
+        ))
+        .end();
+}
+
+#[test]
+fn stats_since() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b");
+    fix.clock.now = ymdhms(2024, 03, 05, 09, 00, 00);
+    fix.test("todo check a");
+    fix.test("todo new c");
+    fix.clock.now += Duration::hours(1);
+    fix.test("todo check c");
+    fix.test("todo stats --since 1 day")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_text(concat!(
+            "Day         Created  Completed\n",
+            "2024-03-04        0          0\n",
+            "2024-03-05        1          2\n",
+            "\n",
+            "Created    _#  1\n",
+            "Completed  _#  2\n",
+            "\n",
+            "Status      Tasks\n",
+            "Incomplete      1\n",
+            "Blocked         0\n",
+            "Complete        2\n",
+            "\n",
+            "Median time to complete: 2 days\n",
+            "On time: -\n",
+        ))
+        .end();
+}
+
+#[test]
+fn stats_by_tag_with_on_time_rate() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new chores --tag");
+    fix.test("todo new dishes laundry -b chores --due 2 hours");
+    fix.test("todo new report --due 1 hour");
+    fix.clock.now += Duration::minutes(90);
+    fix.test("todo check dishes report");
+    fix.test("todo stats")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_text(concat!(
+            "Day         Created  Completed\n",
+            "2024-03-01        4          2\n",
+            "\n",
+            "Created    #  4\n",
+            "Completed  #  2\n",
+            "\n",
+            "Status      Tasks\n",
+            "Incomplete      1\n",
+            "Blocked         1\n",
+            "Complete        2\n",
+            "\n",
+            "Median time to complete: 1 hour\n",
+            "On time: 1 of 2 (50%)\n",
+            "\n",
+            "Tag     Open  Created  Completed  Median        On time\n",
+            "chores     1        2          1  1 hour  1 of 1 (100%)\n",
+        ))
+        .end();
+}
+
+#[test]
+fn stats_as_json() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b");
+    fix.clock.now += Duration::hours(2);
+    fix.test("todo check a");
+    fix.test("todo --format json stats")
+        .modified(Mutated::No)
+        .validate()
+        .printed_report_json(json!({
+            "since": null,
+            "period": "day",
+            "periods": [
+                {"start": "2024-03-01", "created": 2, "completed": 1},
+            ],
+            "backlog": {"incomplete": 1, "blocked": 0, "complete": 1},
+            "overall": {
+                "created": 2,
+                "completed": 1,
+                "open": 1,
+                "median_secs_to_complete": 7200,
+                "on_time": 0,
+                "with_due_date": 0,
+            },
+            "tags": [],
+        }))
+        .end();
+}
+
+#[test]
+fn stats_cannot_parse_since() {
+    let mut fix = Fixture::default();
+    fix.test("todo stats --since whenever")
+        .modified(Mutated::No)
+        .validate()
+        .printed_error(&PrintableError::CannotParseSinceDate {
+            cannot_parse: "whenever".to_string(),
+        })
+        .end();
+}
@@ -6,13 +6,18 @@ use chrono::TimeZone;
 use chrono::Utc;
 use clap::Parser;
 use pretty_assertions::assert_eq;
+use serde_json::Value;
 use todo_app::Application;
+use todo_backups::FakeBackups;
 use todo_cli::Options;
 use todo_clock::FakeClock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
 use todo_model::TodoList;
 use todo_printing::Printable;
 use todo_printing::PrintableError;
 use todo_printing::PrintableInfo;
+use todo_printing::PrintableReport;
 use todo_printing::PrintableTask;
 use todo_printing::PrintableWarning;
 use todo_printing::Status;
@@ -25,12 +30,22 @@ pub enum Mutated {
     No,
 }
 
+// Tasks are much bigger than the other items, but boxing them would make the
+// short ID matching in Validation::end() awkward, and this is only for tests.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum PrintedItem<'list> {
     Task(PrintableTask<'list>),
     Info(PrintableInfo),
     Warning(PrintableWarning),
     Error(PrintableError),
+    Document(String),
+    // Tests check either the text or the JSON of a report, and the other is
+    // left out of the comparison.
+    Report {
+        text: Option<String>,
+        json: Option<Value>,
+    },
 }
 
 pub struct Validation<'validation, 'test> {
@@ -60,8 +75,66 @@ impl<'test> Validation<'_, 'test> {
         self
     }
 
+    pub fn printed_document(mut self, document: &str) -> Self {
+        self.expected
+            .push(PrintedItem::Document(document.to_string()));
+        self
+    }
+
+    pub fn printed_report_text(mut self, text: &str) -> Self {
+        self.expected.push(PrintedItem::Report {
+            text: Some(text.to_string()),
+            json: None,
+        });
+        self
+    }
+
+    pub fn printed_report_json(mut self, json: Value) -> Self {
+        self.expected.push(PrintedItem::Report {
+            text: None,
+            json: Some(json),
+        });
+        self
+    }
+
     pub fn end(self) {
         let cmd = self.cmd;
+        // Short IDs are derived from creation times and descriptions, so most
+        // tests don't care about them. Only compare them for expected tasks
+        // that specify one.
+        let actual = self
+            .actual
+            .iter()
+            .zip(
+                self.expected
+                    .iter()
+                    .map(Some)
+                    .chain(std::iter::repeat(None)),
+            )
+            .map(|(actual, expected)| match (actual, expected) {
+                (
+                    PrintedItem::Task(actual),
+                    Some(PrintedItem::Task(PrintableTask {
+                        short_id: None,
+                        ..
+                    })),
+                ) => PrintedItem::Task(PrintableTask {
+                    short_id: None,
+                    ..actual.clone()
+                }),
+                (
+                    PrintedItem::Report { text, json },
+                    Some(PrintedItem::Report {
+                        text: expected_text,
+                        json: expected_json,
+                    }),
+                ) => PrintedItem::Report {
+                    text: expected_text.as_ref().and(text.clone()),
+                    json: expected_json.as_ref().and(json.clone()),
+                },
+                _ => actual.clone(),
+            })
+            .collect::<Vec<_>>();
         assert_eq!(
             // Note: the pretty_assertions crate switches the order of the
             // arguments to assert_eq!() so that the first argument is labeled
@@ -71,7 +144,7 @@ impl<'test> Validation<'_, 'test> {
             // erroneous actual value when the assertion fails. The "right"
             // argument is painted green, showing what the output should be.
             &self.expected,
-            self.actual,
+            &actual,
             "Unexpected output from '{cmd}' (left: actual, right: expected)"
         );
     }
@@ -109,10 +182,26 @@ where
             .borrow_mut()
             .push(PrintedItem::Error(error.clone()));
     }
+
+    fn print_document(&mut self, document: &str) {
+        self.record
+            .borrow_mut()
+            .push(PrintedItem::Document(document.to_string()));
+    }
+
+    fn print_report(&mut self, report: &PrintableReport) {
+        self.record.borrow_mut().push(PrintedItem::Report {
+            text: Some(report.text.clone()),
+            json: Some(report.json.clone()),
+        });
+    }
 }
 
 pub struct Fixture<'list> {
     pub list: TodoList<'list>,
+    pub journal: Journal<'list>,
+    pub config: ConfigModel,
+    pub backups: FakeBackups,
     pub clock: FakeClock,
     pub text_editor: FakeTextEditor<'list>,
 }
@@ -121,6 +210,9 @@ impl Default for Fixture<'_> {
     fn default() -> Self {
         Fixture {
             list: TodoList::default(),
+            journal: Journal::default(),
+            config: ConfigModel::default(),
+            backups: FakeBackups::default(),
             clock: FakeClock::new(
                 Utc.with_ymd_and_hms(2000, 01, 01, 00, 00, 00).unwrap(),
             ),
@@ -132,10 +224,21 @@ impl Default for Fixture<'_> {
 pub struct Validator<'test> {
     record: Vec<PrintedItem<'test>>,
     mutated: Mutated,
+    read_only: bool,
     cmd: String,
 }
 
 impl<'test> Validator<'test> {
+    // Whether the runner would only take a shared lock for the command.
+    pub fn read_only(self, expected: bool) -> Self {
+        assert_eq!(
+            self.read_only, expected,
+            "Incorrect read-only status of '{}'",
+            self.cmd
+        );
+        self
+    }
+
     pub fn modified(self, expected: Mutated) -> Self {
         assert_eq!(
             self.mutated, expected,
@@ -145,6 +248,18 @@ impl<'test> Validator<'test> {
         self
     }
 
+    // The document the command printed, for tests that feed it back into
+    // another command.
+    pub fn document(&self) -> String {
+        self.record
+            .iter()
+            .find_map(|item| match item {
+                PrintedItem::Document(document) => Some(document.clone()),
+                _ => None,
+            })
+            .expect("No document was printed")
+    }
+
     pub fn validate(&mut self) -> Validation<'_, 'test> {
         Validation {
             cmd: &self.cmd,
@@ -157,18 +272,46 @@ impl<'test> Validator<'test> {
 impl Fixture<'_> {
     pub fn test(&mut self, s: &str) -> Validator<'_> {
         let mut printer = FakePrinter::default();
-        let mutated = {
+        let before = self.list.clone();
+        let (read_only, mutated) = {
             let args = shlex::split(s).expect("Could not split args");
             let options =
                 Options::try_parse_from(args).expect("Could not parse args");
             let app = crate::App::new(options);
-            app.run(&mut self.list, &self.text_editor, &self.clock)
-                .print(&mut printer)
+            let read_only = app.is_read_only();
+            let mutated = app
+                .run(
+                    &mut self.list,
+                    &mut self.journal,
+                    &mut self.config,
+                    &self.backups,
+                    &self.text_editor,
+                    &self.clock,
+                )
+                .print(&mut printer);
+            (read_only, mutated)
         };
+        // The runner only holds a shared lock for read-only commands, so they
+        // must never report a mutation.
+        assert!(
+            !(read_only && mutated),
+            "'{s}' is read-only but mutated the list"
+        );
         let record = printer.record.borrow().clone();
+        if mutated {
+            // Mirror the runner, which records every mutating command so that
+            // it can be undone.
+            self.journal.record(
+                s.to_string(),
+                self.clock.now,
+                before,
+                todo_config::Config::new(&self.config).undo_limit,
+            );
+        }
         Validator {
             record,
             mutated: if mutated { Mutated::Yes } else { Mutated::No },
+            read_only,
             cmd: s.to_string(),
         }
     }
@@ -0,0 +1,143 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use todo_lookup_key::Key::*;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+// Records |minutes| of work on the task with the given key.
+fn work_on(fix: &mut Fixture, key: &str, minutes: i64) {
+    fix.test(&format!("todo start {key}"));
+    fix.clock.now += Duration::minutes(minutes);
+    fix.test("todo stop");
+}
+
+#[test]
+fn time_with_nothing_recorded() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo time").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn time_shows_tasks_with_recorded_time() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    work_on(&mut fix, "a", 10);
+    work_on(&mut fix, "c", 20);
+    fix.test("todo time")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .time_spent(Duration::minutes(10))
+                .time_spent_with_deps(Duration::minutes(10)),
+        )
+        .printed_task(
+            &task("c", 3, Incomplete)
+                .time_spent(Duration::minutes(20))
+                .time_spent_with_deps(Duration::minutes(20)),
+        )
+        .end();
+}
+
+#[test]
+fn time_rolls_up_deps() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    work_on(&mut fix, "a", 30);
+    fix.test("todo check a");
+    work_on(&mut fix, "b", 15);
+    fix.test("todo time c")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("c", 2, Blocked)
+                .deps_stats(1, 2)
+                .time_spent_with_deps(Duration::minutes(45)),
+        )
+        .end();
+}
+
+#[test]
+fn time_shows_tasks_blocked_on_tasks_with_recorded_time() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo new d");
+    work_on(&mut fix, "a", 30);
+    fix.test("todo time")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .adeps_stats(1, 2)
+                .time_spent(Duration::minutes(30))
+                .time_spent_with_deps(Duration::minutes(30)),
+        )
+        .printed_task(
+            &task("b", 3, Blocked)
+                .deps_stats(1, 1)
+                .time_spent_with_deps(Duration::minutes(30)),
+        )
+        .printed_task(
+            &task("c", 4, Blocked)
+                .deps_stats(1, 2)
+                .time_spent_with_deps(Duration::minutes(30)),
+        )
+        .end();
+}
+
+#[test]
+fn time_includes_running_timer() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b --chain");
+    work_on(&mut fix, "a", 10);
+    fix.test("todo start a");
+    fix.clock.now += Duration::minutes(5);
+    fix.test("todo time b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("b", 2, Blocked)
+                .deps_stats(1, 1)
+                .time_spent_with_deps(Duration::minutes(15)),
+        )
+        .end();
+}
+
+#[test]
+fn time_includes_done_if_requested() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    work_on(&mut fix, "a", 10);
+    fix.test("todo check a");
+    fix.test("todo time").modified(Mutated::No).validate().end();
+    fix.test("todo time -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 0, Complete)
+                .time_spent(Duration::minutes(10))
+                .time_spent_with_deps(Duration::minutes(10)),
+        )
+        .end();
+}
+
+#[test]
+fn time_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo time b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: ByName("b".to_string()),
+        })
+        .end();
+}
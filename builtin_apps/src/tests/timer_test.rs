@@ -0,0 +1,186 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use todo_lookup_key::Key::*;
+use todo_printing::Action::*;
+use todo_printing::BriefPrintableTask;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+#[test]
+fn start_timer() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b");
+    fix.test("todo start b")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .timer_start(ymdhms(2024, 03, 01, 09, 00, 00))
+                .action(Start),
+        )
+        .end();
+}
+
+#[test]
+fn stop_timer() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b");
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 45, 00);
+    fix.test("todo stop")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .time_spent(Duration::minutes(45))
+                .action(Stop),
+        )
+        .end();
+}
+
+#[test]
+fn stop_without_running_timer() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo stop")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoTimerRunning)
+        .end();
+}
+
+#[test]
+fn start_timer_stops_running_timer() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a b");
+    fix.test("todo start b");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 00, 00);
+    fix.test("todo start a")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("b", 2, Incomplete)
+                .time_spent(Duration::hours(1))
+                .action(Stop),
+        )
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .timer_start(ymdhms(2024, 03, 01, 10, 00, 00))
+                .action(Start),
+        )
+        .end();
+}
+
+#[test]
+fn start_timer_adds_to_time_spent() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a");
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 30, 00);
+    fix.test("todo stop");
+    fix.clock.now = ymdhms(2024, 03, 01, 13, 00, 00);
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 13, 15, 00);
+    fix.test("todo stop")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .time_spent(Duration::minutes(45))
+                .action(Stop),
+        )
+        .end();
+}
+
+#[test]
+fn start_timer_already_running() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo start a");
+    fix.test("todo start a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::TimerAlreadyRunning {
+            timed_task: BriefPrintableTask::new(1, Incomplete),
+        })
+        .end();
+}
+
+#[test]
+fn start_timer_on_complete_task() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo check a");
+    fix.test("todo start a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::CannotStartTimerBecauseComplete {
+            cannot_start: BriefPrintableTask::new(0, Complete),
+        })
+        .end();
+}
+
+#[test]
+fn start_timer_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo start b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: ByName("b".to_string()),
+        })
+        .end();
+}
+
+#[test]
+fn start_timer_ambiguous_key() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a a");
+    fix.test("todo start a")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::AmbiguousKey {
+            key: ByName("a".to_string()),
+            matches: vec![
+                BriefPrintableTask::new(1, Incomplete),
+                BriefPrintableTask::new(2, Incomplete),
+            ],
+        })
+        .end();
+}
+
+#[test]
+fn check_stops_timer_and_log_shows_time_with_budget() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2024, 03, 01, 09, 00, 00);
+    fix.test("todo new a --budget 1 hour");
+    fix.test("todo start a");
+    fix.clock.now = ymdhms(2024, 03, 01, 10, 30, 00);
+    fix.test("todo check a");
+    fix.test("todo stop")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoTimerRunning)
+        .end();
+    fix.test("todo log")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 0, Complete)
+                .budget(Duration::hours(1))
+                .time_spent(Duration::minutes(90))
+                .log_date(todo_printing::LogDate::YearMonthDay(2024, 03, 01)),
+        )
+        .end();
+}
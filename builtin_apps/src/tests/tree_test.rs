@@ -0,0 +1,278 @@
+use todo_lookup_key::Key;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_printing::TreePosition;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn root() -> TreePosition {
+    TreePosition::default()
+}
+
+fn child(is_last: &[bool]) -> TreePosition {
+    TreePosition {
+        is_last: is_last.to_vec(),
+        back_reference: false,
+    }
+}
+
+fn back_reference(is_last: &[bool]) -> TreePosition {
+    TreePosition {
+        is_last: is_last.to_vec(),
+        back_reference: true,
+    }
+}
+
+// Sets up a project that is blocked on a and b, which are both blocked on c.
+fn diamond() -> Fixture<'static> {
+    let mut fix = Fixture::default();
+    fix.test("todo new c");
+    fix.test("todo new a b -p c");
+    fix.test("todo new project -p a b");
+    fix
+}
+
+#[test]
+fn tree_empty() {
+    let mut fix = Fixture::default();
+    fix.test("todo tree").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn tree_unrelated_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo tree")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete).tree_position(root()))
+        .printed_task(&task("b", 2, Incomplete).tree_position(root()))
+        .end();
+}
+
+#[test]
+fn tree_shows_shared_deps_once() {
+    let mut fix = diamond();
+    fix.test("todo tree")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("project", 4, Blocked)
+                .deps_stats(1, 3)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("a", 2, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[false])),
+        )
+        .printed_task(
+            &task("c", 1, Incomplete)
+                .adeps_stats(2, 3)
+                .tree_position(child(&[false, true])),
+        )
+        .printed_task(
+            &task("b", 3, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .printed_task(
+            &task("c", 1, Incomplete)
+                .adeps_stats(2, 3)
+                .tree_position(back_reference(&[true, true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_up() {
+    let mut fix = diamond();
+    fix.test("todo tree c --up")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("c", 1, Incomplete)
+                .adeps_stats(2, 3)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("a", 2, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[false])),
+        )
+        .printed_task(
+            &task("project", 4, Blocked)
+                .deps_stats(1, 3)
+                .tree_position(child(&[false, true])),
+        )
+        .printed_task(
+            &task("b", 3, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .printed_task(
+            &task("project", 4, Blocked)
+                .deps_stats(1, 3)
+                .tree_position(back_reference(&[true, true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_up_without_keys_starts_from_unblocked_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    fix.test("todo tree --up")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 1, Incomplete)
+                .adeps_stats(1, 1)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("b", 2, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_with_depth_limit() {
+    let mut fix = diamond();
+    fix.test("todo tree project --depth 1")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("project", 4, Blocked)
+                .deps_stats(1, 3)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("a", 2, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[false])),
+        )
+        .printed_task(
+            &task("b", 3, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_expands_task_cut_off_by_depth_limit_elsewhere() {
+    let mut fix = Fixture::default();
+    fix.test("todo new r1 r2");
+    fix.test("todo new m -p r1");
+    fix.test("todo new x -p m r2");
+    fix.test("todo new top -p x");
+    // x is cut off under r1 -> m, so it's shown in full under r2.
+    fix.test("todo tree --up --depth 2")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("r1", 1, Incomplete)
+                .adeps_stats(1, 3)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("m", 3, Blocked)
+                .deps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .printed_task(
+            &task("x", 4, Blocked)
+                .deps_stats(2, 3)
+                .tree_position(child(&[true, true])),
+        )
+        .printed_task(
+            &task("r2", 2, Incomplete)
+                .adeps_stats(0, 2)
+                .tree_position(root()),
+        )
+        .printed_task(
+            &task("x", 4, Blocked)
+                .deps_stats(2, 3)
+                .tree_position(child(&[true])),
+        )
+        .printed_task(
+            &task("top", 5, Blocked)
+                .deps_stats(2, 4)
+                .tree_position(child(&[true, true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_with_depth_zero_shows_only_roots() {
+    let mut fix = diamond();
+    fix.test("todo tree a b --depth 0")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("a", 2, Blocked).deps_stats(1, 1).tree_position(root()),
+        )
+        .printed_task(
+            &task("b", 3, Blocked).deps_stats(1, 1).tree_position(root()),
+        )
+        .end();
+}
+
+#[test]
+fn tree_excludes_complete_deps() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo new c -p a b");
+    fix.test("todo check a");
+    fix.test("todo tree c")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("c", 2, Blocked).deps_stats(1, 2).tree_position(root()),
+        )
+        .printed_task(
+            &task("b", 1, Incomplete)
+                .adeps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_include_done() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo new c -p a b");
+    fix.test("todo check a");
+    fix.test("todo tree c -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(
+            &task("c", 2, Blocked).deps_stats(1, 2).tree_position(root()),
+        )
+        .printed_task(&task("a", 0, Complete).tree_position(child(&[false])))
+        .printed_task(
+            &task("b", 1, Incomplete)
+                .adeps_stats(1, 1)
+                .tree_position(child(&[true])),
+        )
+        .end();
+}
+
+#[test]
+fn tree_no_match() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo tree b")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NoMatchFoundForKey {
+            requested_key: Key::ByName("b".to_string()),
+        })
+        .end();
+}
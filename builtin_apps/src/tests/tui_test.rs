@@ -0,0 +1,253 @@
+use std::io;
+
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::Event;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::crossterm::event::KeyEvent;
+use ratatui::crossterm::event::KeyModifiers;
+use ratatui::Terminal;
+use todo_cli::Tui;
+use todo_printing::Plicit::*;
+use todo_printing::Status::*;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn key(code: KeyCode) -> io::Result<Event> {
+    Ok(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+}
+
+fn keys(s: &str) -> Vec<io::Result<Event>> {
+    s.chars().map(|c| key(KeyCode::Char(c))).collect()
+}
+
+// Runs the TUI with the given key presses, returning the lines on the screen
+// after the last one, and whether the list was modified.
+fn run_tui(
+    fix: &mut Fixture,
+    cmd: &Tui,
+    events: Vec<io::Result<Event>>,
+) -> (Vec<String>, bool) {
+    let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+    let mutated = crate::tui::run_in_terminal(
+        &mut fix.list,
+        &fix.text_editor,
+        &fix.clock,
+        false,
+        cmd,
+        &mut terminal,
+        events,
+    )
+    .unwrap();
+    let buffer = terminal.backend().buffer();
+    let lines = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect();
+    (lines, mutated)
+}
+
+fn enter(s: &str) -> Vec<io::Result<Event>> {
+    let mut events = keys(s);
+    events.push(key(KeyCode::Enter));
+    events
+}
+
+#[test]
+fn tui_shows_incomplete_tasks_and_related_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo new d");
+    let (screen, mutated) = run_tui(&mut fix, &Tui::default(), keys(""));
+    assert!(!mutated);
+    assert_eq!(
+        screen,
+        [
+            "┌ Tasks ───────────────────────────┐┌ Related ─────────────┐",
+            "│    1) 🔓 1/2 a                    ││ *  1) 🔓 1/2 a        │",
+            "│    2) d                          ││    3) 🔒 1/1 b        │",
+            "│                                  ││    4) 🔒 1/2 c        │",
+            "└──────────────────────────────────┘└──────────────────────┘",
+            "┌ Output ──────────────────────────────────────────────────┐",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+            "j/k move  c check  p punt  s snooze  P priority  e edit  m m",
+        ]
+    );
+}
+
+#[test]
+fn tui_related_tasks_follow_selection() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo new d");
+    let (screen, _) = run_tui(&mut fix, &Tui::default(), keys("j"));
+    assert!(
+        screen[1].ends_with("││ *  2) d              │"),
+        "{screen:#?}"
+    );
+    assert!(
+        screen[2].ends_with("││                      │"),
+        "{screen:#?}"
+    );
+}
+
+#[test]
+fn tui_check_selected_task() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    let (screen, mutated) = run_tui(&mut fix, &Tui::default(), keys("jc"));
+    assert!(mutated);
+    assert!(screen[6].starts_with("│[✓] 0) b "), "{screen:#?}");
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 1, Incomplete))
+        .printed_task(&task("c", 2, Incomplete))
+        .end();
+}
+
+#[test]
+fn tui_check_blocked_task_shows_error() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    let tui = Tui {
+        include_blocked: true,
+        ..Default::default()
+    };
+    let (screen, mutated) = run_tui(&mut fix, &tui, keys("jc"));
+    assert!(!mutated);
+    assert!(
+        screen[6].contains("error: Cannot complete 2)"),
+        "{screen:#?}"
+    );
+}
+
+#[test]
+fn tui_punt_keeps_task_selected() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), keys("pc"));
+    assert!(mutated);
+    fix.test("todo -d")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("a", 0, Complete))
+        .printed_task(&task("b", 1, Incomplete))
+        .printed_task(&task("c", 2, Incomplete))
+        .end();
+}
+
+#[test]
+fn tui_snooze_selected_task() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    let mut events = keys("s");
+    events.extend(enter("1 day"));
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), events);
+    assert!(mutated);
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn tui_set_priority() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    let mut events = keys("jP");
+    events.extend(enter("2"));
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), events);
+    assert!(mutated);
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete).priority(Explicit(2)))
+        .printed_task(&task("a", 2, Incomplete))
+        .end();
+}
+
+#[test]
+fn tui_priority_must_be_a_number() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    let mut events = keys("P");
+    events.extend(enter("high"));
+    let (screen, mutated) = run_tui(&mut fix, &Tui::default(), events);
+    assert!(!mutated);
+    assert_eq!(screen[11].trim_end(), "Priority: high");
+}
+
+#[test]
+fn tui_edit_description() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    let mut events = keys("e");
+    events.push(key(KeyCode::Backspace));
+    events.extend(enter("z"));
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), events);
+    assert!(mutated);
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("z", 1, Incomplete))
+        .printed_task(&task("b", 2, Incomplete))
+        .end();
+}
+
+#[test]
+fn tui_cancel_input() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    let mut events = keys("ex");
+    events.push(key(KeyCode::Esc));
+    events.extend(keys("j"));
+    let (screen, mutated) = run_tui(&mut fix, &Tui::default(), events);
+    assert!(!mutated);
+    assert!(screen[11].starts_with("j/k move"));
+}
+
+#[test]
+fn tui_block_marked_task_on_selected_task() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c");
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), keys("mjjb"));
+    assert!(mutated);
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete))
+        .printed_task(&task("c", 2, Incomplete).adeps_stats(1, 1))
+        .end();
+}
+
+#[test]
+fn tui_toggle_blocked_and_done_tasks() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo check a");
+    let (screen, _) = run_tui(&mut fix, &Tui::default(), keys("BD"));
+    assert!(screen[0].starts_with("┌ Tasks +blocked +done ─"));
+    assert!(screen[1].starts_with("│    0) a "), "{screen:#?}");
+    assert!(screen[2].starts_with("│    1) 🔓 1/1 b "), "{screen:#?}");
+    assert!(screen[3].starts_with("│    2) 🔒 1/2 c "), "{screen:#?}");
+    // The task that was selected stays selected.
+    assert!(screen[1].contains("│ *  1) 🔓 1/1 b"), "{screen:#?}");
+}
+
+#[test]
+fn tui_quit() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    let (_, mutated) = run_tui(&mut fix, &Tui::default(), keys("qc"));
+    assert!(!mutated);
+}
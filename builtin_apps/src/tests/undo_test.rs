@@ -0,0 +1,216 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Utc;
+use todo_printing::Action::*;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableWarning;
+use todo_printing::Status::*;
+use todo_testing::ymdhms;
+
+use super::testing::task;
+use super::testing::Fixture;
+use super::testing::Mutated;
+
+fn info_undid(command: &str) -> PrintableInfo {
+    PrintableInfo::Undid {
+        command: command.to_string(),
+    }
+}
+
+fn info_redid(command: &str) -> PrintableInfo {
+    PrintableInfo::Redid {
+        command: command.to_string(),
+    }
+}
+
+fn info_removed(desc: &str) -> PrintableInfo {
+    PrintableInfo::Removed {
+        desc: desc.to_string(),
+    }
+}
+
+fn info_undoable(command: &str, time: chrono::DateTime<Utc>) -> PrintableInfo {
+    PrintableInfo::UndoableCommand {
+        command: command.to_string(),
+        time,
+    }
+}
+
+#[test]
+fn undo_with_empty_journal() {
+    let mut fix = Fixture::default();
+    fix.test("todo undo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NothingToUndo)
+        .end();
+}
+
+#[test]
+fn undo_new() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo new a b"))
+        .printed_info(&info_removed("a"))
+        .printed_info(&info_removed("b"))
+        .end();
+    fix.test("todo -a").modified(Mutated::No).validate().end();
+}
+
+#[test]
+fn undo_rm() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b c --chain");
+    fix.test("todo rm b");
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo rm b"))
+        .printed_task(&task("b", 2, Blocked).action(New).deps_stats(1, 1))
+        .printed_task(&task("c", 3, Blocked).deps_stats(1, 2))
+        .end();
+}
+
+#[test]
+fn undo_check() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b --chain");
+    fix.test("todo check 1");
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo check 1"))
+        .printed_task(
+            &task("a", 1, Incomplete).action(Uncheck).adeps_stats(1, 1),
+        )
+        .printed_task(&task("b", 2, Blocked).action(Lock).deps_stats(1, 1))
+        .end();
+}
+
+#[test]
+fn undo_twice() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo new b");
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo new b"))
+        .printed_info(&info_removed("b"))
+        .end();
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo new a"))
+        .printed_info(&info_removed("a"))
+        .end();
+    fix.test("todo undo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NothingToUndo)
+        .end();
+}
+
+#[test]
+fn undo_does_not_record_read_only_commands() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo");
+    fix.test("todo log");
+    fix.test("todo undo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_undid("todo new a"))
+        .printed_info(&info_removed("a"))
+        .end();
+}
+
+#[test]
+fn undo_respects_limit() {
+    let mut fix = Fixture::default();
+    fix.test("todo config undo_limit 1");
+    fix.test("todo new a");
+    fix.test("todo new b");
+    fix.test("todo undo").modified(Mutated::Yes);
+    fix.test("todo undo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NothingToUndo)
+        .end();
+}
+
+#[test]
+fn undo_list() {
+    let mut fix = Fixture::default();
+    fix.clock.now = ymdhms(2000, 01, 01, 09, 00, 00);
+    fix.test("todo new a");
+    fix.clock.now = ymdhms(2000, 01, 01, 10, 00, 00);
+    fix.test("todo check a");
+    fix.test("todo undo --list")
+        .modified(Mutated::No)
+        .validate()
+        .printed_info(&info_undoable(
+            "todo check a",
+            ymdhms(2000, 01, 01, 10, 00, 00),
+        ))
+        .printed_info(&info_undoable(
+            "todo new a",
+            ymdhms(2000, 01, 01, 09, 00, 00),
+        ))
+        .end();
+}
+
+#[test]
+fn undo_list_empty() {
+    let mut fix = Fixture::default();
+    fix.test("todo undo --list")
+        .modified(Mutated::No)
+        .validate()
+        .end();
+}
+
+#[test]
+fn redo_with_empty_journal() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo redo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NothingToRedo)
+        .end();
+}
+
+#[test]
+fn redo_undone_rm() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a b");
+    fix.test("todo rm a");
+    fix.test("todo undo");
+    fix.test("todo redo")
+        .modified(Mutated::Yes)
+        .validate()
+        .printed_info(&info_redid("todo rm a"))
+        .printed_info(&info_removed("a"))
+        .end();
+    fix.test("todo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_task(&task("b", 1, Incomplete))
+        .end();
+}
+
+#[test]
+fn new_command_clears_redo() {
+    let mut fix = Fixture::default();
+    fix.test("todo new a");
+    fix.test("todo undo");
+    fix.test("todo new b");
+    fix.test("todo redo")
+        .modified(Mutated::No)
+        .validate()
+        .printed_warning(&PrintableWarning::NothingToRedo)
+        .end();
+}
@@ -5,20 +5,42 @@ use chrono::Duration;
 use todo_lookup_key::Key;
 use todo_model::CheckOptions;
 use todo_model::NewOptions;
+use todo_model::TaskId;
 use todo_model::TodoList;
 use todo_printing::Action::*;
 use todo_printing::Plicit::*;
+use todo_printing::PrintableTask;
 use todo_printing::Status::*;
 use todo_testing::ymdhms;
 
 use super::testing::task;
 use super::util::*;
 
+// Short IDs depend on the creation time, so most tests ignore them.
+fn format_task_without_id<'list>(
+    list: &'list TodoList,
+    id: TaskId,
+) -> PrintableTask<'list> {
+    let mut task = format_task(list, id);
+    task.short_id.take();
+    task
+}
+
 #[test]
-fn format_task_basic() {
+fn format_task_with_short_id() {
     let mut list = TodoList::default();
     let a = list.add("a");
+    let short_id = &list.get(a).unwrap().short_id;
     let actual = format_task(&list, a);
+    let expected = task("a", 1, Incomplete).short_id(short_id);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn format_task_basic() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete);
     assert_eq!(actual, expected);
 }
@@ -27,7 +49,7 @@ fn format_task_basic() {
 fn format_task_with_action() {
     let mut list = TodoList::default();
     let a = list.add("a");
-    let actual = format_task(&list, a).action(Punt);
+    let actual = format_task_without_id(&list, a).action(Punt);
     let expected = task("a", 1, Incomplete).action(Punt);
     assert_eq!(actual, expected);
 }
@@ -36,7 +58,7 @@ fn format_task_with_action() {
 fn format_task_with_priority() {
     let mut list = TodoList::default();
     let a = list.add(NewOptions::new().desc("a").priority(1));
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete).priority(Explicit(1));
     assert_eq!(actual, expected);
 }
@@ -45,7 +67,7 @@ fn format_task_with_priority() {
 fn format_task_with_zero_priority() {
     let mut list = TodoList::default();
     let a = list.add(NewOptions::new().desc("a").priority(0));
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete);
     assert_eq!(actual, expected);
 }
@@ -61,7 +83,7 @@ fn format_task_with_budget() {
             .budget(Duration::hours(10))
             .due_date(due),
     );
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete)
         .due_date(Explicit(due))
         .budget(Duration::hours(10));
@@ -76,7 +98,7 @@ fn format_incomplete_task_with_adep_stats() {
     let c = list.add("c");
     list.block(b).on(a).unwrap();
     list.block(c).on(b).unwrap();
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete).adeps_stats(1, 2);
     assert_eq!(actual, expected);
 }
@@ -89,7 +111,7 @@ fn format_incomplete_task_with_all_all_adeps_unlockable() {
     let c = list.add("c");
     list.block(b).on(a).unwrap();
     list.block(c).on(a).unwrap();
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete).adeps_stats(2, 2);
     assert_eq!(actual, expected);
 }
@@ -148,7 +170,7 @@ fn format_incomplete_task_with_long_adep_chain() {
     list.block(x).on(w).unwrap();
     list.block(y).on(x).unwrap();
     list.block(z).on(y).unwrap();
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete).adeps_stats(1, 25);
     assert_eq!(actual, expected);
 }
@@ -161,7 +183,7 @@ fn format_blocked_task_with_two_deps() {
     let c = list.add("c");
     list.block(c).on(a).unwrap();
     list.block(c).on(b).unwrap();
-    let actual = format_task(&list, c);
+    let actual = format_task_without_id(&list, c);
     let expected = task("c", 3, Blocked).deps_stats(2, 2);
     assert_eq!(actual, expected);
 }
@@ -172,7 +194,7 @@ fn format_blocked_task_with_one_dep() {
     let a = list.add("a");
     let b = list.add("b");
     list.block(b).on(a).unwrap();
-    let actual = format_task(&list, b);
+    let actual = format_task_without_id(&list, b);
     let expected = task("b", 2, Blocked).deps_stats(1, 1);
     assert_eq!(actual, expected);
 }
@@ -186,7 +208,7 @@ fn format_blocked_task_with_complete_deps() {
     list.block(c).on(a).unwrap();
     list.block(c).on(b).unwrap();
     list.check(a).unwrap();
-    let actual = format_task(&list, c);
+    let actual = format_task_without_id(&list, c);
     let expected = task("c", 2, Blocked).deps_stats(1, 2);
     assert_eq!(actual, expected);
 }
@@ -199,7 +221,7 @@ fn format_blocked_task_with_blocked_deps() {
     let c = list.add("c");
     list.block(b).on(a).unwrap();
     list.block(c).on(b).unwrap();
-    let actual = format_task(&list, c);
+    let actual = format_task_without_id(&list, c);
     let expected = task("c", 3, Blocked).deps_stats(1, 2);
     assert_eq!(actual, expected);
 }
@@ -212,7 +234,7 @@ fn format_blocked_task_with_deps_and_adeps() {
     let c = list.add("c");
     list.block(b).on(a).unwrap();
     list.block(c).on(b).unwrap();
-    let actual = format_task(&list, b);
+    let actual = format_task_without_id(&list, b);
     let expected = task("b", 2, Blocked).deps_stats(1, 1);
     assert_eq!(actual, expected);
 }
@@ -228,7 +250,7 @@ fn format_complete_task_does_not_show_deps_or_adeps() {
     list.check(a).unwrap();
     list.check(b).unwrap();
     list.check(c).unwrap();
-    let actual = format_task(&list, b);
+    let actual = format_task_without_id(&list, b);
     let expected = task("b", -1, Complete);
     assert_eq!(actual, expected);
 }
@@ -240,7 +262,7 @@ fn format_incomplete_task_does_not_show_deps() {
     let b = list.add("b");
     list.block(b).on(a).unwrap();
     list.check(a).unwrap();
-    let actual = format_task(&list, b);
+    let actual = format_task_without_id(&list, b);
     let expected = task("b", 1, Incomplete);
     assert_eq!(actual, expected);
 }
@@ -256,7 +278,7 @@ fn format_complete_task_with_punctuality_early() {
             .due_date(now + chrono::Duration::hours(2)),
     );
     list.check(CheckOptions { id: a, now }).unwrap();
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected =
         task("a", 0, Complete).punctuality(-chrono::Duration::hours(2));
     assert_eq!(actual, expected);
@@ -273,7 +295,7 @@ fn format_complete_task_with_punctuality_late() {
             .due_date(now - chrono::Duration::days(3)),
     );
     list.check(CheckOptions { id: a, now }).unwrap();
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected =
         task("a", 0, Complete).punctuality(chrono::Duration::days(3));
     assert_eq!(actual, expected);
@@ -283,7 +305,7 @@ fn format_complete_task_with_punctuality_late() {
 fn format_tag() {
     let mut list = TodoList::default();
     let a = list.add(NewOptions::new().desc("a").as_tag());
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete).as_tag();
     assert_eq!(actual, expected);
 }
@@ -296,7 +318,7 @@ fn format_task_with_implicit_tags() {
     let c = list.add("c");
     list.block(a).on(c).unwrap();
     list.block(b).on(c).unwrap();
-    let actual = format_task(&list, c);
+    let actual = format_task_without_id(&list, c);
     let expected = task("c", 1, Incomplete).adeps_stats(2, 2).tag("b").tag("a");
     assert_eq!(actual, expected);
 }
@@ -309,7 +331,7 @@ fn format_tag_with_implicit_tags() {
     let c = list.add(NewOptions::new().desc("c").as_tag());
     list.block(a).on(c).unwrap();
     list.block(b).on(c).unwrap();
-    let actual = format_task(&list, c);
+    let actual = format_task_without_id(&list, c);
     let expected = task("c", 1, Incomplete)
         .adeps_stats(2, 2)
         .tag("b")
@@ -346,6 +368,27 @@ fn lookup_by_name() {
     assert_eq!(lookup3.as_sorted_vec(&list), [c]);
 }
 
+#[test]
+fn lookup_by_id() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let id_b = list.get(b).unwrap().short_id.clone();
+    let lookup = lookup_tasks(&list, &[Key::ById(id_b)]);
+    assert_eq!(lookup.as_sorted_vec(&list), [b]);
+    let id_a = list.get(a).unwrap().short_id.to_uppercase();
+    let lookup = lookup_tasks(&list, &[Key::ById(id_a)]);
+    assert_eq!(lookup.as_sorted_vec(&list), [a]);
+}
+
+#[test]
+fn lookup_by_id_falls_back_to_name() {
+    let mut list = TodoList::default();
+    let a = list.add("#add");
+    let lookup = lookup_tasks(&list, &[Key::ById("add".to_string())]);
+    assert_eq!(lookup.as_sorted_vec(&list), [a]);
+}
+
 #[test]
 fn lookup_multiple_keys() {
     let mut list = TodoList::default();
@@ -384,7 +427,7 @@ fn tags_are_formatted_in_reverse_sorted_order() {
     list.block(c).on(a).unwrap();
     list.block(d).on(b).unwrap();
     assert_eq!(list.all_tasks().collect::<Vec<_>>(), [a, c, b, d]);
-    let actual = format_task(&list, a);
+    let actual = format_task_without_id(&list, a);
     let expected = task("a", 1, Incomplete)
         .adeps_stats(2, 3)
         .tag("d")
@@ -0,0 +1,77 @@
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use todo_cli::Time;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+
+use super::util::format_task;
+use super::util::lookup_task;
+use super::util::should_include_done;
+
+// The time spent on the task and everything it's transitively blocked on,
+// including the running timer.
+fn time_spent_with_deps(
+    list: &TodoList,
+    id: TaskId,
+    now: DateTime<Utc>,
+) -> Duration {
+    (list.transitive_deps(id) | TaskSet::of(id))
+        .iter_unsorted()
+        .filter_map(|id| list.get(id))
+        .map(|task| task.time_spent(now))
+        .sum()
+}
+
+pub fn run<'list>(
+    list: &'list TodoList,
+    now: DateTime<Utc>,
+    cmd: &Time,
+) -> PrintableResult<'list> {
+    let mut warnings = Vec::new();
+    let tasks = if cmd.keys.is_empty() {
+        // The tasks with time spent on them or their deps are the ones with
+        // time spent on them, and everything those are blocking.
+        list.all_tasks()
+            .filter(|&id| {
+                list.get(id)
+                    .is_some_and(|task| task.time_spent(now) > Duration::zero())
+            })
+            .fold(TaskSet::default(), |so_far, id| {
+                if so_far.contains(id) {
+                    so_far
+                } else {
+                    so_far | TaskSet::of(id) | list.transitive_adeps(id)
+                }
+            })
+            .include_done(list, cmd.include_done)
+    } else {
+        let tasks = cmd.keys.iter().fold(TaskSet::default(), |so_far, key| {
+            let tasks = lookup_task(list, key);
+            if tasks.is_empty() {
+                warnings.push(PrintableWarning::NoMatchFoundForKey {
+                    requested_key: key.clone(),
+                });
+            }
+            so_far | tasks
+        });
+        let include_done =
+            should_include_done(cmd.include_done, list, tasks.iter_unsorted());
+        tasks.include_done(list, include_done)
+    };
+    Ok(PrintableAppSuccess {
+        warnings,
+        tasks: tasks
+            .iter_sorted(list)
+            .map(|id| {
+                format_task(list, id)
+                    .time_spent_with_deps(time_spent_with_deps(list, id, now))
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
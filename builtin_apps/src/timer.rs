@@ -0,0 +1,79 @@
+use chrono::DateTime;
+use chrono::Utc;
+use todo_cli::Start;
+use todo_model::StartTimerError;
+use todo_model::TodoList;
+use todo_printing::Action;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableWarning;
+
+use super::util::format_task;
+use super::util::format_task_brief;
+use super::util::format_tasks_brief;
+use super::util::lookup_task;
+
+fn warn<'list>(warning: PrintableWarning) -> PrintableResult<'list> {
+    Ok(PrintableAppSuccess {
+        warnings: vec![warning],
+        ..Default::default()
+    })
+}
+
+pub fn run_start<'list>(
+    list: &'list mut TodoList,
+    now: DateTime<Utc>,
+    cmd: &Start,
+) -> PrintableResult<'list> {
+    let tasks = lookup_task(list, &cmd.key);
+    let id = match tasks.iter_sorted(list).collect::<Vec<_>>().as_slice() {
+        [] => {
+            return warn(PrintableWarning::NoMatchFoundForKey {
+                requested_key: cmd.key.clone(),
+            })
+        }
+        [id] => *id,
+        _ => {
+            return warn(PrintableWarning::AmbiguousKey {
+                key: cmd.key.clone(),
+                matches: format_tasks_brief(list, &tasks),
+            })
+        }
+    };
+    let stopped = match list.start_timer(id, now) {
+        Ok(stopped) => stopped,
+        Err(StartTimerError::TaskIsComplete) => {
+            return warn(PrintableWarning::CannotStartTimerBecauseComplete {
+                cannot_start: format_task_brief(list, id),
+            })
+        }
+        Err(StartTimerError::AlreadyRunning) => {
+            return warn(PrintableWarning::TimerAlreadyRunning {
+                timed_task: format_task_brief(list, id),
+            })
+        }
+    };
+    Ok(PrintableAppSuccess {
+        tasks: stopped
+            .map(|stopped| format_task(list, stopped).action(Action::Stop))
+            .into_iter()
+            .chain(std::iter::once(format_task(list, id).action(Action::Start)))
+            .collect(),
+        mutated: true,
+        ..Default::default()
+    })
+}
+
+pub fn run_stop<'list>(
+    list: &'list mut TodoList,
+    now: DateTime<Utc>,
+) -> PrintableResult<'list> {
+    match list.stop_timer(now) {
+        Some(stopped) => Ok(PrintableAppSuccess {
+            tasks: vec![format_task(list, stopped).action(Action::Stop)],
+            mutated: true,
+            ..Default::default()
+        }),
+        None => warn(PrintableWarning::NoTimerRunning),
+    }
+}
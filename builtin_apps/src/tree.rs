@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use todo_cli::Tree;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableTask;
+use todo_printing::PrintableWarning;
+use todo_printing::TreePosition;
+
+use super::util::format_task;
+use super::util::lookup_task;
+use super::util::should_include_done;
+
+struct TreePrinter<'a, 'list> {
+    list: &'list TodoList<'a>,
+    up: bool,
+    max_depth: Option<usize>,
+    include_done: bool,
+    visited: HashSet<TaskId>,
+    tasks: Vec<PrintableTask<'list>>,
+}
+
+impl<'list> TreePrinter<'_, 'list> {
+    fn children(&self, id: TaskId) -> Vec<TaskId> {
+        if self.up {
+            self.list.adeps(id)
+        } else {
+            self.list.deps(id)
+        }
+        .include_done(self.list, self.include_done)
+        .iter_sorted(self.list)
+        .collect()
+    }
+
+    // Adds the task and, the first time it is visited above the depth limit,
+    // its subtree. A task is only marked as visited once its subtree is
+    // shown, so that a task cut off by the depth limit is still shown in full
+    // where it appears higher up in the tree.
+    fn visit(&mut self, id: TaskId, is_last: &mut Vec<bool>) {
+        let back_reference = self.visited.contains(&id);
+        self.tasks.push(format_task(self.list, id).tree_position(
+            TreePosition {
+                is_last: is_last.clone(),
+                back_reference,
+            },
+        ));
+        if back_reference
+            || self.max_depth.is_some_and(|max| is_last.len() >= max)
+        {
+            return;
+        }
+        self.visited.insert(id);
+        let children = self.children(id);
+        let num_children = children.len();
+        for (i, child) in children.into_iter().enumerate() {
+            is_last.push(i + 1 == num_children);
+            self.visit(child, is_last);
+            is_last.pop();
+        }
+    }
+}
+
+pub fn run<'list>(list: &'list TodoList, cmd: &Tree) -> PrintableResult<'list> {
+    let mut warnings = Vec::new();
+    let roots = if cmd.keys.is_empty() {
+        // Without keys, start from the tasks at the edge of the graph in the
+        // direction we're coming from, like 'todo top'.
+        list.all_tasks()
+            .filter(|&id| {
+                cmd.include_done
+                    || list.status(id) != Some(TaskStatus::Complete)
+            })
+            .filter(|&id| {
+                if cmd.up {
+                    list.deps(id).is_empty()
+                } else {
+                    list.adeps(id).is_empty()
+                }
+            })
+            .collect::<TaskSet>()
+    } else {
+        cmd.keys.iter().fold(TaskSet::default(), |so_far, key| {
+            let tasks = lookup_task(list, key);
+            if tasks.is_empty() {
+                warnings.push(PrintableWarning::NoMatchFoundForKey {
+                    requested_key: key.clone(),
+                });
+            }
+            so_far | tasks
+        })
+    };
+    let mut printer = TreePrinter {
+        list,
+        up: cmd.up,
+        max_depth: cmd.depth,
+        include_done: should_include_done(
+            cmd.include_done,
+            list,
+            roots.iter_unsorted(),
+        ),
+        visited: HashSet::new(),
+        tasks: Vec::new(),
+    };
+    for root in roots.iter_sorted(list) {
+        printer.visit(root, &mut Vec::new());
+    }
+    Ok(PrintableAppSuccess {
+        tasks: printer.tasks,
+        warnings,
+        ..Default::default()
+    })
+}
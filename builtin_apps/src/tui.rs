@@ -0,0 +1,538 @@
+use std::io;
+use std::io::IsTerminal;
+
+use ansi_to_tui::IntoText;
+use chrono::DateTime;
+use chrono::Utc;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event;
+use ratatui::crossterm::event::Event;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::crossterm::event::KeyEventKind;
+use ratatui::layout::Constraint;
+use ratatui::layout::Layout;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::style::Stylize;
+use ratatui::text::Text;
+use ratatui::widgets;
+use ratatui::widgets::List;
+use ratatui::widgets::ListItem;
+use ratatui::widgets::ListState;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use ratatui::Terminal;
+use todo_cli::Block;
+use todo_cli::Check;
+use todo_cli::Edit;
+use todo_cli::Get;
+use todo_cli::Priority;
+use todo_cli::Punt;
+use todo_cli::Snooze;
+use todo_cli::Tui;
+use todo_clock::Clock;
+use todo_config::ConfigModel;
+use todo_lookup_key::Key;
+use todo_model::TodoList;
+use todo_printing::Printable;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableError;
+use todo_printing::PrintableResult;
+use todo_printing::PrintingContext;
+use todo_printing::SimpleTodoPrinter;
+use todo_printing::TodoPrinter;
+use todo_text_editing::TextEditor;
+
+use super::block;
+use super::check;
+use super::edit;
+use super::get;
+use super::priority;
+use super::punt;
+use super::snooze;
+use super::status;
+
+// The height of the pane showing the output of the last action, including
+// its borders.
+const OUTPUT_HEIGHT: u16 = 6;
+
+const HELP: &str = "j/k move  c check  p punt  s snooze  P priority  \
+                    e edit  m mark  b block marked  B blocked  D done  \
+                    q quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prompt {
+    Snooze,
+    Priority,
+    Edit,
+}
+
+impl Prompt {
+    fn label(self) -> &'static str {
+        match self {
+            Prompt::Snooze => "Snooze until: ",
+            Prompt::Priority => "Priority: ",
+            Prompt::Edit => "Description: ",
+        }
+    }
+}
+
+struct Input {
+    prompt: Prompt,
+    text: String,
+}
+
+enum Action {
+    Check,
+    Punt,
+    Snooze(String),
+    Priority(i32),
+    Edit(String),
+    // Blocks the marked task, given by its key, on the selected task.
+    Block(Key),
+}
+
+// A task in the list, with the key used to select it in commands.
+struct Row {
+    key: Key,
+    desc: String,
+    text: String,
+}
+
+struct Panes {
+    list: Rect,
+    context: Rect,
+    output: Rect,
+    footer: Rect,
+}
+
+impl Panes {
+    fn new(area: Rect) -> Self {
+        let [main, output, footer] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(OUTPUT_HEIGHT),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        let [list, context] = Layout::horizontal([
+            Constraint::Percentage(60),
+            Constraint::Percentage(40),
+        ])
+        .areas(main);
+        Panes {
+            list,
+            context,
+            output,
+            footer,
+        }
+    }
+}
+
+// The number of columns inside a pane's borders.
+fn inner_width(pane: Rect) -> usize {
+    pane.width.saturating_sub(2).max(1) as usize
+}
+
+fn printer(
+    width: usize,
+    max_index_digits: usize,
+    now: DateTime<Utc>,
+    show_ids: bool,
+) -> SimpleTodoPrinter<Vec<u8>> {
+    SimpleTodoPrinter {
+        out: Vec::new(),
+        context: PrintingContext {
+            max_index_digits,
+            width,
+            now,
+            show_ids,
+        },
+    }
+}
+
+// Prints a result the way the command line would, returning the printed
+// text and whether the list was modified.
+fn render(
+    result: &PrintableResult,
+    width: usize,
+    now: DateTime<Utc>,
+    show_ids: bool,
+) -> (String, bool) {
+    let mut printer = printer(width, result.max_index_digits(), now, show_ids);
+    let mutated = result.print(&mut printer);
+    (String::from_utf8_lossy(&printer.out).into_owned(), mutated)
+}
+
+// Converts printed text, which is colored with ANSI escape codes, to text
+// that can be drawn in a pane.
+fn to_text(s: &str) -> Text<'static> {
+    let s = s.trim_end_matches('\n');
+    s.into_text().unwrap_or_else(|_| Text::raw(s.to_string()))
+}
+
+struct State {
+    include_blocked: bool,
+    include_done: bool,
+    list_state: ListState,
+    // The key of the selected task, so that it stays selected when other
+    // tasks move around it.
+    selected: Option<Key>,
+    // The key and description of the task to block with 'b'.
+    marked: Option<(Key, String)>,
+    input: Option<Input>,
+    output: String,
+    mutated: bool,
+    quit: bool,
+}
+
+impl State {
+    fn new(cmd: &Tui) -> Self {
+        State {
+            include_blocked: cmd.include_blocked,
+            include_done: cmd.include_done,
+            list_state: ListState::default(),
+            selected: None,
+            marked: None,
+            input: None,
+            output: String::new(),
+            mutated: false,
+            quit: false,
+        }
+    }
+
+    // Selects the same task as before if it's still in the list, or else the
+    // task that took its place.
+    fn reselect(&mut self, rows: &[Row]) {
+        let index = rows
+            .iter()
+            .position(|row| Some(&row.key) == self.selected.as_ref())
+            .or(self.list_state.selected())
+            .map(|index| index.min(rows.len().saturating_sub(1)));
+        self.select(rows, index.unwrap_or_default());
+    }
+
+    fn select(&mut self, rows: &[Row], index: usize) {
+        let row = rows.get(index);
+        self.list_state.select(row.map(|_| index));
+        self.selected = row.map(|row| row.key.clone());
+    }
+
+    fn selected_row<'a>(&self, rows: &'a [Row]) -> Option<&'a Row> {
+        rows.get(self.list_state.selected()?)
+    }
+
+    fn handle_input(&mut self, code: KeyCode) -> Option<Action> {
+        let input = self.input.as_mut()?;
+        match code {
+            KeyCode::Char(c) => input.text.push(c),
+            KeyCode::Backspace => {
+                input.text.pop();
+            }
+            KeyCode::Esc => self.input = None,
+            KeyCode::Enter => {
+                let action = match input.prompt {
+                    Prompt::Snooze => Action::Snooze(input.text.clone()),
+                    // Keep asking until the priority is a number.
+                    Prompt::Priority => {
+                        Action::Priority(input.text.trim().parse().ok()?)
+                    }
+                    Prompt::Edit => Action::Edit(input.text.clone()),
+                };
+                self.input = None;
+                return Some(action);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn prompt(&mut self, prompt: Prompt, text: String) {
+        self.input = Some(Input { prompt, text });
+    }
+
+    fn handle(&mut self, event: Event, rows: &[Row]) -> Option<Action> {
+        let Event::Key(key) = event else {
+            return None;
+        };
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if self.input.is_some() {
+            return self.handle_input(key.code);
+        }
+        let index = self.list_state.selected().unwrap_or_default();
+        let last = rows.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.select(rows, (index + 1).min(last))
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.select(rows, index.saturating_sub(1))
+            }
+            KeyCode::Char('g') | KeyCode::Home => self.select(rows, 0),
+            KeyCode::Char('G') | KeyCode::End => self.select(rows, last),
+            KeyCode::Char('B') => self.include_blocked = !self.include_blocked,
+            KeyCode::Char('D') => self.include_done = !self.include_done,
+            _ => {
+                let row = self.selected_row(rows)?;
+                match key.code {
+                    KeyCode::Char('c') => return Some(Action::Check),
+                    KeyCode::Char('p') => return Some(Action::Punt),
+                    KeyCode::Char('s') => {
+                        self.prompt(Prompt::Snooze, String::new())
+                    }
+                    KeyCode::Char('P') => {
+                        self.prompt(Prompt::Priority, String::new())
+                    }
+                    KeyCode::Char('e') => {
+                        self.prompt(Prompt::Edit, row.desc.clone())
+                    }
+                    KeyCode::Char('m') => {
+                        self.marked = Some((row.key.clone(), row.desc.clone()))
+                    }
+                    KeyCode::Char('b') => {
+                        let (marked, _) = self.marked.take()?;
+                        return Some(Action::Block(marked));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+}
+
+struct View {
+    rows: Vec<Row>,
+    // The tasks related to the selected task, as 'todo get' prints them.
+    context: String,
+}
+
+impl View {
+    fn new(
+        list: &mut TodoList,
+        now: DateTime<Utc>,
+        show_ids: bool,
+        panes: &Panes,
+        state: &mut State,
+    ) -> Self {
+        let result = status::run(
+            list,
+            now,
+            &status::Status {
+                include_blocked: state.include_blocked,
+                include_done: state.include_done,
+            },
+        );
+        let max_index_digits = result.max_index_digits();
+        let rows: Vec<_> = match &result {
+            Ok(PrintableAppSuccess { tasks, mutated, .. }) => {
+                // Showing the list unsnoozes tasks whose snooze date has
+                // passed, just like 'todo' does.
+                state.mutated |= mutated;
+                tasks
+                    .iter()
+                    .map(|task| {
+                        let mut printer = printer(
+                            inner_width(panes.list),
+                            max_index_digits,
+                            now,
+                            show_ids,
+                        );
+                        printer.print_task(task);
+                        Row {
+                            key: match task.short_id {
+                                Some(short_id) => {
+                                    Key::ById(short_id.to_string())
+                                }
+                                None => Key::ByNumber(task.number),
+                            },
+                            desc: task.desc.to_string(),
+                            text: String::from_utf8_lossy(&printer.out)
+                                .into_owned(),
+                        }
+                    })
+                    .collect()
+            }
+            Err(_) => Vec::new(),
+        };
+        state.reselect(&rows);
+        let context = match &state.selected {
+            Some(key) => {
+                let get = Get {
+                    keys: vec![key.clone()],
+                    ..Default::default()
+                };
+                let result = get::run(list, &get);
+                render(&result, inner_width(panes.context), now, show_ids).0
+            }
+            None => String::new(),
+        };
+        View { rows, context }
+    }
+}
+
+fn draw(frame: &mut Frame, panes: &Panes, view: &View, state: &mut State) {
+    let mut title = " Tasks ".to_string();
+    if state.include_blocked {
+        title.push_str("+blocked ");
+    }
+    if state.include_done {
+        title.push_str("+done ");
+    }
+    if let Some((_, desc)) = &state.marked {
+        title.push_str(&format!("(marked: {desc}) "));
+    }
+    let items = view
+        .rows
+        .iter()
+        .map(|row| ListItem::new(to_text(&row.text)));
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(widgets::Block::bordered().title(title))
+            .highlight_style(Style::new().reversed()),
+        panes.list,
+        &mut state.list_state,
+    );
+    frame.render_widget(
+        Paragraph::new(to_text(&view.context))
+            .block(widgets::Block::bordered().title(" Related ")),
+        panes.context,
+    );
+    frame.render_widget(
+        Paragraph::new(to_text(&state.output))
+            .block(widgets::Block::bordered().title(" Output ")),
+        panes.output,
+    );
+    match &state.input {
+        Some(Input { prompt, text }) => {
+            let line = format!("{}{}", prompt.label(), text);
+            let column = panes.footer.x + line.chars().count() as u16;
+            frame.render_widget(Paragraph::new(line), panes.footer);
+            frame.set_cursor_position((column, panes.footer.y));
+        }
+        None => frame.render_widget(Paragraph::new(HELP).dim(), panes.footer),
+    }
+}
+
+// Runs the action on the selected task with the same code as the command
+// line.
+fn apply<'list>(
+    list: &'list mut TodoList,
+    text_editor: &impl TextEditor,
+    now: DateTime<Utc>,
+    key: Key,
+    action: Action,
+) -> PrintableResult<'list> {
+    let keys = vec![key];
+    match action {
+        Action::Check => check::run(list, now, &Check { keys, force: false }),
+        Action::Punt => punt::run(list, &Punt { keys }),
+        Action::Snooze(until) => snooze::run(
+            list,
+            now,
+            &Snooze {
+                keys,
+                until: until.split_whitespace().map(str::to_string).collect(),
+            },
+        ),
+        Action::Priority(priority) => priority::run(
+            list,
+            &Priority {
+                keys,
+                priority: Some(priority),
+                include_done: false,
+            },
+        ),
+        Action::Edit(desc) => edit::run(
+            list,
+            text_editor,
+            &Edit {
+                keys,
+                desc: Some(desc),
+                include_done: false,
+            },
+        ),
+        Action::Block(marked) => block::run(
+            list,
+            &Block {
+                keys: vec![marked],
+                on: keys,
+                include_done: false,
+            },
+        ),
+    }
+}
+
+/// Runs the TUI in the given terminal until the user quits or there are no
+/// more events. Returns whether the list was modified.
+pub fn run_in_terminal<B: Backend>(
+    list: &mut TodoList,
+    text_editor: &impl TextEditor,
+    clock: &impl Clock,
+    show_ids: bool,
+    cmd: &Tui,
+    terminal: &mut Terminal<B>,
+    events: impl IntoIterator<Item = io::Result<Event>>,
+) -> io::Result<bool> {
+    let mut state = State::new(cmd);
+    let mut events = events.into_iter();
+    loop {
+        let size = terminal.size()?;
+        let panes = Panes::new(Rect::new(0, 0, size.width, size.height));
+        let view = View::new(list, clock.now(), show_ids, &panes, &mut state);
+        terminal.draw(|frame| draw(frame, &panes, &view, &mut state))?;
+        let Some(event) = events.next() else {
+            break;
+        };
+        let action = state.handle(event?, &view.rows);
+        if state.quit {
+            break;
+        }
+        let (Some(action), Some(key)) = (action, state.selected.clone()) else {
+            continue;
+        };
+        let now = clock.now();
+        let result = apply(list, text_editor, now, key, action);
+        let (output, mutated) =
+            render(&result, inner_width(panes.output), now, show_ids);
+        state.output = output;
+        state.mutated |= mutated;
+    }
+    Ok(state.mutated)
+}
+
+pub fn run<'list>(
+    list: &'list mut TodoList,
+    text_editor: &impl TextEditor,
+    clock: &impl Clock,
+    config: &ConfigModel,
+    cmd: &Tui,
+) -> PrintableResult<'list> {
+    let cannot_use_terminal = |explanation: String| {
+        vec![PrintableError::CannotUseTerminal { explanation }]
+    };
+    if !io::stdout().is_terminal() {
+        return Err(cannot_use_terminal(
+            "standard output is not a terminal".to_string(),
+        ));
+    }
+    let mut terminal =
+        ratatui::try_init().map_err(|e| cannot_use_terminal(e.to_string()))?;
+    let result = run_in_terminal(
+        list,
+        text_editor,
+        clock,
+        todo_config::Config::new(config).show_ids,
+        cmd,
+        &mut terminal,
+        std::iter::repeat_with(event::read),
+    );
+    ratatui::restore();
+    let mutated = result.map_err(|e| cannot_use_terminal(e.to_string()))?;
+    Ok(PrintableAppSuccess {
+        mutated,
+        ..Default::default()
+    })
+}
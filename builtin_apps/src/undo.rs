@@ -0,0 +1,151 @@
+use todo_cli::Undo;
+use todo_journal::Journal;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_printing::Action;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableInfo;
+use todo_printing::PrintableResult;
+use todo_printing::PrintableTask;
+use todo_printing::PrintableWarning;
+
+use super::util::format_task;
+
+// Task ids may be reused after a task is removed, so a task is only considered
+// to be the same in both lists if it was created at the same time.
+fn is_same_task(a: &TodoList, b: &TodoList, id: TaskId) -> bool {
+    match (a.get(id), b.get(id)) {
+        (Some(a), Some(b)) => a.creation_time == b.creation_time,
+        _ => false,
+    }
+}
+
+fn action_for_change(old: &TodoList, new: &TodoList, id: TaskId) -> Action {
+    use TaskStatus::*;
+    match (old.status(id), new.status(id)) {
+        (Some(Complete), Some(Incomplete | Blocked)) => Action::Uncheck,
+        (Some(Incomplete | Blocked), Some(Complete)) => Action::Check,
+        (Some(Blocked), Some(Incomplete)) => Action::Unlock,
+        (Some(Incomplete), Some(Blocked)) => Action::Lock,
+        _ => Action::None,
+    }
+}
+
+fn has_changed(old: &TodoList, new: &TodoList, id: TaskId) -> bool {
+    old.get(id) != new.get(id) || old.deps(id) != new.deps(id)
+}
+
+/// Describes how the list changed when |old| was replaced by |new|. Tasks
+/// that only exist in |new| are printed as new tasks, tasks that only exist in
+/// |old| are reported as removed, and tasks that exist in both are printed if
+/// anything about them changed.
+fn format_changes<'list>(
+    old: &TodoList,
+    new: &'list TodoList,
+) -> (Vec<PrintableInfo>, Vec<PrintableTask<'list>>) {
+    let removed = old
+        .all_tasks()
+        .filter(|&id| !is_same_task(old, new, id))
+        .map(|id| PrintableInfo::Removed {
+            desc: old.get(id).unwrap().desc.to_string(),
+        })
+        .collect();
+    let tasks = new
+        .all_tasks()
+        .filter_map(|id| {
+            if !is_same_task(old, new, id) {
+                Some((id, Action::New))
+            } else if has_changed(old, new, id) {
+                Some((id, action_for_change(old, new, id)))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let tasks_to_print = tasks
+        .iter()
+        .map(|&(id, _)| id)
+        .collect::<TaskSet>()
+        .iter_sorted(new)
+        .map(|id| {
+            let action = tasks
+                .iter()
+                .find(|&&(other, _)| other == id)
+                .map(|&(_, action)| action)
+                .unwrap_or_default();
+            format_task(new, id).action(action)
+        })
+        .collect();
+    (removed, tasks_to_print)
+}
+
+fn list_undoable_commands<'list>(journal: &Journal) -> PrintableResult<'list> {
+    Ok(PrintableAppSuccess {
+        infos: journal
+            .undo_entries()
+            .map(|entry| PrintableInfo::UndoableCommand {
+                command: entry.command.clone(),
+                time: entry.time,
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
+
+pub fn run<'list, 'ser>(
+    list: &'list mut TodoList<'ser>,
+    journal: &mut Journal<'ser>,
+    cmd: &Undo,
+) -> PrintableResult<'list> {
+    if cmd.list {
+        return list_undoable_commands(journal);
+    }
+    let command = match journal.undo(list) {
+        Some(command) => command,
+        None => {
+            return Ok(PrintableAppSuccess {
+                warnings: vec![PrintableWarning::NothingToUndo],
+                ..Default::default()
+            })
+        }
+    };
+    // The list from before the undo is now at the top of the redo stack.
+    let old = &journal.redo_entries().next().unwrap().list;
+    let (removed, tasks) = format_changes(old, list);
+    Ok(PrintableAppSuccess {
+        infos: std::iter::once(PrintableInfo::Undid { command })
+            .chain(removed)
+            .collect(),
+        tasks,
+        mutated: true,
+        ..Default::default()
+    })
+}
+
+pub fn run_redo<'list, 'ser>(
+    list: &'list mut TodoList<'ser>,
+    journal: &mut Journal<'ser>,
+) -> PrintableResult<'list> {
+    let command = match journal.redo(list) {
+        Some(command) => command,
+        None => {
+            return Ok(PrintableAppSuccess {
+                warnings: vec![PrintableWarning::NothingToRedo],
+                ..Default::default()
+            })
+        }
+    };
+    // The list from before the redo is now at the top of the undo stack.
+    let old = &journal.undo_entries().next().unwrap().list;
+    let (removed, tasks) = format_changes(old, list);
+    Ok(PrintableAppSuccess {
+        infos: std::iter::once(PrintableInfo::Redid { command })
+            .chain(removed)
+            .collect(),
+        tasks,
+        mutated: true,
+        ..Default::default()
+    })
+}
@@ -16,6 +16,7 @@ use todo_printing::Plicit;
 use todo_printing::PrintableError;
 use todo_printing::PrintableTask;
 use todo_printing::Status;
+use todo_time_format::Recurrence;
 
 fn to_printing_status(status: TaskStatus) -> Status {
     match status {
@@ -108,6 +109,9 @@ pub fn format_task<'list>(
             let mut result =
                 PrintableTask::new(&task.desc, pos, to_printing_status(status))
                     .truncate_tags_if_needed(true);
+            if !task.short_id.is_empty() {
+                result = result.short_id(&task.short_id);
+            }
             if implicit_priority != 0 {
                 result =
                     result.priority(if implicit_priority == task.priority {
@@ -140,6 +144,15 @@ pub fn format_task<'list>(
             if task.tag {
                 result = result.as_tag();
             }
+            if !task.notes.is_empty() {
+                result = result.notes(&task.notes);
+            }
+            if !task.work_log.is_empty() {
+                result = result.time_spent(task.logged_time());
+            }
+            if let Some(timer_start) = task.timer_start {
+                result = result.timer_start(timer_start);
+            }
             for tag_id in TaskSet::from_iter(task.implicit_tags.iter().cloned())
                 .iter_sorted(list)
                 .rev()
@@ -191,6 +204,12 @@ pub fn lookup_task(list: &TodoList, key: &Key) -> TaskSet {
                     .is_some()
             })
             .collect(),
+        // A description like "#add" looks like an ID, so fall back to
+        // matching it as a description if no task has that ID.
+        Key::ById(ref short_id) => match list.lookup_by_short_id(short_id) {
+            Some(id) => TaskSet::of(id),
+            None => lookup_task(list, &Key::ByName(format!("#{short_id}"))),
+        },
     }
 }
 
@@ -255,7 +274,7 @@ pub fn parse_budget(
     if budget_string == "0" || budget_string.is_empty() {
         return Ok(DurationInSeconds::default());
     }
-    match humantime::parse_duration(&budget_string) {
+    match todo_time_format::parse_duration(&budget_string) {
         Ok(duration) => {
             Ok(DurationInSeconds(match u32::try_from(duration.as_secs()) {
                 Ok(secs) => secs,
@@ -273,6 +292,21 @@ pub fn parse_budget(
     }
 }
 
+pub fn parse_recurrence(
+    chunks: &[String],
+) -> Result<Option<Recurrence>, PrintableError> {
+    if chunks.is_empty() {
+        return Ok(None);
+    }
+    let recurrence_string = chunks.join(" ");
+    match ::todo_time_format::parse_recurrence(&recurrence_string) {
+        Ok(recurrence) => Ok(Some(recurrence)),
+        Err(_) => Err(PrintableError::CannotParseRecurrence {
+            cannot_parse: recurrence_string,
+        }),
+    }
+}
+
 pub fn parse_snooze_date(
     now: DateTime<Utc>,
     chunks: &[String],
@@ -3,6 +3,7 @@ mod subcommand;
 mod subcommands;
 
 pub use self::options::Options;
+pub use self::options::OutputFormat;
 pub use self::subcommand::SubCommand;
 pub use self::subcommands::*;
 
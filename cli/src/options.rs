@@ -1,4 +1,7 @@
+use clap::value_parser;
+use clap::Arg;
 use clap::Parser;
+use clap::ValueEnum;
 
 use crate::SubCommand;
 
@@ -37,6 +40,11 @@ use crate::SubCommand;
 /// and the second, 'todo', showed the updated list, with "wash the dishes" now
 /// the first and only remaining task, and therefore with position 1.
 ///
+/// Since positions change as the list changes, every task also has a short ID
+/// that never changes, like "a3f9". Run 'todo config show_ids true' to show
+/// the IDs, and refer to a task by its ID with a leading '#', quoted so that
+/// the shell doesn't treat it as a comment: 'todo check "#a3f9"'.
+///
 /// Notice that the completed "walk the dog" task was updated to position 0.
 /// Checking a task off does not remove it from the list. You can view completed
 /// tasks by using 'todo log', which at this point will show:
@@ -135,4 +143,37 @@ pub struct Options {
     /// Show all tasks in the status.
     #[arg(long, short = 'a')]
     pub include_all: bool,
+
+    /// Print machine-readable output instead of text. 'json' prints a single
+    /// object when the command finishes, with a 'mutated' flag and arrays of
+    /// 'tasks', 'infos', 'warnings', and 'errors'. 'jsonl' prints one object
+    /// per line, each with a 'type' field, followed by a 'result' object with
+    /// the 'mutated' flag.
+    #[arg(long = "format", id = "output_format", global = true)]
+    pub format: Option<OutputFormat>,
+
+    /// Show what the command would do without saving any changes to the list
+    /// or the config.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+}
+
+/// The machine-readable formats that can be given to the global --format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Jsonl,
+}
+
+/// Takes the place of the global --format in subcommands that have a --format
+/// of their own, like 'todo export'. Clap adds a global argument to every
+/// subcommand unless it already has one with the same id, and two arguments
+/// in a subcommand can't share a name. The output format can also be given
+/// before the subcommand, as in 'todo --format json export --format dot'.
+pub(crate) fn output_format_arg() -> Arg {
+    Arg::new("output_format")
+        .long("output-format")
+        .value_name("FORMAT")
+        .help("Print machine-readable output, like the global --format")
+        .value_parser(value_parser!(OutputFormat))
 }
@@ -1,7 +1,13 @@
 use clap::Parser;
 
+use crate::testing::expect_error;
 use crate::testing::expect_parses_into;
+use crate::Export;
+use crate::ExportFormat;
+use crate::Import;
+use crate::ImportFormat;
 use crate::Options;
+use crate::OutputFormat;
 use crate::SubCommand;
 
 fn parse<I>(args: I) -> Options
@@ -45,3 +51,103 @@ fn status_include_all() {
 fn log() {
     expect_parses_into("todo log", SubCommand::Log);
 }
+
+#[test]
+fn format_defaults_to_text() {
+    let options = parse(&["todo"]);
+    assert_eq!(options.format, None);
+}
+
+#[test]
+fn format_json() {
+    let options = parse(&["todo", "--format", "json"]);
+    assert_eq!(options.format, Some(OutputFormat::Json));
+}
+
+#[test]
+fn format_jsonl() {
+    let options = parse(&["todo", "--format", "jsonl"]);
+    assert_eq!(options.format, Some(OutputFormat::Jsonl));
+}
+
+#[test]
+fn format_after_subcommand() {
+    let options = parse(&["todo", "log", "--format", "json"]);
+    assert_eq!(options.cmd, Some(SubCommand::Log));
+    assert_eq!(options.format, Some(OutputFormat::Json));
+}
+
+#[test]
+fn format_for_export_only() {
+    expect_error("todo --format dot");
+    expect_error("todo log --format mermaid");
+    expect_error("todo --format todo.txt");
+}
+
+#[test]
+fn output_format_with_export_format() {
+    let options =
+        parse(&["todo", "--format", "json", "export", "--format", "dot"]);
+    assert_eq!(options.format, Some(OutputFormat::Json));
+    assert!(matches!(
+        options.cmd,
+        Some(SubCommand::Export(Export {
+            format: ExportFormat::Dot,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn output_format_after_export() {
+    let options = parse(&[
+        "todo",
+        "export",
+        "--format",
+        "dot",
+        "--output-format",
+        "json",
+    ]);
+    assert_eq!(options.format, Some(OutputFormat::Json));
+}
+
+#[test]
+fn output_format_with_import_format() {
+    let options = parse(&[
+        "todo", "--format", "jsonl", "import", "--format", "md", "a.md",
+    ]);
+    assert_eq!(options.format, Some(OutputFormat::Jsonl));
+    assert!(matches!(
+        options.cmd,
+        Some(SubCommand::Import(Import {
+            format: ImportFormat::Md,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn format_unknown() {
+    expect_error("todo --format xml");
+}
+
+#[test]
+fn dry_run_defaults_to_false() {
+    let options = parse(&["todo", "rm", "1"]);
+    assert!(!options.dry_run);
+}
+
+#[test]
+fn dry_run_after_subcommand() {
+    let options = parse(&["todo", "rm", "1..40", "--dry-run"]);
+    assert!(matches!(options.cmd, Some(SubCommand::Rm(_))));
+    assert!(options.dry_run);
+}
+
+#[test]
+fn dry_run_before_subcommand() {
+    let options =
+        parse(&["todo", "--dry-run", "merge", "a", "b", "--into", "c"]);
+    assert!(matches!(options.cmd, Some(SubCommand::Merge(_))));
+    assert!(options.dry_run);
+}
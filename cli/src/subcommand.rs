@@ -4,17 +4,24 @@ pub use crate::subcommands::*;
 
 #[derive(Debug, PartialEq, Eq, Parser)]
 pub enum SubCommand {
+    Agenda(Agenda),
+    Batch(Batch),
     Block(Block),
     Bottom(Bottom),
     Budget(Budget),
     Chain(Chain),
     Check(Check),
     Clean(Clean),
+    #[command(name = "__complete-keys", hide = true)]
+    CompleteKeys(CompleteKeys),
+    Completions(Completions),
     Config(Config),
     Due(Due),
     Edit(Edit),
+    Export(Export),
     Find(Find),
     Get(Get),
+    Import(Import),
 
     /// Shows completed tasks.
     ///
@@ -26,19 +33,47 @@ pub enum SubCommand {
     #[command(verbatim_doc_comment)]
     Log,
 
+    Ls(Ls),
     Merge(Merge),
     New(New),
+    Note(Note),
     Path(Path),
     Priority(Priority),
     Punt(Punt),
     Put(Put),
+    Recover(Recover),
+
+    /// Reapplies the most recently undone command.
+    ///
+    /// Commands undone with 'todo undo' can be redone in the reverse order
+    /// that they were undone, as long as no other command has modified the
+    /// list in the meantime.
+    #[command(verbatim_doc_comment)]
+    Redo,
+
     Restore(Restore),
     Rm(Rm),
+    Schedule(Schedule),
+    Shell(Shell),
     Snooze(Snooze),
     Snoozed(Snoozed),
     Split(Split),
+    Start(Start),
+    Stats(Stats),
+
+    /// Stops the running timer.
+    ///
+    /// The time since 'todo start' is added to the work log of the task that
+    /// was being timed.
+    #[command(verbatim_doc_comment)]
+    Stop,
+
     Tag(Tag),
+    Time(Time),
     Top(Top),
+    Tree(Tree),
+    Tui(Tui),
     Unblock(Unblock),
+    Undo(Undo),
     Unsnooze(Unsnooze),
 }
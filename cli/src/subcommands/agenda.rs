@@ -0,0 +1,26 @@
+use clap::Parser;
+
+/// Shows incomplete tasks by the day they're due or wake up from snooze.
+///
+/// Tasks are listed under headings for the days they're due, counting due
+/// dates inherited from the tasks they block, and for the days that snoozed
+/// tasks wake up. A snoozed task with a due date is listed under both days.
+/// Tasks that are past due are listed under "Overdue", and tasks that are
+/// further out than '--days' under "Later". Days start and end at midnight
+/// in the local time zone.
+///
+/// Tasks without a due date that aren't snoozed are not shown.
+#[derive(Debug, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Agenda {
+    /// How many days, starting today, to give their own headings.
+    #[arg(long, default_value_t = 7)]
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub days: u32,
+}
+
+impl Default for Agenda {
+    fn default() -> Self {
+        Agenda { days: 7 }
+    }
+}
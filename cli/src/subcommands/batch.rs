@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Run many commands from a file as one change to the list.
+///
+/// Each line of the file is a command, written as it would be after 'todo'.
+/// Blank lines and lines starting with '#' are ignored:
+///
+///   # plan.todo
+///   new "write draft" --as $draft
+///   new "get feedback" --blocked-by $draft --as $feedback
+///   new "publish" --blocked-by $feedback
+///
+///   todo batch plan.todo
+///   generate-plan | todo batch
+///
+/// Add '--as $name' to a command that creates one task to name it, and use
+/// '$name' in later lines to refer to that task instead of its number, which
+/// may change as the batch runs. Names only last for the batch.
+///
+/// The list is saved once, after the last command, and 'todo undo' undoes
+/// the whole batch. If any command fails, nothing is changed, unless
+/// '--keep-going' is given, in which case failed commands are skipped with a
+/// warning.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Batch {
+    /// The file to read commands from, or '-' for standard input.
+    #[arg(default_value = "-")]
+    pub file: PathBuf,
+    /// Skip commands that fail instead of changing nothing.
+    #[arg(long)]
+    pub keep_going: bool,
+}
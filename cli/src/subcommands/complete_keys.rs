@@ -0,0 +1,14 @@
+use clap::Parser;
+
+/// Print the keys of the tasks that a subcommand can take, one per line.
+///
+/// This is used by the scripts that 'todo completions' prints, and is not
+/// meant to be run directly.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+pub struct CompleteKeys {
+    /// The subcommand being completed.
+    pub subcommand: Option<String>,
+    /// Print descriptions as they are, without quoting them for the shell.
+    #[arg(long)]
+    pub raw: bool,
+}
@@ -0,0 +1,34 @@
+use clap::Parser;
+use clap::ValueEnum;
+
+/// Print a script that completes 'todo' commands in your shell.
+///
+/// Subcommands and flags are completed, and so are the numbers and
+/// descriptions of tasks in your list, choosing the tasks that make sense for
+/// each subcommand: only complete tasks for 'todo restore', and only snoozed
+/// tasks for 'todo unsnooze', for example.
+///
+/// To enable completions, load the script when your shell starts:
+///
+///   # ~/.bashrc
+///   source <(todo completions bash)
+///
+///   # ~/.zshrc, after compinit
+///   source <(todo completions zsh)
+///
+///   # fish
+///   todo completions fish > ~/.config/fish/completions/todo.fish
+#[derive(Debug, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Completions {
+    /// The shell to complete commands in.
+    #[arg(value_enum)]
+    pub shell: CompletionShell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
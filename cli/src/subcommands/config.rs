@@ -7,6 +7,24 @@ use clap::Parser;
 /// 'key' and 'value' are provided, then the config value is mutated and printed
 /// if successful. If '--reset' is passed, the config value is restored to the
 /// default.
+///
+/// Valid keys are:
+///
+///   paginator_cmd    The command used to page output, e.g. "less -rFX".
+///   text_editor_cmd  The command used to edit text, e.g. "vim".
+///   undo_limit       The number of commands that 'todo undo' can undo.
+///   backup_count     The number of previous versions of the list to keep
+///                    for 'todo recover'.
+///   lock_timeout_secs
+///                    How long to wait for another 'todo' process to
+///                    release the list before giving up.
+///   show_ids         Whether to show each task's short ID, e.g. "#a3f9",
+///                    which can be used to refer to the task even after its
+///                    number changes.
+///   shell_autosave   Whether 'todo shell' saves the list after every change,
+///                    rather than only when it exits.
+///
+/// Config values are saved in 'config.json' in the todo config directory.
 #[derive(Debug, PartialEq, Eq, Parser)]
 #[command(allow_negative_numbers(true), verbatim_doc_comment)]
 pub struct Config {
@@ -15,6 +33,6 @@ pub struct Config {
     /// If provided, sets the config value for the given key.
     pub value: Vec<String>,
     /// If passed, resets the config value to the default.
-    #[arg(long)]
+    #[arg(long, requires = "key", conflicts_with = "value")]
     pub reset: bool,
 }
@@ -0,0 +1,78 @@
+use clap::Parser;
+use clap::ValueEnum;
+use todo_lookup_key::Key;
+
+use crate::options::output_format_arg;
+
+/// Export the dependency graph to a diagram, or the list to another format.
+///
+/// The output is printed to stdout, so it can be redirected to a file or
+/// piped to a renderer:
+///
+///   todo export --format dot | dot -Tsvg > plan.svg
+///   todo export --format mermaid project > plan.mmd
+///   todo export --format todo.txt > todo.txt
+///   todo export --format ics > todo.ics
+///   todo export --format taskwarrior | task import
+///   todo export --format csv --include-done > todo.csv
+///
+/// In a diagram, each task is a node labelled with its number and
+/// description, and its implicit due date if it has one. An arrow from one
+/// task to another means the first task blocks the second. Incomplete,
+/// blocked, and complete tasks are drawn in different colors, and tags in a
+/// different shape.
+///
+/// In todo.txt format, tags are written as projects of the tasks that block
+/// them, and priorities 26 and above are written as (A), 25 as (B), and so
+/// on. Other blocks are not exported, since todo.txt has no way to express
+/// them.
+///
+/// In iCalendar format, each task is a VTODO with its implicit due date and
+/// priority, and its snooze date as DTSTART, unless it's snoozed past its
+/// due date. Blocks are RELATED-TO properties, so importing the file back
+/// restores them.
+///
+/// In Taskwarrior format, blocks are the 'depends' attribute, tags are
+/// written as Taskwarrior tags of the tasks that block them, and priorities
+/// 3 and above are H, 2 is M, and 1 is L.
+///
+/// In CSV and TSV format, there is a row for each task, with its position,
+/// short ID, description, status, creation and completion times, explicit
+/// and implicit priority and due date, budget in seconds, start date,
+/// whether it's a tag, its implicit tags, and the positions of its deps and
+/// adeps.
+#[derive(Debug, PartialEq, Eq, Parser)]
+#[command(
+    allow_negative_numbers(true),
+    verbatim_doc_comment,
+    arg(output_format_arg())
+)]
+pub struct Export {
+    /// The format: 'dot' for Graphviz, 'mermaid', 'todo.txt', 'ics' for
+    /// iCalendar, 'taskwarrior' for the JSON of 'task export', or 'csv' or
+    /// 'tsv' for spreadsheets.
+    #[arg(long)]
+    pub format: ExportFormat,
+    /// Only export these tasks and the tasks they are transitively blocking
+    /// or blocked on. If none are specified, exports the whole list.
+    pub keys: Vec<Key>,
+    /// Include complete tasks.
+    ///
+    /// Complete tasks are included even without this flag if any of the
+    /// given tasks are themselves complete, and always in the formats that
+    /// 'todo import' reads: todo.txt, ics, and taskwarrior.
+    #[arg(long, short = 'd')]
+    pub include_done: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Dot,
+    Mermaid,
+    #[value(name = "todo.txt")]
+    TodoTxt,
+    Ics,
+    Taskwarrior,
+    Csv,
+    Tsv,
+}
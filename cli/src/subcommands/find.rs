@@ -4,7 +4,8 @@ use clap::Parser;
 ///
 /// The tasks, as always, will be ordered by their canonical numbering.
 /// The results include incomplete tasks and blocked tasks, and can include
-/// complete tasks if you pass the --include-done flag.
+/// complete tasks if you pass the --include-done flag. With --notes, tasks
+/// whose notes contain a search term are included too.
 #[derive(Debug, Default, PartialEq, Eq, Parser)]
 #[command(verbatim_doc_comment)]
 pub struct Find {
@@ -14,4 +15,7 @@ pub struct Find {
     /// Show completed tasks in search results.
     #[arg(long, short = 'd')]
     pub include_done: bool,
+    /// Also search the notes of tasks.
+    #[arg(long, short = 'n')]
+    pub notes: bool,
 }
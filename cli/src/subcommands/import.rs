@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use clap::ValueEnum;
+
+use crate::options::output_format_arg;
+
+/// Import tasks from another tool's format.
+///
+/// Each task in the file is added to the list, unless a task with the same
+/// description already exists.
+///
+///   todo import --format todo.txt ~/todo.txt
+///   todo import --format ics tasks.ics
+///   todo import --format md plan.md
+///   todo import --format taskwarrior tasks.json
+///
+/// From todo.txt, priorities (A) to (Z) become priorities 26 to 1, due: sets
+/// the due date, t: snoozes the task until the given date, and tasks marked
+/// with x are imported as complete. Each +project becomes a tag that is
+/// blocked on the task.
+///
+/// From iCalendar, each VTODO becomes a task, with DUE as its due date and
+/// DTSTART as its snooze date. RELATED-TO properties become blocks, with
+/// parent tasks blocked on their subtasks. Other components, like events, are
+/// ignored.
+///
+/// From Markdown, each checklist item ('- [ ] item' or '- [x] item') becomes
+/// a task, and is blocked on the items indented under it, so an outline of a
+/// plan becomes a dependency graph. Checked items are imported as complete.
+///
+/// From Org-mode, each headline with a TODO or DONE keyword becomes a task,
+/// blocked on the headlines under it. DEADLINE sets the due date, and
+/// SCHEDULED snoozes the task until the given date.
+///
+/// From Taskwarrior, 'depends' becomes blocks, 'due' the due date, and
+/// 'wait' or 'scheduled' the snooze date. Priorities H, M, and L become 3, 2,
+/// and 1, and both tags and projects become tags. Deleted tasks are skipped.
+#[derive(Debug, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment, arg(output_format_arg()))]
+pub struct Import {
+    /// The format of the file: 'todo.txt', 'ics' for iCalendar, 'md' for
+    /// Markdown, 'org' for Org-mode, or 'taskwarrior' for the JSON of 'task
+    /// export'.
+    #[arg(long)]
+    pub format: ImportFormat,
+    /// The file to import.
+    pub file: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    #[value(name = "todo.txt")]
+    TodoTxt,
+    Ics,
+    Md,
+    Org,
+    Taskwarrior,
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+
+/// List the tasks that match a query.
+///
+/// A query combines filters with 'and', 'or', 'not', and parentheses, e.g.:
+///
+///   todo ls 'due<friday and priority>=2'
+///   todo ls 'tag:work and not blocked'
+///   todo ls 'budget>2h or created>last monday'
+///   todo ls 'desc~/^buy/'
+///
+/// A filter is a status ('blocked', 'complete' or 'done', 'incomplete',
+/// 'snoozed', or 'tag'), or a field, an operator, and a value. The fields are
+/// 'due', 'created', 'completed', and 'start' (dates, parsed like the dates
+/// given to 'todo due'), 'priority' (a number), 'budget' (a duration), 'desc',
+/// 'tag', and 'id' (text), and 'status'. The operators are <, <=, >, >=, =,
+/// and != for comparisons, ':' for text that contains the value (or equals it,
+/// for fields other than 'desc'), and '~' for a regular expression written
+/// between slashes. A date without a time of day covers the whole day.
+///
+/// Values may contain spaces, and extend up to the next 'and', 'or', or
+/// parenthesis. Put a value in double quotes to use those words in it.
+///
+/// The tasks are ordered by their canonical numbering. Complete tasks are
+/// only included if you pass the --include-done flag, or if the query
+/// mentions completion (e.g. 'done' or 'completed>monday'). Without a query,
+/// all incomplete and blocked tasks are listed.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Ls {
+    /// The query. Multiple words are joined with spaces, so the query does
+    /// not need to be quoted unless it contains characters special to your
+    /// shell, like '<' or '>'.
+    pub query: Vec<String>,
+    /// Include completed tasks in the results.
+    #[arg(long, short = 'd')]
+    pub include_done: bool,
+}
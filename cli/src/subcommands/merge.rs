@@ -6,7 +6,11 @@ use todo_lookup_key::Key;
 /// The merged task will retain the dependency structure of the tasks it was
 /// merged from. Its due date will be the earliest explicit due date of the
 /// constituents and its priority will be the lowest explicit priority of
-/// the constituents.
+/// the constituents. Time logged on the constituents is kept on the merged
+/// task, and a timer running on one of them is stopped. Their notes are
+/// joined, one after another, into the notes of the merged task. If any of
+/// them repeat, the merged task repeats the same way; tasks that repeat on
+/// different schedules can't be merged.
 ///
 /// This is the opposite of 'split'.
 #[derive(Debug, Default, PartialEq, Eq, Parser)]
@@ -1,82 +1,136 @@
+mod agenda;
+mod batch;
 mod block;
 mod bottom;
 mod budget;
 mod chain;
 mod check;
 mod clean;
+mod complete_keys;
+mod completions;
 mod config;
 mod due;
 mod edit;
+mod export;
 mod find;
 mod get;
+mod import;
+mod ls;
 mod merge;
 mod new;
+mod note;
 mod path;
 mod priority;
 mod punt;
 mod put;
+mod recover;
 mod restore;
 mod rm;
+mod schedule;
+mod shell;
 mod snooze;
 mod snoozed;
 mod split;
+mod start;
+mod stats;
 mod tag;
+mod time;
 mod top;
+mod tree;
+mod tui;
 mod unblock;
+mod undo;
 mod unsnooze;
 
+pub use self::agenda::Agenda;
+pub use self::batch::Batch;
 pub use self::block::Block;
 pub use self::bottom::Bottom;
 pub use self::budget::Budget;
 pub use self::chain::Chain;
 pub use self::check::Check;
 pub use self::clean::Clean;
+pub use self::complete_keys::CompleteKeys;
+pub use self::completions::CompletionShell;
+pub use self::completions::Completions;
 pub use self::config::Config;
 pub use self::due::Due;
 pub use self::edit::Edit;
+pub use self::export::Export;
+pub use self::export::ExportFormat;
 pub use self::find::Find;
 pub use self::get::Get;
+pub use self::import::Import;
+pub use self::import::ImportFormat;
+pub use self::ls::Ls;
 pub use self::merge::Merge;
 pub use self::new::New;
+pub use self::note::Note;
 pub use self::path::Path;
 pub use self::priority::Priority;
 pub use self::punt::Punt;
 pub use self::put::Put;
+pub use self::recover::Recover;
 pub use self::restore::Restore;
 pub use self::rm::Rm;
+pub use self::schedule::Schedule;
+pub use self::shell::Shell;
 pub use self::snooze::Snooze;
 pub use self::snoozed::Snoozed;
 pub use self::split::Split;
+pub use self::start::Start;
+pub use self::stats::Stats;
 pub use self::tag::Tag;
+pub use self::time::Time;
 pub use self::top::Top;
+pub use self::tree::Tree;
+pub use self::tui::Tui;
 pub use self::unblock::Unblock;
+pub use self::undo::Undo;
 pub use self::unsnooze::Unsnooze;
 
 #[cfg(test)]
 mod tests {
+    mod agenda_test;
+    mod batch_test;
     mod block_test;
     mod bottom_test;
     mod budget_test;
     mod chain_test;
     mod check_test;
+    mod complete_keys_test;
+    mod completions_test;
     mod config_test;
     mod due_test;
     mod edit_test;
+    mod export_test;
     mod find_test;
     mod get_test;
+    mod import_test;
+    mod ls_test;
     mod merge_test;
     mod new_test;
+    mod note_test;
     mod path_test;
     mod priority_test;
     mod punt_test;
     mod put_test;
+    mod recover_test;
     mod restore_test;
     mod rm_test;
+    mod schedule_test;
+    mod shell_test;
     mod snooze_test;
     mod snoozed_test;
     mod split_test;
+    mod start_test;
+    mod stats_test;
     mod tag_test;
+    mod time_test;
     mod top_test;
+    mod tree_test;
+    mod tui_test;
     mod unblock_test;
+    mod undo_test;
     mod unsnooze_test;
 }
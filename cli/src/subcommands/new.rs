@@ -126,6 +126,18 @@ pub struct New {
     #[arg(long, short = 's', num_args = 1..)]
     pub snooze: Vec<String>,
 
+    /// Make the new tasks recur.
+    ///
+    /// The recurrence rule is a human-readable string, e.g. "daily", "every
+    /// weekday", "every 2 weeks", "monthly", or "monthly on the 1st".
+    ///
+    /// When a recurring task is checked, its next instance is created with the
+    /// same deps, adeps, priority, budget, and tag status. The next instance is
+    /// snoozed until the start of its next occurrence, and its due date, if
+    /// any, is moved forward by the same amount.
+    #[arg(long, short = 'r', num_args = 1..)]
+    pub repeat: Vec<String>,
+
     /// Complete the new tasks.
     ///
     /// You can use this to mark a task as complete when you create it. This is
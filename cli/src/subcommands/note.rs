@@ -0,0 +1,33 @@
+use clap::Parser;
+use todo_lookup_key::Key;
+
+/// View or change the notes attached to tasks.
+///
+/// Notes hold anything about a task that doesn't belong in its description,
+/// like links, ticket numbers, or what was said in a meeting, and can span
+/// many lines. Tasks with notes are marked with a 📝 when listed, and 'todo
+/// get' shows their notes in full.
+///
+/// Without --append, a text editor is opened with each task's notes in turn,
+/// and whatever is saved becomes the task's notes. Saving an empty file
+/// removes them. For example:
+///
+///   # Write notes for the 'taxes' task in a text editor.
+///   todo note taxes
+///
+///   # Add a line to the end of the notes without opening an editor.
+///   todo note taxes --append "Receipts are in the blue folder"
+///
+///   # Search notes as well as descriptions.
+///   todo find --notes folder
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Note {
+    /// Tasks whose notes to change.
+    #[arg(required = true, num_args = 1..)]
+    pub keys: Vec<Key>,
+    /// A line to add to the end of the notes. If not set, a text editor is
+    /// used.
+    #[arg(long)]
+    pub append: Option<String>,
+}
@@ -0,0 +1,22 @@
+use clap::Parser;
+
+/// Lists backups of the list, or restores one of them.
+///
+/// Every time the list is saved, the previous version is kept as a backup,
+/// with the most recent backup numbered 1. Without arguments, 'todo recover'
+/// shows the available backups, when they were made, and how many tasks they
+/// contain. With a backup number, the list is replaced with that backup.
+///
+/// Recovering a backup is itself saved like any other change, so the list
+/// that was replaced becomes backup 1, and the recovery can be reverted with
+/// 'todo undo'.
+///
+/// The number of backups to keep is controlled by the 'backup_count' config
+/// key (see 'todo help config').
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Recover {
+    /// The number of the backup to restore, as shown by 'todo recover'.
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub backup: Option<u32>,
+}
@@ -20,8 +20,8 @@ use todo_lookup_key::Key;
 ///
 ///   a <- c
 ///
-/// Removal of tasks cannot be undone! You must manually re-create the task
-/// if you want to undo it.
+/// Removed tasks can be brought back with 'todo undo', as long as the removal
+/// is still in the undo journal (see 'todo help undo').
 #[derive(Debug, PartialEq, Eq, Parser)]
 #[command(allow_negative_numbers(true), verbatim_doc_comment)]
 pub struct Rm {
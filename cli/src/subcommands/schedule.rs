@@ -0,0 +1,30 @@
+use clap::Parser;
+use todo_lookup_key::Key;
+
+/// Shows whether due dates can still be met, given the budgets of tasks.
+///
+/// Each incomplete task is assumed to take the part of its budget that
+/// hasn't been spent yet (see 'todo budget' and 'todo start'), and to be
+/// started as soon as everything it's blocked on is done. From that, this
+/// shows when each task can be done at the earliest, and how much time there
+/// is to spare before it would make a due date be missed.
+///
+/// The critical path, i.e. the chain of tasks where any delay delays the
+/// tasks you asked about, is marked as critical. These are the tasks with the
+/// least slack towards the task they lead to. Without keys, all tasks that
+/// a due date depends on are shown, and the critical paths lead to the tasks
+/// with due dates. A warning is printed for each task that can no longer be
+/// done in time. For example:
+///
+///   todo new "write report" --budget 3 hours
+///   todo new "send report" --budget 30 min --due 5pm -p "write report"
+///
+///   # See if the report can be sent by 5pm.
+///   todo schedule "send report"
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Schedule {
+    /// Tasks to schedule, along with everything they're blocked on. If not
+    /// set, shows all tasks that a due date depends on.
+    pub keys: Vec<Key>,
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+
+/// Run commands one after another without reloading the list.
+///
+/// Each line is a command, written as it would be after 'todo', e.g.:
+///
+///   todo> new "walk the dog"
+///   todo> check 1
+///   todo> exit
+///
+/// The list is loaded once when the shell starts, which is much faster than
+/// running 'todo' over and over with a large list. It is saved after each
+/// command that changes it, or only when the shell exits if 'todo config
+/// shell_autosave false' is set.
+///
+/// The shell holds the lock on the list until it exits, so other 'todo'
+/// commands, including tab completion in your terminal, wait for it and give
+/// up after 'lock_timeout_secs' seconds.
+///
+/// With 'todo --dry-run shell', commands change the list as usual for the
+/// rest of the session, but nothing is saved. The lock is shared, so other
+/// commands that only read the list can still run.
+///
+/// Press Tab to complete subcommands and task descriptions, and Up and Down
+/// to go through previous commands. Type 'exit' or press Ctrl-D to exit.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Shell {}
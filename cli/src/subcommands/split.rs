@@ -39,6 +39,11 @@ use todo_lookup_key::Key;
 /// ... which results in:
 ///
 ///  a <- b1 <- b2 <- b3 <- c
+///
+/// Time logged on the split task, including a running timer, moves to the
+/// first of the new tasks, unless --keep is passed. Each of the new tasks
+/// gets a copy of the split task's notes, and repeats if the split task did,
+/// unless --keep is passed, in which case only the original keeps repeating.
 #[derive(Debug, PartialEq, Eq, Parser, Default)]
 #[command(allow_negative_numbers(true), verbatim_doc_comment)]
 pub struct Split {
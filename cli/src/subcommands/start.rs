@@ -0,0 +1,24 @@
+use clap::Parser;
+use todo_lookup_key::Key;
+
+/// Starts a timer on a task, to track how long it actually takes.
+///
+/// The time between 'todo start' and 'todo stop' is added to the task's work
+/// log. Only one timer runs at a time, so starting a timer on another task
+/// stops the running one first. Checking a task also stops its timer.
+///
+/// Use 'todo time' to see how much time was spent on tasks, and 'todo log'
+/// to compare the time a completed task took with its budget. For example:
+///
+///   # Give the task a budget, then start working on it.
+///   todo budget "file taxes" 2h
+///   todo start "file taxes"
+///
+///   # Take a break.
+///   todo stop
+#[derive(Debug, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Start {
+    /// The task to time. Must match exactly one task.
+    pub key: Key,
+}
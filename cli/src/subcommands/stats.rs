@@ -0,0 +1,27 @@
+use clap::Parser;
+
+/// Shows how many tasks get created and completed, and how quickly.
+///
+/// The report counts the tasks created and completed on each day, or each
+/// week if the period covers more than four weeks, with a sparkline of each
+/// count. Below that are the number of tasks that are incomplete, blocked,
+/// and complete now, the median time from creating a task to completing it,
+/// and the share of tasks with due dates that were completed on time. The
+/// same numbers are then broken down by tag.
+///
+/// By default, the report covers every task in the list. To only count the
+/// tasks created or completed in the last two weeks:
+///
+///   todo stats --since 2 weeks
+///
+/// With '--format json' or '--format jsonl', the report is printed as a JSON
+/// object instead, for use in scripts.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Stats {
+    /// The start of the period to report on, either a date like "monday" or
+    /// "2024 jan 1", or a duration like "2 weeks", which counts back from
+    /// now.
+    #[arg(long, num_args = 1..)]
+    pub since: Vec<String>,
+}
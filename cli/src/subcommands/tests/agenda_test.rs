@@ -0,0 +1,22 @@
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Agenda;
+use crate::SubCommand;
+
+#[test]
+fn agenda_default() {
+    expect_parses_into("todo agenda", SubCommand::Agenda(Agenda { days: 7 }));
+}
+
+#[test]
+fn agenda_days() {
+    expect_parses_into(
+        "todo agenda --days 14",
+        SubCommand::Agenda(Agenda { days: 14 }),
+    );
+}
+
+#[test]
+fn agenda_zero_days() {
+    expect_error("todo agenda --days 0");
+}
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Batch;
+use crate::SubCommand;
+
+#[test]
+fn batch_reads_stdin_by_default() {
+    expect_parses_into(
+        "todo batch",
+        SubCommand::Batch(Batch {
+            file: PathBuf::from("-"),
+            keep_going: false,
+        }),
+    );
+}
+
+#[test]
+fn batch_file() {
+    expect_parses_into(
+        "todo batch plan.todo",
+        SubCommand::Batch(Batch {
+            file: PathBuf::from("plan.todo"),
+            keep_going: false,
+        }),
+    );
+}
+
+#[test]
+fn batch_keep_going() {
+    expect_parses_into(
+        "todo batch --keep-going plan.todo",
+        SubCommand::Batch(Batch {
+            file: PathBuf::from("plan.todo"),
+            keep_going: true,
+        }),
+    );
+}
+
+#[test]
+fn batch_two_files() {
+    expect_error("todo batch a.todo b.todo");
+}
@@ -0,0 +1,22 @@
+use crate::testing::expect_parses_into;
+use crate::CompleteKeys;
+use crate::SubCommand;
+
+#[test]
+fn complete_keys_no_subcommand() {
+    expect_parses_into(
+        "todo __complete-keys",
+        SubCommand::CompleteKeys(CompleteKeys::default()),
+    );
+}
+
+#[test]
+fn complete_keys_for_subcommand() {
+    expect_parses_into(
+        "todo __complete-keys restore --raw",
+        SubCommand::CompleteKeys(CompleteKeys {
+            subcommand: Some("restore".to_string()),
+            raw: true,
+        }),
+    );
+}
@@ -0,0 +1,35 @@
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::CompletionShell;
+use crate::Completions;
+use crate::SubCommand;
+
+#[test]
+fn completions_bash() {
+    expect_parses_into(
+        "todo completions bash",
+        SubCommand::Completions(Completions {
+            shell: CompletionShell::Bash,
+        }),
+    );
+}
+
+#[test]
+fn completions_fish() {
+    expect_parses_into(
+        "todo completions fish",
+        SubCommand::Completions(Completions {
+            shell: CompletionShell::Fish,
+        }),
+    );
+}
+
+#[test]
+fn completions_needs_shell() {
+    expect_error("todo completions");
+}
+
+#[test]
+fn completions_unknown_shell() {
+    expect_error("todo completions tcsh");
+}
@@ -1,3 +1,4 @@
+use crate::testing::expect_error;
 use crate::testing::expect_parses_into;
 use crate::Config;
 use crate::SubCommand;
@@ -49,3 +50,13 @@ fn reset_value_for_key() {
         }),
     );
 }
+
+#[test]
+fn reset_requires_key() {
+    expect_error("todo config --reset");
+}
+
+#[test]
+fn reset_conflicts_with_value() {
+    expect_error("todo config pager more --reset");
+}
@@ -0,0 +1,115 @@
+use todo_lookup_key::Key;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Export;
+use crate::ExportFormat;
+use crate::SubCommand;
+
+#[test]
+fn export_missing_format() {
+    expect_error("todo export");
+}
+
+#[test]
+fn export_dot() {
+    expect_parses_into(
+        "todo export --format dot",
+        SubCommand::Export(Export {
+            format: ExportFormat::Dot,
+            keys: Vec::new(),
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_mermaid_with_keys() {
+    expect_parses_into(
+        "todo export --format mermaid 1 a",
+        SubCommand::Export(Export {
+            format: ExportFormat::Mermaid,
+            keys: vec![Key::ByNumber(1), Key::ByName("a".to_string())],
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_include_done() {
+    expect_parses_into(
+        "todo export --format dot -d",
+        SubCommand::Export(Export {
+            format: ExportFormat::Dot,
+            keys: Vec::new(),
+            include_done: true,
+        }),
+    );
+}
+
+#[test]
+fn export_todo_txt() {
+    expect_parses_into(
+        "todo export --format todo.txt",
+        SubCommand::Export(Export {
+            format: ExportFormat::TodoTxt,
+            keys: Vec::new(),
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_ics() {
+    expect_parses_into(
+        "todo export --format ics",
+        SubCommand::Export(Export {
+            format: ExportFormat::Ics,
+            keys: Vec::new(),
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_taskwarrior() {
+    expect_parses_into(
+        "todo export --format taskwarrior",
+        SubCommand::Export(Export {
+            format: ExportFormat::Taskwarrior,
+            keys: Vec::new(),
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_csv_include_done() {
+    expect_parses_into(
+        "todo export --format csv --include-done",
+        SubCommand::Export(Export {
+            format: ExportFormat::Csv,
+            keys: Vec::new(),
+            include_done: true,
+        }),
+    );
+}
+
+#[test]
+fn export_tsv() {
+    expect_parses_into(
+        "todo export --format tsv",
+        SubCommand::Export(Export {
+            format: ExportFormat::Tsv,
+            keys: Vec::new(),
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn export_unsupported_format() {
+    expect_error("todo export --format json");
+    expect_error("todo export --format xml");
+    expect_error("todo export --format md");
+}
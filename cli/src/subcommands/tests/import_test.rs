@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Import;
+use crate::ImportFormat;
+use crate::SubCommand;
+
+#[test]
+fn import_todo_txt() {
+    expect_parses_into(
+        "todo import --format todo.txt todo.txt",
+        SubCommand::Import(Import {
+            format: ImportFormat::TodoTxt,
+            file: PathBuf::from("todo.txt"),
+        }),
+    );
+}
+
+#[test]
+fn import_ics() {
+    expect_parses_into(
+        "todo import --format ics tasks.ics",
+        SubCommand::Import(Import {
+            format: ImportFormat::Ics,
+            file: PathBuf::from("tasks.ics"),
+        }),
+    );
+}
+
+#[test]
+fn import_md() {
+    expect_parses_into(
+        "todo import --format md plan.md",
+        SubCommand::Import(Import {
+            format: ImportFormat::Md,
+            file: PathBuf::from("plan.md"),
+        }),
+    );
+}
+
+#[test]
+fn import_org() {
+    expect_parses_into(
+        "todo import --format org plan.org",
+        SubCommand::Import(Import {
+            format: ImportFormat::Org,
+            file: PathBuf::from("plan.org"),
+        }),
+    );
+}
+
+#[test]
+fn import_taskwarrior() {
+    expect_parses_into(
+        "todo import --format taskwarrior tasks.json",
+        SubCommand::Import(Import {
+            format: ImportFormat::Taskwarrior,
+            file: PathBuf::from("tasks.json"),
+        }),
+    );
+}
+
+#[test]
+fn import_missing_file() {
+    expect_error("todo import --format todo.txt");
+}
+
+#[test]
+fn import_missing_format() {
+    expect_error("todo import todo.txt");
+}
+
+#[test]
+fn import_unsupported_format() {
+    expect_error("todo import --format dot todo.txt");
+    expect_error("todo import --format json todo.txt");
+    expect_error("todo import --format csv todo.csv");
+}
@@ -0,0 +1,57 @@
+use crate::testing::expect_parses_into;
+use crate::Ls;
+use crate::SubCommand;
+
+#[test]
+fn ls_no_query() {
+    expect_parses_into("todo ls", SubCommand::Ls(Ls::default()));
+}
+
+#[test]
+fn ls_single_word_query() {
+    expect_parses_into(
+        "todo ls priority>=2",
+        SubCommand::Ls(Ls {
+            query: vec!["priority>=2".to_string()],
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn ls_query_in_multiple_words() {
+    expect_parses_into(
+        "todo ls tag:work and not blocked",
+        SubCommand::Ls(Ls {
+            query: vec![
+                "tag:work".to_string(),
+                "and".to_string(),
+                "not".to_string(),
+                "blocked".to_string(),
+            ],
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn ls_include_done_long() {
+    expect_parses_into(
+        "todo ls desc:a --include-done",
+        SubCommand::Ls(Ls {
+            query: vec!["desc:a".to_string()],
+            include_done: true,
+        }),
+    );
+}
+
+#[test]
+fn ls_include_done_short() {
+    expect_parses_into(
+        "todo ls -d",
+        SubCommand::Ls(Ls {
+            query: vec![],
+            include_done: true,
+        }),
+    );
+}
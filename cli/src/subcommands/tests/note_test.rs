@@ -0,0 +1,49 @@
+use todo_lookup_key::Key::*;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Note;
+use crate::SubCommand;
+
+#[test]
+fn note_missing_keys() {
+    expect_error("todo note");
+}
+
+#[test]
+fn note_one_task() {
+    expect_parses_into(
+        "todo note 1",
+        SubCommand::Note(Note {
+            keys: vec![ByNumber(1)],
+            append: None,
+        }),
+    );
+}
+
+#[test]
+fn note_multiple_tasks() {
+    expect_parses_into(
+        "todo note a -2",
+        SubCommand::Note(Note {
+            keys: vec![ByName("a".to_string()), ByNumber(-2)],
+            append: None,
+        }),
+    );
+}
+
+#[test]
+fn note_append() {
+    expect_parses_into(
+        "todo note 1 --append hello",
+        SubCommand::Note(Note {
+            keys: vec![ByNumber(1)],
+            append: Some("hello".to_string()),
+        }),
+    );
+}
+
+#[test]
+fn note_append_missing_text() {
+    expect_error("todo note 1 --append");
+}
@@ -0,0 +1,30 @@
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Recover;
+use crate::SubCommand;
+
+#[test]
+fn recover_list() {
+    expect_parses_into(
+        "todo recover",
+        SubCommand::Recover(Recover { backup: None }),
+    );
+}
+
+#[test]
+fn recover_backup() {
+    expect_parses_into(
+        "todo recover 2",
+        SubCommand::Recover(Recover { backup: Some(2) }),
+    );
+}
+
+#[test]
+fn recover_backup_zero() {
+    expect_error("todo recover 0");
+}
+
+#[test]
+fn recover_not_a_number() {
+    expect_error("todo recover latest");
+}
@@ -0,0 +1,23 @@
+use todo_lookup_key::Key::*;
+
+use crate::testing::expect_parses_into;
+use crate::Schedule;
+use crate::SubCommand;
+
+#[test]
+fn schedule_no_keys() {
+    expect_parses_into(
+        "todo schedule",
+        SubCommand::Schedule(Schedule::default()),
+    );
+}
+
+#[test]
+fn schedule_with_keys() {
+    expect_parses_into(
+        "todo schedule 1 report",
+        SubCommand::Schedule(Schedule {
+            keys: vec![ByNumber(1), ByName("report".to_string())],
+        }),
+    );
+}
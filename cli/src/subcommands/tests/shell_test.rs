@@ -0,0 +1,14 @@
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Shell;
+use crate::SubCommand;
+
+#[test]
+fn shell_no_args() {
+    expect_parses_into("todo shell", SubCommand::Shell(Shell {}));
+}
+
+#[test]
+fn shell_extraneous() {
+    expect_error("todo shell check 1");
+}
@@ -0,0 +1,44 @@
+use todo_lookup_key::Key::*;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::Start;
+use crate::SubCommand;
+
+#[test]
+fn start_missing_key() {
+    expect_error("todo start");
+}
+
+#[test]
+fn start_by_number() {
+    expect_parses_into(
+        "todo start 1",
+        SubCommand::Start(Start { key: ByNumber(1) }),
+    );
+}
+
+#[test]
+fn start_by_name() {
+    expect_parses_into(
+        "todo start taxes",
+        SubCommand::Start(Start {
+            key: ByName("taxes".to_string()),
+        }),
+    );
+}
+
+#[test]
+fn start_multiple_keys() {
+    expect_error("todo start 1 2");
+}
+
+#[test]
+fn stop_no_args() {
+    expect_parses_into("todo stop", SubCommand::Stop);
+}
+
+#[test]
+fn stop_extraneous() {
+    expect_error("todo stop 1");
+}
@@ -0,0 +1,18 @@
+use crate::testing::expect_parses_into;
+use crate::Stats;
+use crate::SubCommand;
+
+#[test]
+fn stats_default() {
+    expect_parses_into("todo stats", SubCommand::Stats(Stats::default()));
+}
+
+#[test]
+fn stats_since() {
+    expect_parses_into(
+        "todo stats --since 2 weeks",
+        SubCommand::Stats(Stats {
+            since: vec!["2".to_string(), "weeks".to_string()],
+        }),
+    );
+}
@@ -0,0 +1,32 @@
+use todo_lookup_key::Key::*;
+
+use crate::testing::expect_parses_into;
+use crate::SubCommand;
+use crate::Time;
+
+#[test]
+fn time_no_keys() {
+    expect_parses_into("todo time", SubCommand::Time(Time::default()));
+}
+
+#[test]
+fn time_with_keys() {
+    expect_parses_into(
+        "todo time 1 -2",
+        SubCommand::Time(Time {
+            keys: vec![ByNumber(1), ByNumber(-2)],
+            include_done: false,
+        }),
+    );
+}
+
+#[test]
+fn time_include_done() {
+    expect_parses_into(
+        "todo time -d",
+        SubCommand::Time(Time {
+            keys: vec![],
+            include_done: true,
+        }),
+    );
+}
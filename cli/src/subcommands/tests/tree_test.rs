@@ -0,0 +1,68 @@
+use todo_lookup_key::Key;
+
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::SubCommand;
+use crate::Tree;
+
+#[test]
+fn tree_no_keys() {
+    expect_parses_into("todo tree", SubCommand::Tree(Tree::default()));
+}
+
+#[test]
+fn tree_with_keys() {
+    expect_parses_into(
+        "todo tree 1 a",
+        SubCommand::Tree(Tree {
+            keys: vec![Key::ByNumber(1), Key::ByName("a".to_string())],
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn tree_up() {
+    expect_parses_into(
+        "todo tree 1 --up",
+        SubCommand::Tree(Tree {
+            keys: vec![Key::ByNumber(1)],
+            up: true,
+            ..Default::default()
+        }),
+    );
+    expect_parses_into(
+        "todo tree -u",
+        SubCommand::Tree(Tree {
+            up: true,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn tree_depth() {
+    expect_parses_into(
+        "todo tree --depth 2",
+        SubCommand::Tree(Tree {
+            depth: Some(2),
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn tree_negative_depth() {
+    expect_error("todo tree --depth -1");
+}
+
+#[test]
+fn tree_include_done() {
+    expect_parses_into(
+        "todo tree -d",
+        SubCommand::Tree(Tree {
+            include_done: true,
+            ..Default::default()
+        }),
+    );
+}
@@ -0,0 +1,25 @@
+use crate::testing::expect_error;
+use crate::testing::expect_parses_into;
+use crate::SubCommand;
+use crate::Tui;
+
+#[test]
+fn tui_extraneous() {
+    expect_error("todo tui foo");
+}
+
+#[test]
+fn tui_no_args() {
+    expect_parses_into("todo tui", SubCommand::Tui(Tui::default()));
+}
+
+#[test]
+fn tui_include_blocked_and_done() {
+    expect_parses_into(
+        "todo tui -bd",
+        SubCommand::Tui(Tui {
+            include_blocked: true,
+            include_done: true,
+        }),
+    );
+}
@@ -0,0 +1,26 @@
+use crate::testing::expect_parses_into;
+use crate::SubCommand;
+use crate::Undo;
+
+#[test]
+fn undo() {
+    expect_parses_into("todo undo", SubCommand::Undo(Undo { list: false }));
+}
+
+#[test]
+fn undo_list() {
+    expect_parses_into(
+        "todo undo --list",
+        SubCommand::Undo(Undo { list: true }),
+    );
+}
+
+#[test]
+fn undo_list_short() {
+    expect_parses_into("todo undo -l", SubCommand::Undo(Undo { list: true }));
+}
+
+#[test]
+fn redo() {
+    expect_parses_into("todo redo", SubCommand::Redo);
+}
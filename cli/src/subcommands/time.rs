@@ -0,0 +1,22 @@
+use clap::Parser;
+use todo_lookup_key::Key;
+
+/// Shows how much time was spent on tasks.
+///
+/// Time is recorded with 'todo start' and 'todo stop'. Each task shows the
+/// time spent on the task itself, and, if it's different, the time spent on
+/// it and all the tasks it's transitively blocked on, so that the time spent
+/// on a project's subtasks adds up under the project.
+///
+/// Without keys, every task with recorded time is shown, which includes
+/// complete tasks only if you pass the --include-done flag.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Time {
+    /// Tasks to show the time spent on. If not set, shows all tasks with
+    /// recorded time.
+    pub keys: Vec<Key>,
+    /// Include complete tasks.
+    #[arg(long, short = 'd')]
+    pub include_done: bool,
+}
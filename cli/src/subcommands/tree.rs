@@ -0,0 +1,35 @@
+use clap::Parser;
+use todo_lookup_key::Key;
+
+/// Shows tasks and their dependencies as a tree.
+///
+/// Each task is followed by the tasks it is blocked on, indented underneath
+/// it, so you can see the shape of a project at a glance:
+///
+///   todo new project
+///   todo new design build -b project --chain
+///   todo tree project
+///
+/// A task that is blocked on by more than one task in the tree is shown in
+/// full the first time it appears, and marked "(see above)" after that.
+///
+/// With --up, the tree goes the other way: each task is followed by the tasks
+/// it is blocking.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(allow_negative_numbers(true), verbatim_doc_comment)]
+pub struct Tree {
+    /// Tasks at the roots of the tree. If none are specified, the roots are
+    /// the tasks that aren't blocking anything (or, with --up, the tasks that
+    /// aren't blocked on anything).
+    pub keys: Vec<Key>,
+    /// Show the tasks each task is blocking, rather than the tasks it is
+    /// blocked on.
+    #[arg(long, short = 'u')]
+    pub up: bool,
+    /// How many levels to show below the roots. 0 shows only the roots.
+    #[arg(long, value_name = "levels")]
+    pub depth: Option<usize>,
+    /// Show complete tasks too.
+    #[arg(long, short = 'd')]
+    pub include_done: bool,
+}
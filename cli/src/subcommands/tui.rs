@@ -0,0 +1,41 @@
+use clap::Parser;
+
+/// Browse and change the list in a full-screen terminal UI.
+///
+/// The list of incomplete tasks fills the left of the screen, and the tasks
+/// related to the selected task, as 'todo get' shows them, fill the right.
+/// The output of each action is shown below the list, just as the command
+/// line would print it.
+///
+/// Keys:
+///
+///   j, Down     Select the next task.
+///   k, Up       Select the previous task.
+///   g, Home     Select the first task.
+///   G, End      Select the last task.
+///   c           Check the selected task, like 'todo check'.
+///   p           Punt the selected task, like 'todo punt'.
+///   s           Snooze the selected task until a time you type.
+///   P           Set the priority of the selected task.
+///   e           Edit the description of the selected task.
+///   m           Mark the selected task to be blocked.
+///   b           Block the marked task on the selected task.
+///   B           Show or hide blocked tasks.
+///   D           Show or hide complete tasks.
+///   q, Esc      Quit.
+///
+/// While typing, Enter finishes and Esc cancels.
+///
+/// Changes are saved when you quit, and can be undone all at once with
+/// 'todo undo'. Other 'todo' commands wait for the TUI to quit before
+/// changing the list.
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Tui {
+    /// Show blocked tasks at first.
+    #[arg(long, short = 'b')]
+    pub include_blocked: bool,
+    /// Show complete tasks at first.
+    #[arg(long, short = 'd')]
+    pub include_done: bool,
+}
@@ -0,0 +1,20 @@
+use clap::Parser;
+
+/// Reverts the most recent command that modified the list.
+///
+/// Every command that changes the list (e.g. 'new', 'check', 'rm', 'merge')
+/// is recorded in a journal next to the list, along with a snapshot of the
+/// list from before the command ran. 'todo undo' restores that snapshot and
+/// shows the tasks that were affected. An undone command can be reapplied
+/// with 'todo redo', until another command modifies the list.
+///
+/// The number of commands that can be undone is controlled by the
+/// 'undo_limit' config key (see 'todo help config').
+#[derive(Debug, Default, PartialEq, Eq, Parser)]
+#[command(verbatim_doc_comment)]
+pub struct Undo {
+    /// Show the commands that can be undone, most recent first, instead of
+    /// undoing anything.
+    #[arg(long, short = 'l')]
+    pub list: bool,
+}
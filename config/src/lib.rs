@@ -1,18 +1,45 @@
 use std::io::Read;
+use std::io::Write;
+use std::time::Duration;
 
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 use thiserror::Error;
 
-#[derive(Default, Debug, PartialEq, Deserialize, Serialize)]
-struct ConfigModel {
+/// The contents of the config file. Every field is optional; missing fields
+/// take on their default values when converted into a `Config`.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ConfigModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     paginator_cmd: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     text_editor_cmd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    undo_limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backup_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_timeout_secs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_ids: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shell_autosave: Option<bool>,
 }
 
 pub struct Config {
     pub paginator_cmd: Vec<String>,
     pub text_editor_cmd: String,
+    /// The maximum number of commands that can be undone.
+    pub undo_limit: usize,
+    /// The number of previous versions of the list to keep as backups.
+    pub backup_count: usize,
+    /// How long to wait for another invocation to release the list.
+    pub lock_timeout: Duration,
+    /// Whether to show the short ID of each task on the terminal.
+    pub show_ids: bool,
+    /// Whether 'todo shell' saves the list after every command that changes
+    /// it, rather than only when it exits.
+    pub shell_autosave: bool,
 }
 
 fn default_paginator_cmd() -> Vec<String> {
@@ -23,15 +50,41 @@ fn default_text_editor_cmd() -> String {
     "vim".to_string()
 }
 
+fn default_undo_limit() -> usize {
+    20
+}
+
+fn default_backup_count() -> usize {
+    5
+}
+
+fn default_lock_timeout_secs() -> usize {
+    10
+}
+
 impl Config {
-    fn new(model: ConfigModel) -> Self {
+    pub fn new(model: &ConfigModel) -> Self {
         Self {
             paginator_cmd: model
                 .paginator_cmd
+                .clone()
                 .unwrap_or_else(default_paginator_cmd),
             text_editor_cmd: model
                 .text_editor_cmd
+                .clone()
                 .unwrap_or_else(default_text_editor_cmd),
+            undo_limit: model.undo_limit.unwrap_or_else(default_undo_limit),
+            backup_count: model
+                .backup_count
+                .unwrap_or_else(default_backup_count),
+            lock_timeout: Duration::from_secs(
+                model
+                    .lock_timeout_secs
+                    .unwrap_or_else(default_lock_timeout_secs)
+                    as u64,
+            ),
+            show_ids: model.show_ids.unwrap_or_default(),
+            shell_autosave: model.shell_autosave.unwrap_or(true),
         }
     }
 }
@@ -41,8 +94,153 @@ impl Default for Config {
         Self {
             paginator_cmd: default_paginator_cmd(),
             text_editor_cmd: default_text_editor_cmd(),
+            undo_limit: default_undo_limit(),
+            backup_count: default_backup_count(),
+            lock_timeout: Duration::from_secs(
+                default_lock_timeout_secs() as u64
+            ),
+            show_ids: false,
+            shell_autosave: true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ConfigError {
+    #[error("unknown config key")]
+    UnknownKey(String),
+    #[error("cannot parse config value")]
+    CannotParseValue {
+        key: String,
+        value: String,
+        explanation: String,
+    },
+}
+
+fn format_cmd(cmd: &[String]) -> String {
+    shlex::try_join(cmd.iter().map(|s| s.as_str()))
+        .unwrap_or_else(|_| cmd.join(" "))
+}
+
+// A command may be given either as a single quoted string, which is split
+// like a shell would split it, or as a sequence of arguments.
+fn parse_cmd(key: &str, value: &[String]) -> Result<Vec<String>, ConfigError> {
+    let cmd = match value {
+        [single] => shlex::split(single).ok_or_else(|| {
+            ConfigError::CannotParseValue {
+                key: key.to_string(),
+                value: single.clone(),
+                explanation: "unbalanced quotes".to_string(),
+            }
+        })?,
+        _ => value.to_vec(),
+    };
+    if cmd.is_empty() {
+        return Err(ConfigError::CannotParseValue {
+            key: key.to_string(),
+            value: value.join(" "),
+            explanation: "command cannot be empty".to_string(),
+        });
+    }
+    Ok(cmd)
+}
+
+fn parse_count(key: &str, value: &[String]) -> Result<usize, ConfigError> {
+    value.join(" ").parse::<usize>().map_err(|_| {
+        ConfigError::CannotParseValue {
+            key: key.to_string(),
+            value: value.join(" "),
+            explanation: "expected a non-negative integer".to_string(),
+        }
+    })
+}
+
+fn parse_bool(key: &str, value: &[String]) -> Result<bool, ConfigError> {
+    match value.join(" ").as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(ConfigError::CannotParseValue {
+            key: key.to_string(),
+            value: value.join(" "),
+            explanation: "expected 'true' or 'false'".to_string(),
+        }),
+    }
+}
+
+impl ConfigModel {
+    /// All keys that can be passed to `get()`, `set()`, and `reset()`.
+    pub const KEYS: &'static [&'static str] = &[
+        "paginator_cmd",
+        "text_editor_cmd",
+        "undo_limit",
+        "backup_count",
+        "lock_timeout_secs",
+        "show_ids",
+        "shell_autosave",
+    ];
+
+    /// Returns a human-readable representation of the effective value for the
+    /// given key, which is the default value if the key is not set.
+    pub fn get(&self, key: &str) -> Result<String, ConfigError> {
+        let config = Config::new(self);
+        match key {
+            "paginator_cmd" => Ok(format_cmd(&config.paginator_cmd)),
+            "text_editor_cmd" => Ok(config.text_editor_cmd),
+            "undo_limit" => Ok(config.undo_limit.to_string()),
+            "backup_count" => Ok(config.backup_count.to_string()),
+            "lock_timeout_secs" => {
+                Ok(config.lock_timeout.as_secs().to_string())
+            }
+            "show_ids" => Ok(config.show_ids.to_string()),
+            "shell_autosave" => Ok(config.shell_autosave.to_string()),
+            _ => Err(ConfigError::UnknownKey(key.to_string())),
         }
     }
+
+    /// Parses the given value and assigns it to the given key.
+    pub fn set(
+        &mut self,
+        key: &str,
+        value: &[String],
+    ) -> Result<(), ConfigError> {
+        match key {
+            "paginator_cmd" => {
+                self.paginator_cmd = Some(parse_cmd(key, value)?);
+            }
+            "text_editor_cmd" => {
+                self.text_editor_cmd =
+                    Some(parse_cmd(key, value).map(|_| value.join(" "))?);
+            }
+            "undo_limit" => self.undo_limit = Some(parse_count(key, value)?),
+            "backup_count" => {
+                self.backup_count = Some(parse_count(key, value)?)
+            }
+            "lock_timeout_secs" => {
+                self.lock_timeout_secs = Some(parse_count(key, value)?)
+            }
+            "show_ids" => self.show_ids = Some(parse_bool(key, value)?),
+            "shell_autosave" => {
+                self.shell_autosave = Some(parse_bool(key, value)?)
+            }
+            _ => return Err(ConfigError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Clears the value for the given key so that the default is used.
+    pub fn reset(&mut self, key: &str) -> Result<(), ConfigError> {
+        match key {
+            "paginator_cmd" => self.paginator_cmd = None,
+            "text_editor_cmd" => self.text_editor_cmd = None,
+            "undo_limit" => self.undo_limit = None,
+            "backup_count" => self.backup_count = None,
+            "lock_timeout_secs" => self.lock_timeout_secs = None,
+            "show_ids" => self.show_ids = None,
+            "shell_autosave" => self.shell_autosave = None,
+            _ => return Err(ConfigError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Error)]
@@ -51,9 +249,29 @@ pub enum LoadError {
     DeserializeError(#[from] serde_json::Error),
 }
 
-pub fn load<R>(reader: R) -> Result<Config, LoadError>
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("IO error")]
+    IoError(#[from] std::io::Error),
+    #[error("Serialize error")]
+    SerializeError(#[from] serde_json::Error),
+}
+
+pub fn load<R>(reader: R) -> Result<ConfigModel, LoadError>
 where
     R: Read,
 {
-    Ok(Config::new(serde_json::from_reader(reader)?))
+    Ok(serde_json::from_reader(reader)?)
 }
+
+pub fn save<W>(mut writer: W, model: &ConfigModel) -> Result<(), SaveError>
+where
+    W: Write,
+{
+    serde_json::to_writer_pretty(&mut writer, model)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
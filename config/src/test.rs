@@ -0,0 +1,159 @@
+use crate::*;
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn get_default_values() {
+    let model = ConfigModel::default();
+    assert_eq!(model.get("paginator_cmd"), Ok("less -rFX".to_string()));
+    assert_eq!(model.get("text_editor_cmd"), Ok("vim".to_string()));
+    assert_eq!(model.get("undo_limit"), Ok("20".to_string()));
+    assert_eq!(model.get("backup_count"), Ok("5".to_string()));
+    assert_eq!(model.get("lock_timeout_secs"), Ok("10".to_string()));
+    assert_eq!(model.get("show_ids"), Ok("false".to_string()));
+    assert_eq!(model.get("shell_autosave"), Ok("true".to_string()));
+}
+
+#[test]
+fn get_unknown_key() {
+    let model = ConfigModel::default();
+    assert_eq!(
+        model.get("pager"),
+        Err(ConfigError::UnknownKey("pager".to_string()))
+    );
+}
+
+#[test]
+fn set_paginator_cmd_from_single_string() {
+    let mut model = ConfigModel::default();
+    model.set("paginator_cmd", &strings(&["more -d"])).unwrap();
+    assert_eq!(Config::new(&model).paginator_cmd, strings(&["more", "-d"]));
+}
+
+#[test]
+fn set_paginator_cmd_from_multiple_args() {
+    let mut model = ConfigModel::default();
+    model
+        .set("paginator_cmd", &strings(&["more", "-d"]))
+        .unwrap();
+    assert_eq!(model.get("paginator_cmd"), Ok("more -d".to_string()));
+}
+
+#[test]
+fn set_empty_command() {
+    let mut model = ConfigModel::default();
+    assert_eq!(
+        model.set("text_editor_cmd", &[]),
+        Err(ConfigError::CannotParseValue {
+            key: "text_editor_cmd".to_string(),
+            value: "".to_string(),
+            explanation: "command cannot be empty".to_string(),
+        })
+    );
+    assert_eq!(model, ConfigModel::default());
+}
+
+#[test]
+fn set_unknown_key() {
+    let mut model = ConfigModel::default();
+    assert_eq!(
+        model.set("pager", &strings(&["more"])),
+        Err(ConfigError::UnknownKey("pager".to_string()))
+    );
+}
+
+#[test]
+fn reset_restores_default() {
+    let mut model = ConfigModel::default();
+    model.set("text_editor_cmd", &strings(&["nano"])).unwrap();
+    assert_eq!(model.get("text_editor_cmd"), Ok("nano".to_string()));
+    model.reset("text_editor_cmd").unwrap();
+    assert_eq!(model, ConfigModel::default());
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let mut model = ConfigModel::default();
+    model.set("paginator_cmd", &strings(&["more"])).unwrap();
+    let mut out = Vec::new();
+    save(&mut out, &model).unwrap();
+    assert_eq!(load(out.as_slice()).unwrap(), model);
+}
+
+#[test]
+fn save_omits_unset_keys() {
+    let mut out = Vec::new();
+    save(&mut out, &ConfigModel::default()).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "{}\n");
+}
+
+#[test]
+fn set_undo_limit() {
+    let mut model = ConfigModel::default();
+    model.set("undo_limit", &strings(&["5"])).unwrap();
+    assert_eq!(Config::new(&model).undo_limit, 5);
+}
+
+#[test]
+fn set_undo_limit_to_non_number() {
+    let mut model = ConfigModel::default();
+    assert_eq!(
+        model.set("undo_limit", &strings(&["lots"])),
+        Err(ConfigError::CannotParseValue {
+            key: "undo_limit".to_string(),
+            value: "lots".to_string(),
+            explanation: "expected a non-negative integer".to_string(),
+        })
+    );
+}
+
+#[test]
+fn set_and_reset_backup_count() {
+    let mut model = ConfigModel::default();
+    model.set("backup_count", &strings(&["0"])).unwrap();
+    assert_eq!(Config::new(&model).backup_count, 0);
+    model.reset("backup_count").unwrap();
+    assert_eq!(Config::new(&model).backup_count, 5);
+}
+
+#[test]
+fn set_and_reset_lock_timeout() {
+    let mut model = ConfigModel::default();
+    model.set("lock_timeout_secs", &strings(&["30"])).unwrap();
+    assert_eq!(Config::new(&model).lock_timeout, Duration::from_secs(30));
+    model.reset("lock_timeout_secs").unwrap();
+    assert_eq!(Config::new(&model).lock_timeout, Duration::from_secs(10));
+}
+
+#[test]
+fn set_and_reset_show_ids() {
+    let mut model = ConfigModel::default();
+    model.set("show_ids", &strings(&["true"])).unwrap();
+    assert!(Config::new(&model).show_ids);
+    model.reset("show_ids").unwrap();
+    assert!(!Config::new(&model).show_ids);
+}
+
+#[test]
+fn set_and_reset_shell_autosave() {
+    let mut model = ConfigModel::default();
+    model.set("shell_autosave", &strings(&["false"])).unwrap();
+    assert!(!Config::new(&model).shell_autosave);
+    model.reset("shell_autosave").unwrap();
+    assert!(Config::new(&model).shell_autosave);
+}
+
+#[test]
+fn set_show_ids_invalid() {
+    let mut model = ConfigModel::default();
+    assert_eq!(
+        model.set("show_ids", &strings(&["yes"])),
+        Err(ConfigError::CannotParseValue {
+            key: "show_ids".to_string(),
+            value: "yes".to_string(),
+            explanation: "expected 'true' or 'false'".to_string(),
+        })
+    );
+}
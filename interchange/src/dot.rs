@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+use crate::edges;
+use crate::node_id;
+use crate::node_label;
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// Colors match the colors of task numbers on the terminal.
+fn attributes(list: &TodoList, id: TaskId) -> String {
+    let label = node_label(list, id)
+        .iter()
+        .map(|line| escape(line))
+        .collect::<Vec<_>>()
+        .join("\\n");
+    let mut attributes = format!("label=\"{label}\"");
+    attributes.push_str(match list.status(id) {
+        Some(TaskStatus::Incomplete) | None => ", color=\"goldenrod\"",
+        Some(TaskStatus::Blocked) => ", color=\"red\"",
+        Some(TaskStatus::Complete) => {
+            ", color=\"green4\", fontcolor=\"gray40\""
+        }
+    });
+    if list.get(id).is_some_and(|task| task.tag) {
+        attributes.push_str(", shape=hexagon");
+    }
+    attributes
+}
+
+/// Renders the given tasks as a Graphviz graph, with an edge from each task
+/// to the tasks it blocks.
+pub fn to_dot(list: &TodoList, tasks: &TaskSet) -> String {
+    let mut out = String::new();
+    out.push_str("digraph todo {\n");
+    out.push_str("  node [shape=box, style=rounded];\n");
+    for id in tasks.iter_sorted(list) {
+        writeln!(out, "  {} [{}];", node_id(list, id), attributes(list, id))
+            .unwrap();
+    }
+    for (from, to) in edges(list, tasks) {
+        writeln!(out, "  {} -> {};", node_id(list, from), node_id(list, to))
+            .unwrap();
+    }
+    out.push_str("}\n");
+    out
+}
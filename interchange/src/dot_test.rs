@@ -0,0 +1,125 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::to_dot;
+
+fn id(list: &TodoList, id: TaskId) -> String {
+    format!("task_{}", list.get(id).unwrap().short_id)
+}
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+#[test]
+fn empty_list() {
+    let list = TodoList::default();
+    assert_eq!(
+        to_dot(&list, &all(&list)),
+        "digraph todo {\n  node [shape=box, style=rounded];\n}\n"
+    );
+}
+
+#[test]
+fn nodes_and_edges() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    list.block(b).on(a).unwrap();
+    assert_eq!(
+        to_dot(&list, &all(&list)),
+        format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {a} [label=\"1) a\", color=\"goldenrod\"];\n",
+                "  {b} [label=\"2) b\", color=\"red\"];\n",
+                "  {a} -> {b};\n",
+                "}}\n",
+            ),
+            a = id(&list, a),
+            b = id(&list, b),
+        )
+    );
+}
+
+#[test]
+fn complete_task_and_tag() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(NewOptions::new().desc("b").as_tag());
+    list.check(a).unwrap();
+    assert_eq!(
+        to_dot(&list, &all(&list)),
+        format!(
+            concat!(
+                "digraph todo {{\n",
+                "  node [shape=box, style=rounded];\n",
+                "  {a} [label=\"0) a\", color=\"green4\", ",
+                "fontcolor=\"gray40\"];\n",
+                "  {b} [label=\"1) b\", color=\"goldenrod\", shape=hexagon];\n",
+                "}}\n",
+            ),
+            a = id(&list, a),
+            b = id(&list, b),
+        )
+    );
+}
+
+#[test]
+fn due_date_in_label() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .due_date(ymdhms(2021, 04, 16, 17, 00, 00)),
+    );
+    assert!(to_dot(&list, &all(&list)).contains(&format!(
+        "  {} [label=\"1) a\\ndue 2021-04-16 17:00\"",
+        id(&list, a)
+    )));
+}
+
+#[test]
+fn implicit_due_date_in_label() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .due_date(ymdhms(2021, 04, 16, 17, 00, 00)),
+    );
+    list.block(b).on(a).unwrap();
+    assert!(to_dot(&list, &all(&list)).contains(&format!(
+        "  {} [label=\"1) a\\ndue 2021-04-16 17:00\"",
+        id(&list, a)
+    )));
+}
+
+#[test]
+fn escapes_labels() {
+    let mut list = TodoList::default();
+    list.add("say \"hi\"\\\nbye");
+    assert!(to_dot(&list, &all(&list))
+        .contains("[label=\"1) say \\\"hi\\\"\\\\\\nbye\""));
+}
+
+#[test]
+fn only_edges_between_given_tasks() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let c = list.add("c");
+    list.block(b).on(a).unwrap();
+    list.block(c).on(b).unwrap();
+    let tasks = TaskSet::of(b) | TaskSet::of(c);
+    let dot = to_dot(&list, &tasks);
+    assert!(!dot.contains(&id(&list, a)));
+    assert!(dot.contains(&format!("  {} -> {};", id(&list, b), id(&list, c))));
+}
@@ -0,0 +1,330 @@
+//! The iCalendar format (RFC 5545). Each task is a VTODO, identified by a UID
+//! made from its short ID.
+//!
+//! Priorities 1 to 9 are iCalendar priorities 9 to 1, since iCalendar ranks
+//! 1 as the most important, and higher priorities are all 1. Blocks are
+//! RELATED-TO properties with the DEPENDS-ON relationship type (RFC 9253) on
+//! the blocked task. Tags are marked with the X-TODO-TAG property.
+//!
+//! When reading, the PARENT and CHILD relationship types that other
+//! applications use for subtasks are also understood, with parents blocked
+//! on their children. Times with a TZID are read as local times.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+use crate::ImportedTask;
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const MAX_LINE_LENGTH: usize = 75;
+
+fn uid(list: &TodoList, id: TaskId) -> String {
+    let short_id = list.get(id).map(|task| task.short_id.as_str());
+    format!("{}@todo", short_id.unwrap_or_default())
+}
+
+fn ics_priority(priority: i32) -> Option<i32> {
+    (priority > 0).then(|| 10 - priority.min(9))
+}
+
+fn format_date_time(date: DateTime<Utc>) -> String {
+    date.format(DATE_TIME_FORMAT).to_string()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+// Splits lines longer than 75 bytes into a line and continuation lines,
+// which start with a space.
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            out.push_str("\r\n ");
+            length = 1;
+        }
+        out.push(c);
+        length += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+fn vtodo(
+    list: &TodoList,
+    tasks: &TaskSet,
+    id: TaskId,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let Some(task) = list.get(id) else {
+        return Vec::new();
+    };
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", uid(list, id)),
+        format!("DTSTAMP:{}", format_date_time(now)),
+        format!("CREATED:{}", format_date_time(task.creation_time)),
+        format!("SUMMARY:{}", escape(&task.desc)),
+    ];
+    // iCalendar requires DUE to be after DTSTART, so the snooze date of a
+    // task snoozed past its due date is left out.
+    if task
+        .implicit_due_date
+        .is_none_or(|due_date| task.start_date < due_date)
+    {
+        lines.push(format!("DTSTART:{}", format_date_time(task.start_date)));
+    }
+    if let Some(due_date) = task.implicit_due_date {
+        lines.push(format!("DUE:{}", format_date_time(due_date)));
+    }
+    if let Some(priority) = ics_priority(task.implicit_priority) {
+        lines.push(format!("PRIORITY:{priority}"));
+    }
+    match (list.status(id), task.completion_time) {
+        (Some(TaskStatus::Complete), Some(completion_time)) => {
+            lines.push("STATUS:COMPLETED".to_string());
+            lines.push(format!(
+                "COMPLETED:{}",
+                format_date_time(completion_time)
+            ));
+        }
+        _ => lines.push("STATUS:NEEDS-ACTION".to_string()),
+    }
+    if task.tag {
+        lines.push("X-TODO-TAG:TRUE".to_string());
+    }
+    list.deps(id)
+        .iter_sorted(list)
+        .filter(|&dep| tasks.contains(dep))
+        .for_each(|dep| {
+            lines.push(format!(
+                "RELATED-TO;RELTYPE=DEPENDS-ON:{}",
+                uid(list, dep)
+            ))
+        });
+    lines.push("END:VTODO".to_string());
+    lines
+}
+
+/// Renders the given tasks as an iCalendar file with a VTODO for each task,
+/// stamped with |now| as the time of export. Due dates and priorities are the
+/// implicit ones, so calendars show the dates by which tasks really need to
+/// be done.
+pub fn to_ics(list: &TodoList, tasks: &TaskSet, now: DateTime<Utc>) -> String {
+    std::iter::once("BEGIN:VCALENDAR".to_string())
+        .chain(["VERSION:2.0".to_string(), "PRODID:-//todo//EN".to_string()])
+        .chain(
+            tasks
+                .iter_sorted(list)
+                .flat_map(|id| vtodo(list, tasks, id, now)),
+        )
+        .chain(std::iter::once("END:VCALENDAR".to_string()))
+        .map(|line| fold(&line))
+        .collect()
+}
+
+// Joins continuation lines to the lines they continue.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+struct Property<'a> {
+    name: String,
+    params: Vec<(String, &'a str)>,
+    value: &'a str,
+}
+
+impl Property<'_> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+fn parse_property(line: &str) -> Option<Property<'_>> {
+    let (name_and_params, value) = line.split_once(':')?;
+    let mut parts = name_and_params.split(';');
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(name, value)| (name.to_ascii_uppercase(), value))
+        .collect();
+    Some(Property {
+        name,
+        params,
+        value,
+    })
+}
+
+fn from_local(date_time: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&date_time)
+        .earliest()
+        .map(|date_time| date_time.with_timezone(&Utc))
+}
+
+// Dates without a time are taken to be at the given time of day.
+fn parse_date_time(
+    property: &Property,
+    time_of_day: NaiveTime,
+) -> Option<DateTime<Utc>> {
+    let value = property.value;
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return from_local(date.and_time(time_of_day));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|date_time| date_time.and_utc());
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .and_then(from_local)
+}
+
+#[derive(Default)]
+struct Vtodo {
+    task: ImportedTask,
+    uid: Option<String>,
+    complete: bool,
+    // UIDs of the tasks this one is blocked on.
+    deps: Vec<String>,
+    // UIDs of the tasks that are blocked on this one.
+    adeps: Vec<String>,
+}
+
+fn read_property(vtodo: &mut Vtodo, property: &Property) {
+    let start_of_day = NaiveTime::MIN;
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+    let task = &mut vtodo.task;
+    match property.name.as_str() {
+        "UID" => vtodo.uid = Some(property.value.to_string()),
+        "SUMMARY" => task.desc = unescape(property.value).trim().to_string(),
+        "CREATED" => {
+            task.creation_time = parse_date_time(property, start_of_day)
+        }
+        "DTSTART" => task.start_date = parse_date_time(property, start_of_day),
+        "DUE" => task.due_date = parse_date_time(property, end_of_day),
+        "COMPLETED" => {
+            task.completion_time = parse_date_time(property, start_of_day)
+        }
+        "PRIORITY" => {
+            if let Ok(priority @ 1..=9) = property.value.parse::<i32>() {
+                task.priority = 10 - priority;
+            }
+        }
+        "STATUS" => {
+            vtodo.complete = property.value.eq_ignore_ascii_case("COMPLETED")
+        }
+        "X-TODO-TAG" => task.tag = property.value.eq_ignore_ascii_case("TRUE"),
+        "RELATED-TO" => {
+            let uid = property.value.to_string();
+            let reltype =
+                property.param("RELTYPE").map(str::to_ascii_uppercase);
+            match reltype.as_deref() {
+                Some("DEPENDS-ON" | "CHILD") => vtodo.deps.push(uid),
+                Some("SIBLING") => {}
+                // The default relationship type is PARENT.
+                _ => vtodo.adeps.push(uid),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reads the VTODOs of an iCalendar file. Tasks with a COMPLETED time or a
+/// COMPLETED status are complete, and those without a COMPLETED time are
+/// completed at |now|. Other components, such as events, and VTODOs without
+/// a summary, are skipped.
+pub fn from_ics(text: &str, now: DateTime<Utc>) -> Vec<ImportedTask> {
+    let mut vtodos = Vec::new();
+    let mut current: Option<Vtodo> = None;
+    // The depth of components nested in the VTODO, like alarms.
+    let mut nested = 0;
+    for line in unfold(text) {
+        let Some(property) = parse_property(&line) else {
+            continue;
+        };
+        let component = property.value.to_ascii_uppercase();
+        match (property.name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if component == "VTODO" => {
+                current = Some(Vtodo::default())
+            }
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) => vtodos.extend(current.take()),
+            (_, Some(vtodo)) if nested == 0 => read_property(vtodo, &property),
+            _ => {}
+        }
+    }
+    vtodos.retain(|vtodo| !vtodo.task.desc.is_empty());
+    let indices: HashMap<String, usize> = vtodos
+        .iter()
+        .enumerate()
+        .filter_map(|(i, vtodo)| Some((vtodo.uid.clone()?, i)))
+        .collect();
+    let mut tasks: Vec<_> = vtodos
+        .iter()
+        .map(|vtodo| {
+            let mut task = vtodo.task.clone();
+            if vtodo.complete && task.completion_time.is_none() {
+                task.completion_time = Some(now);
+            }
+            task.deps = vtodo
+                .deps
+                .iter()
+                .filter_map(|uid| indices.get(uid).copied())
+                .collect();
+            task
+        })
+        .collect();
+    for (i, vtodo) in vtodos.iter().enumerate() {
+        for adep in vtodo.adeps.iter().filter_map(|uid| indices.get(uid)) {
+            tasks[*adep].deps.push(i);
+        }
+    }
+    tasks
+}
@@ -0,0 +1,260 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_model::CheckOptions;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::from_ics;
+use crate::import;
+use crate::to_ics;
+use crate::ImportedTask;
+
+fn uid(list: &TodoList, id: TaskId) -> String {
+    format!("{}@todo", list.get(id).unwrap().short_id)
+}
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+fn utc(list: &TodoList, id: TaskId) -> String {
+    list.get(id)
+        .unwrap()
+        .creation_time
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+#[test]
+fn export_empty_list() {
+    let list = TodoList::default();
+    assert_eq!(
+        to_ics(&list, &all(&list), ymdhms(2021, 04, 01, 09, 00, 00)),
+        concat!(
+            "BEGIN:VCALENDAR\r\n",
+            "VERSION:2.0\r\n",
+            "PRODID:-//todo//EN\r\n",
+            "END:VCALENDAR\r\n",
+        )
+    );
+}
+
+#[test]
+fn export_tasks_with_deps() {
+    let mut list = TodoList::default();
+    let now = ymdhms(2021, 04, 01, 09, 00, 00);
+    let a = list.add(NewOptions::new().desc("a").creation_time(now));
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .creation_time(now)
+            .priority(2)
+            .due_date(ymdhms(2021, 04, 20, 23, 59, 59)),
+    );
+    list.block(b).on(a).unwrap();
+    let exported = ymdhms(2021, 04, 02, 10, 00, 00);
+    assert_eq!(
+        to_ics(&list, &all(&list), exported),
+        format!(
+            concat!(
+                "BEGIN:VCALENDAR\r\n",
+                "VERSION:2.0\r\n",
+                "PRODID:-//todo//EN\r\n",
+                "BEGIN:VTODO\r\n",
+                "UID:{a}\r\n",
+                "DTSTAMP:{exported}\r\n",
+                "CREATED:{created}\r\n",
+                "SUMMARY:a\r\n",
+                "DTSTART:{created}\r\n",
+                "DUE:{due}\r\n",
+                "PRIORITY:8\r\n",
+                "STATUS:NEEDS-ACTION\r\n",
+                "END:VTODO\r\n",
+                "BEGIN:VTODO\r\n",
+                "UID:{b}\r\n",
+                "DTSTAMP:{exported}\r\n",
+                "CREATED:{created}\r\n",
+                "SUMMARY:b\r\n",
+                "DTSTART:{created}\r\n",
+                "DUE:{due}\r\n",
+                "PRIORITY:8\r\n",
+                "STATUS:NEEDS-ACTION\r\n",
+                "RELATED-TO;RELTYPE=DEPENDS-ON:{a}\r\n",
+                "END:VTODO\r\n",
+                "END:VCALENDAR\r\n",
+            ),
+            a = uid(&list, a),
+            b = uid(&list, b),
+            created = utc(&list, a),
+            exported = exported.format("%Y%m%dT%H%M%SZ"),
+            due = ymdhms(2021, 04, 20, 23, 59, 59).format("%Y%m%dT%H%M%SZ"),
+        )
+    );
+}
+
+#[test]
+fn export_complete_task_and_tag() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.add(NewOptions::new().desc("b").as_tag());
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2021, 04, 12, 10, 00, 00),
+    })
+    .unwrap();
+    let ics = to_ics(&list, &all(&list), ymdhms(2021, 04, 13, 09, 00, 00));
+    assert!(ics.contains(&format!(
+        "STATUS:COMPLETED\r\nCOMPLETED:{}\r\n",
+        ymdhms(2021, 04, 12, 10, 00, 00).format("%Y%m%dT%H%M%SZ")
+    )));
+    assert!(ics.contains("STATUS:NEEDS-ACTION\r\nX-TODO-TAG:TRUE\r\n"));
+}
+
+#[test]
+fn export_escapes_and_folds_long_lines() {
+    let mut list = TodoList::default();
+    list.add(format!("a, b; c\\d\n{}", "x".repeat(80)));
+    let ics = to_ics(&list, &all(&list), ymdhms(2021, 04, 01, 09, 00, 00));
+    assert!(ics.contains(&format!(
+        "SUMMARY:a\\, b\\; c\\\\d\\n{}\r\n {}\r\n",
+        "x".repeat(53),
+        "x".repeat(27),
+    )));
+}
+
+#[test]
+fn export_omits_snooze_date_after_due_date() {
+    let mut list = TodoList::default();
+    let now = ymdhms(2021, 04, 01, 09, 00, 00);
+    list.add(
+        NewOptions::new()
+            .desc("a")
+            .creation_time(now)
+            .due_date(ymdhms(2021, 04, 10, 23, 59, 59))
+            .start_date(ymdhms(2021, 04, 12, 00, 00, 00)),
+    );
+    let ics = to_ics(&list, &all(&list), now);
+    assert!(!ics.contains("DTSTART"));
+    assert!(ics.contains(&format!(
+        "DUE:{}\r\n",
+        ymdhms(2021, 04, 10, 23, 59, 59).format("%Y%m%dT%H%M%SZ")
+    )));
+}
+
+#[test]
+fn import_vtodos() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let ics = concat!(
+        "BEGIN:VCALENDAR\r\n",
+        "BEGIN:VEVENT\r\n",
+        "SUMMARY:meeting\r\n",
+        "END:VEVENT\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:1\r\n",
+        "SUMMARY:write\r\n",
+        "  report\\, draft\r\n",
+        "DUE;VALUE=DATE:20210420\r\n",
+        "DTSTART:20210415T090000\r\n",
+        "CREATED:20210401T080000Z\r\n",
+        "PRIORITY:1\r\n",
+        "BEGIN:VALARM\r\n",
+        "SUMMARY:alarm\r\n",
+        "END:VALARM\r\n",
+        "END:VTODO\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:2\r\n",
+        "SUMMARY:outline\r\n",
+        "STATUS:COMPLETED\r\n",
+        "RELATED-TO:1\r\n",
+        "END:VTODO\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:3\r\n",
+        "summary:gather data\r\n",
+        "COMPLETED:20210410T100000Z\r\n",
+        "RELATED-TO;RELTYPE=DEPENDS-ON:2\r\n",
+        "RELATED-TO;RELTYPE=DEPENDS-ON:unknown\r\n",
+        "END:VTODO\r\n",
+        "BEGIN:VTODO\r\n",
+        "UID:4\r\n",
+        "END:VTODO\r\n",
+        "END:VCALENDAR\r\n",
+    );
+    assert_eq!(
+        from_ics(ics, now),
+        [
+            ImportedTask {
+                desc: "write report, draft".to_string(),
+                priority: 9,
+                due_date: Some(ymdhms(2021, 04, 20, 23, 59, 59)),
+                start_date: Some(ymdhms(2021, 04, 15, 09, 00, 00)),
+                creation_time: Some(
+                    chrono::NaiveDate::from_ymd_opt(2021, 04, 01)
+                        .unwrap()
+                        .and_hms_opt(08, 00, 00)
+                        .unwrap()
+                        .and_utc()
+                ),
+                deps: vec![1],
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "outline".to_string(),
+                completion_time: Some(now),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "gather data".to_string(),
+                completion_time: Some(
+                    chrono::NaiveDate::from_ymd_opt(2021, 04, 10)
+                        .unwrap()
+                        .and_hms_opt(10, 00, 00)
+                        .unwrap()
+                        .and_utc()
+                ),
+                deps: vec![1],
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let work = list.add(NewOptions::new().desc("work").as_tag());
+    list.block(b).on(a).unwrap();
+    list.block(work).on(b).unwrap();
+    list.check(a).unwrap();
+    let mut imported = TodoList::default();
+    import(
+        &mut imported,
+        &from_ics(&to_ics(&list, &all(&list), now), now),
+        now,
+    );
+    let summary = |list: &TodoList| {
+        list.all_tasks()
+            .map(|id| {
+                let task = list.get(id).unwrap();
+                let deps = list
+                    .deps(id)
+                    .iter_sorted(list)
+                    .map(|dep| list.get(dep).unwrap().desc.to_string())
+                    .collect::<Vec<_>>();
+                (task.desc.to_string(), task.tag, list.status(id), deps)
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(summary(&imported), summary(&list));
+    assert_eq!(
+        summary(&imported)[0],
+        ("a".to_string(), false, Some(TaskStatus::Complete), vec![])
+    );
+}
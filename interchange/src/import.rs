@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+
+use chrono::DateTime;
+use chrono::Utc;
+use todo_model::CheckOptions;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+
+use crate::tag_name;
+
+/// A task read from another tool's format, before it is added to a list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportedTask {
+    pub desc: String,
+    pub priority: i32,
+    pub due_date: Option<DateTime<Utc>>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub creation_time: Option<DateTime<Utc>>,
+    pub completion_time: Option<DateTime<Utc>>,
+    /// Whether the task is itself a tag.
+    pub tag: bool,
+    /// The descriptions of the tags the task belongs to. Each tag becomes a
+    /// tag task that is blocked on the imported task. A name with hyphens in
+    /// place of spaces also matches an existing tag, since that's how tags
+    /// are exported to formats whose tag names can't contain spaces.
+    pub tags: Vec<String>,
+    /// Indices into the imported tasks of the tasks this one is blocked on.
+    pub deps: Vec<usize>,
+}
+
+fn find_by_desc(list: &TodoList, desc: &str) -> Option<TaskId> {
+    list.all_tasks()
+        .find(|&id| list.get(id).is_some_and(|task| task.desc == desc))
+}
+
+// Finds the tag for a tag name read from a file, either by its description or,
+// for names exported with hyphens in place of spaces, by its tag name.
+fn find_tag(list: &TodoList, name: &str) -> Option<TaskId> {
+    find_by_desc(list, name).or_else(|| {
+        list.all_tasks().find(|&id| {
+            list.get(id)
+                .is_some_and(|task| task.tag && tag_name(&task.desc) == name)
+        })
+    })
+}
+
+/// Adds the imported tasks to the list and returns the tasks that were
+/// created, including any new tag tasks.
+///
+/// A task whose description matches one already in the list is not added
+/// again, and the existing task is left as it is. Blocks that would cause a
+/// cycle are dropped. Complete tasks are only checked once all their deps
+/// are complete, so a complete task blocked on an incomplete one is imported
+/// as incomplete.
+pub fn import(
+    list: &mut TodoList,
+    tasks: &[ImportedTask],
+    now: DateTime<Utc>,
+) -> TaskSet {
+    let mut created = TaskSet::default();
+    let ids: Vec<(TaskId, bool)> = tasks
+        .iter()
+        .map(|task| match find_by_desc(list, &task.desc) {
+            Some(id) => (id, false),
+            None => {
+                let creation_time = task
+                    .creation_time
+                    .or(task.completion_time.map(|done| done.min(now)))
+                    .unwrap_or(now);
+                let id = list.add(NewOptions {
+                    desc: Cow::Owned(task.desc.clone()),
+                    now: creation_time,
+                    priority: task.priority,
+                    due_date: task.due_date,
+                    budget: Default::default(),
+                    start_date: task.start_date,
+                    tag: task.tag,
+                    recurrence: None,
+                });
+                created.push(id);
+                (id, true)
+            }
+        })
+        .collect();
+    for (task, &(id, is_new)) in tasks.iter().zip(&ids) {
+        if !is_new {
+            continue;
+        }
+        for &dep in &task.deps {
+            if let Some(&(dep, _)) = ids.get(dep) {
+                let _ = list.block(id).on(dep);
+            }
+        }
+        for tag in &task.tags {
+            let tag = find_tag(list, tag).unwrap_or_else(|| {
+                let tag = list.add(
+                    NewOptions::new()
+                        .desc(tag.clone())
+                        .creation_time(now)
+                        .as_tag(),
+                );
+                created.push(tag);
+                tag
+            });
+            let _ = list.block(tag).on(id);
+        }
+    }
+    // Check tasks whose deps are all complete until no more can be checked,
+    // so that the order of the imported tasks doesn't matter.
+    let mut to_check: Vec<_> = tasks
+        .iter()
+        .zip(&ids)
+        .filter_map(|(task, &(id, is_new))| {
+            Some((id, task.completion_time?)).filter(|_| is_new)
+        })
+        .collect();
+    loop {
+        let before = to_check.len();
+        to_check.retain(|&(id, completion_time)| {
+            list.check(CheckOptions {
+                id,
+                now: completion_time,
+            })
+            .is_err()
+        });
+        if to_check.len() == before {
+            break;
+        }
+    }
+    created
+}
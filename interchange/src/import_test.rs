@@ -0,0 +1,172 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_model::TaskId;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::import;
+use crate::ImportedTask;
+
+fn imported(desc: &str) -> ImportedTask {
+    ImportedTask {
+        desc: desc.to_string(),
+        ..Default::default()
+    }
+}
+
+fn descs(
+    list: &TodoList,
+    ids: impl IntoIterator<Item = TaskId>,
+) -> Vec<String> {
+    ids.into_iter()
+        .map(|id| list.get(id).unwrap().desc.to_string())
+        .collect()
+}
+
+#[test]
+fn adds_tasks_with_properties() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let created = import(
+        &mut list,
+        &[ImportedTask {
+            desc: "a".to_string(),
+            priority: 2,
+            due_date: Some(ymdhms(2021, 05, 03, 23, 59, 59)),
+            creation_time: Some(ymdhms(2021, 04, 01, 00, 00, 00)),
+            ..Default::default()
+        }],
+        now,
+    );
+    let a = created.as_sorted_vec(&list)[0];
+    let task = list.get(a).unwrap();
+    assert_eq!(task.desc, "a");
+    assert_eq!(task.priority, 2);
+    assert_eq!(task.due_date, Some(ymdhms(2021, 05, 03, 23, 59, 59)));
+    assert_eq!(task.creation_time, ymdhms(2021, 04, 01, 00, 00, 00));
+}
+
+#[test]
+fn skips_existing_descriptions() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let created = import(
+        &mut list,
+        &[imported("a"), imported("b"), imported("b")],
+        now,
+    );
+    assert_eq!(descs(&list, created.iter_sorted(&list)), ["b"]);
+    assert_eq!(list.all_tasks().count(), 2);
+    assert_eq!(list.get(a).unwrap().desc, "a");
+}
+
+#[test]
+fn tags_block_on_tasks() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let home = list.add(todo_model::NewOptions::new().desc("home").as_tag());
+    let created = import(
+        &mut list,
+        &[
+            ImportedTask {
+                tags: vec!["work".to_string(), "home".to_string()],
+                ..imported("a")
+            },
+            ImportedTask {
+                tags: vec!["work".to_string()],
+                ..imported("b")
+            },
+        ],
+        now,
+    );
+    assert_eq!(descs(&list, created.iter_sorted(&list)), ["a", "b", "work"]);
+    let work = created.as_sorted_vec(&list)[2];
+    assert!(list.get(work).unwrap().tag);
+    assert_eq!(descs(&list, list.deps(work).iter_sorted(&list)), ["a", "b"]);
+    assert_eq!(descs(&list, list.deps(home).iter_sorted(&list)), ["a"]);
+}
+
+#[test]
+fn hyphenated_tag_names_match_tags_with_spaces() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let work_stuff =
+        list.add(todo_model::NewOptions::new().desc("work stuff").as_tag());
+    let created = import(
+        &mut list,
+        &[ImportedTask {
+            tags: vec!["work-stuff".to_string()],
+            ..imported("a")
+        }],
+        now,
+    );
+    assert_eq!(descs(&list, created.iter_sorted(&list)), ["a"]);
+    assert_eq!(
+        descs(&list, list.deps(work_stuff).iter_sorted(&list)),
+        ["a"]
+    );
+}
+
+#[test]
+fn deps_block_tasks() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let created = import(
+        &mut list,
+        &[
+            ImportedTask {
+                deps: vec![1],
+                ..imported("a")
+            },
+            imported("b"),
+        ],
+        now,
+    );
+    let [b, a] = created.as_sorted_vec(&list)[..] else {
+        panic!("expected two tasks");
+    };
+    assert_eq!(list.deps(a).as_sorted_vec(&list), [b]);
+}
+
+#[test]
+fn completes_tasks_after_their_deps() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let created = import(
+        &mut list,
+        &[
+            ImportedTask {
+                deps: vec![1],
+                completion_time: Some(ymdhms(2021, 04, 20, 00, 00, 00)),
+                ..imported("a")
+            },
+            ImportedTask {
+                completion_time: Some(ymdhms(2021, 04, 10, 00, 00, 00)),
+                ..imported("b")
+            },
+            ImportedTask {
+                deps: vec![3],
+                completion_time: Some(ymdhms(2021, 04, 10, 00, 00, 00)),
+                ..imported("c")
+            },
+            imported("d"),
+        ],
+        now,
+    );
+    let statuses = created
+        .iter_sorted(&list)
+        .map(|id| (list.get(id).unwrap().desc.to_string(), list.status(id)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        [
+            ("b".to_string(), Some(TaskStatus::Complete)),
+            ("a".to_string(), Some(TaskStatus::Complete)),
+            ("d".to_string(), Some(TaskStatus::Incomplete)),
+            ("c".to_string(), Some(TaskStatus::Blocked)),
+        ]
+    );
+}
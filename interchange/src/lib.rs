@@ -0,0 +1,96 @@
+//! Converts to-do lists to and from the formats of other tools.
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+
+mod dot;
+mod ics;
+mod import;
+mod mermaid;
+mod outline;
+mod table;
+mod taskwarrior;
+mod todo_txt;
+
+pub use self::dot::to_dot;
+pub use self::ics::from_ics;
+pub use self::ics::to_ics;
+pub use self::import::import;
+pub use self::import::ImportedTask;
+pub use self::mermaid::to_mermaid;
+pub use self::outline::from_markdown;
+pub use self::outline::from_org;
+pub use self::table::to_csv;
+pub use self::table::to_tsv;
+pub use self::taskwarrior::from_taskwarrior;
+pub use self::taskwarrior::to_taskwarrior;
+pub use self::todo_txt::from_todo_txt;
+pub use self::todo_txt::to_todo_txt;
+
+// The name of a tag in formats where tag names can't contain spaces, like
+// todo.txt projects and Taskwarrior tags. Importing maps the name back to the
+// tag it came from.
+fn tag_name(desc: &str) -> String {
+    desc.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+// An identifier for the task's node in a diagram. Short IDs are used rather
+// than numbers because numbers may be negative.
+fn node_id(list: &TodoList, id: TaskId) -> String {
+    let short_id = list.get(id).map(|task| task.short_id.as_str());
+    format!("task_{}", short_id.unwrap_or_default())
+}
+
+// The lines of text to show for a task in a diagram: the number and
+// description, then the due date, if any.
+fn node_label(list: &TodoList, id: TaskId) -> Vec<String> {
+    let (Some(task), Some(pos)) = (list.get(id), list.position(id)) else {
+        return Vec::new();
+    };
+    let mut lines = vec![format!("{pos}) {}", task.desc)];
+    if let Some(due_date) = task.implicit_due_date {
+        lines.push(format!("due {}", format_date(due_date)));
+    }
+    lines
+}
+
+fn format_date(date: DateTime<Utc>) -> String {
+    date.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+// Pairs of tasks where the first blocks the second, among the given tasks.
+fn edges(list: &TodoList, tasks: &TaskSet) -> Vec<(TaskId, TaskId)> {
+    tasks
+        .iter_sorted(list)
+        .flat_map(|id| {
+            list.deps(id)
+                .iter_sorted(list)
+                .filter(|&dep| tasks.contains(dep))
+                .map(move |dep| (dep, id))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod dot_test;
+#[cfg(test)]
+mod ics_test;
+#[cfg(test)]
+mod import_test;
+#[cfg(test)]
+mod mermaid_test;
+#[cfg(test)]
+mod outline_test;
+#[cfg(test)]
+mod table_test;
+#[cfg(test)]
+mod taskwarrior_test;
+#[cfg(test)]
+mod todo_txt_test;
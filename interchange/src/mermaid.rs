@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+use crate::edges;
+use crate::node_id;
+use crate::node_label;
+
+// Mermaid labels are HTML, and '#' starts an entity code, so characters that
+// could be misread are written as entity codes.
+fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '#' => "#35;".to_string(),
+            '"' => "#quot;".to_string(),
+            '<' => "#lt;".to_string(),
+            '>' => "#gt;".to_string(),
+            '\n' => "<br/>".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn node(list: &TodoList, id: TaskId) -> String {
+    let label = node_label(list, id)
+        .iter()
+        .map(|line| escape(line))
+        .collect::<Vec<_>>()
+        .join("<br/>");
+    let class = match list.status(id) {
+        Some(TaskStatus::Incomplete) | None => "incomplete",
+        Some(TaskStatus::Blocked) => "blocked",
+        Some(TaskStatus::Complete) => "complete",
+    };
+    let node_id = node_id(list, id);
+    if list.get(id).is_some_and(|task| task.tag) {
+        format!("{node_id}{{{{\"{label}\"}}}}:::{class}")
+    } else {
+        format!("{node_id}[\"{label}\"]:::{class}")
+    }
+}
+
+/// Renders the given tasks as a Mermaid flowchart, with an arrow from each
+/// task to the tasks it blocks.
+pub fn to_mermaid(list: &TodoList, tasks: &TaskSet) -> String {
+    let mut out = String::new();
+    out.push_str("flowchart TD\n");
+    for id in tasks.iter_sorted(list) {
+        writeln!(out, "    {}", node(list, id)).unwrap();
+    }
+    for (from, to) in edges(list, tasks) {
+        writeln!(out, "    {} --> {}", node_id(list, from), node_id(list, to))
+            .unwrap();
+    }
+    // Colors match the colors of task numbers on the terminal.
+    out.push_str("    classDef incomplete stroke:#b8860b\n");
+    out.push_str("    classDef blocked stroke:#ff0000\n");
+    out.push_str("    classDef complete stroke:#008b00,color:#666666\n");
+    out
+}
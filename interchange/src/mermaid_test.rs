@@ -0,0 +1,101 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::to_mermaid;
+
+fn id(list: &TodoList, id: TaskId) -> String {
+    format!("task_{}", list.get(id).unwrap().short_id)
+}
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+const CLASS_DEFS: &str = concat!(
+    "    classDef incomplete stroke:#b8860b\n",
+    "    classDef blocked stroke:#ff0000\n",
+    "    classDef complete stroke:#008b00,color:#666666\n",
+);
+
+#[test]
+fn empty_list() {
+    let list = TodoList::default();
+    assert_eq!(
+        to_mermaid(&list, &all(&list)),
+        format!("flowchart TD\n{CLASS_DEFS}")
+    );
+}
+
+#[test]
+fn nodes_and_edges() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let c = list.add(NewOptions::new().desc("c").as_tag());
+    list.block(b).on(a).unwrap();
+    list.check(a).unwrap();
+    list.block(c).on(b).unwrap();
+    assert_eq!(
+        to_mermaid(&list, &all(&list)),
+        format!(
+            concat!(
+                "flowchart TD\n",
+                "    {a}[\"0) a\"]:::complete\n",
+                "    {b}[\"1) b\"]:::incomplete\n",
+                "    {c}{{{{\"2) c\"}}}}:::blocked\n",
+                "    {a} --> {b}\n",
+                "    {b} --> {c}\n",
+                "{class_defs}",
+            ),
+            a = id(&list, a),
+            b = id(&list, b),
+            c = id(&list, c),
+            class_defs = CLASS_DEFS,
+        )
+    );
+}
+
+#[test]
+fn due_date_in_label() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .due_date(ymdhms(2021, 04, 16, 17, 00, 00)),
+    );
+    assert!(to_mermaid(&list, &all(&list)).contains(&format!(
+        "    {}[\"1) a<br/>due 2021-04-16 17:00\"]:::incomplete\n",
+        id(&list, a)
+    )));
+}
+
+#[test]
+fn implicit_due_date_in_label() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .due_date(ymdhms(2021, 04, 16, 17, 00, 00)),
+    );
+    list.block(b).on(a).unwrap();
+    assert!(to_mermaid(&list, &all(&list)).contains(&format!(
+        "    {}[\"1) a<br/>due 2021-04-16 17:00\"]:::incomplete\n",
+        id(&list, a)
+    )));
+}
+
+#[test]
+fn escapes_labels() {
+    let mut list = TodoList::default();
+    list.add("say \"hi\" to #1 <b>\nnow");
+    assert!(to_mermaid(&list, &all(&list)).contains(
+        "[\"1) say #quot;hi#quot; to #35;1 #lt;b#gt;<br/>now\"]:::incomplete"
+    ));
+}
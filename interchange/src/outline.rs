@@ -0,0 +1,187 @@
+//! Outlines of tasks in Markdown checklists and Org-mode headlines. Each
+//! checklist item or TODO headline is a task, and each task is blocked on the
+//! tasks nested under it.
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono::Utc;
+
+use crate::ImportedTask;
+
+// Makes each task blocked on the tasks nested under it, given the depth of
+// each item in the outline. Items that aren't tasks are None; tasks nested
+// under them are blocking the closest ancestor that is a task.
+fn nest(items: Vec<(usize, Option<ImportedTask>)>) -> Vec<ImportedTask> {
+    let mut tasks = Vec::<ImportedTask>::new();
+    // The depths of the current item's ancestors, and their indices if they
+    // are tasks.
+    let mut ancestors = Vec::<(usize, Option<usize>)>::new();
+    for (depth, task) in items {
+        while ancestors.last().is_some_and(|&(d, _)| d >= depth) {
+            ancestors.pop();
+        }
+        let Some(task) = task else {
+            ancestors.push((depth, None));
+            continue;
+        };
+        let index = tasks.len();
+        if let Some(parent) = ancestors.iter().rev().find_map(|&(_, i)| i) {
+            tasks[parent].deps.push(index);
+        }
+        ancestors.push((depth, Some(index)));
+        tasks.push(task);
+    }
+    tasks
+}
+
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+// Strips a bullet ("-", "*", or "+") or number ("1." or "1)") from the start
+// of a list item.
+fn strip_list_marker(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix(['-', '*', '+']).or_else(|| {
+        let digits = line.find(|c: char| !c.is_ascii_digit())?;
+        (digits > 0)
+            .then(|| line[digits..].strip_prefix(['.', ')']))
+            .flatten()
+    })?;
+    rest.strip_prefix([' ', '\t'])
+}
+
+// Reads a list item, which is a task if it has a checkbox.
+fn parse_list_item(
+    line: &str,
+    now: DateTime<Utc>,
+) -> Option<(usize, Option<ImportedTask>)> {
+    let item = strip_list_marker(line.trim_start())?.trim_start();
+    let (checked, desc) = if let Some(desc) = item.strip_prefix("[ ]") {
+        (false, desc)
+    } else if let Some(desc) =
+        item.strip_prefix("[x]").or(item.strip_prefix("[X]"))
+    {
+        (true, desc)
+    } else {
+        return Some((indentation(line), None));
+    };
+    let task = (!desc.trim().is_empty()).then(|| ImportedTask {
+        desc: desc.trim().to_string(),
+        completion_time: checked.then_some(now),
+        ..Default::default()
+    });
+    Some((indentation(line), task))
+}
+
+/// Reads the items of Markdown checklists ("- [ ] item" or "- [x] item"),
+/// where items are nested by indenting them. Checked items are completed at
+/// |now|. List items without a checkbox aren't tasks, and other lines are
+/// skipped.
+pub fn from_markdown(text: &str, now: DateTime<Utc>) -> Vec<ImportedTask> {
+    nest(
+        text.lines()
+            .filter_map(|line| parse_list_item(line, now))
+            .collect(),
+    )
+}
+
+// Reads the date and optional time of an Org timestamp, like
+// "<2021-04-20 Tue>" or "[2021-04-20 Tue 17:00]". Dates without a time are
+// taken to be at the given time of day.
+fn parse_org_timestamp(
+    s: &str,
+    time_of_day: NaiveTime,
+) -> Option<DateTime<Utc>> {
+    let s = s.trim_start().strip_prefix(['<', '['])?;
+    let end = s.find(['>', ']'])?;
+    let mut words = s[..end].split_whitespace();
+    let date = NaiveDate::parse_from_str(words.next()?, "%Y-%m-%d").ok()?;
+    let time = words
+        .find_map(|word| NaiveTime::parse_from_str(word, "%H:%M").ok())
+        .unwrap_or(time_of_day);
+    Local
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|date_time| date_time.with_timezone(&Utc))
+}
+
+// Reads the DEADLINE, SCHEDULED, and CLOSED timestamps of a planning line
+// into the task.
+fn read_org_planning(line: &str, task: &mut ImportedTask) {
+    let start_of_day = NaiveTime::MIN;
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+    for (keyword, time_of_day) in [
+        ("DEADLINE:", end_of_day),
+        ("SCHEDULED:", start_of_day),
+        ("CLOSED:", start_of_day),
+    ] {
+        let Some(date) = line
+            .find(keyword)
+            .map(|i| &line[i + keyword.len()..])
+            .and_then(|rest| parse_org_timestamp(rest, time_of_day))
+        else {
+            continue;
+        };
+        match keyword {
+            "DEADLINE:" => task.due_date = Some(date),
+            "SCHEDULED:" => task.start_date = Some(date),
+            // Only DONE headlines are closed.
+            _ if task.completion_time.is_some() => {
+                task.completion_time = Some(date)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reads the Org-mode headlines with a TODO or DONE keyword, nested by their
+/// level. DONE headlines are completed at their CLOSED time if there is one,
+/// or at |now|. DEADLINE is the due date, and SCHEDULED is the date the task
+/// is snoozed until. Other headlines aren't tasks, and other lines are
+/// skipped.
+pub fn from_org(text: &str, now: DateTime<Utc>) -> Vec<ImportedTask> {
+    let mut items = Vec::<(usize, Option<ImportedTask>)>::new();
+    // Whether the previous line was a headline, so that a planning line
+    // after it applies to it.
+    let mut after_headline = false;
+    for line in text.lines() {
+        let level = line.chars().take_while(|&c| c == '*').count();
+        let Some(headline) =
+            line[level..].strip_prefix(' ').filter(|_| level > 0)
+        else {
+            if let (true, Some((_, Some(task)))) =
+                (after_headline, items.last_mut())
+            {
+                read_org_planning(line, task);
+            }
+            after_headline = false;
+            continue;
+        };
+        after_headline = true;
+        let (keyword, desc) = headline
+            .trim()
+            .split_once(' ')
+            .unwrap_or((headline.trim(), ""));
+        let completion_time = match keyword {
+            "TODO" => None,
+            "DONE" => Some(now),
+            _ => {
+                items.push((level, None));
+                continue;
+            }
+        };
+        let task = (!desc.trim().is_empty()).then(|| ImportedTask {
+            desc: desc.trim().to_string(),
+            completion_time,
+            ..Default::default()
+        });
+        items.push((level, task));
+    }
+    nest(items)
+}
@@ -0,0 +1,143 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_testing::ymdhms;
+
+use crate::from_markdown;
+use crate::from_org;
+use crate::ImportedTask;
+
+fn task(desc: &str, deps: &[usize]) -> ImportedTask {
+    ImportedTask {
+        desc: desc.to_string(),
+        deps: deps.to_vec(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn markdown_nested_checklist() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "# Launch\n",
+        "\n",
+        "- [ ] launch\n",
+        "  - [ ] write docs\n",
+        "    - [x] outline\n",
+        "  - [ ] fix bugs\n",
+        "- [ ] party\n",
+    );
+    assert_eq!(
+        from_markdown(text, now),
+        [
+            task("launch", &[1, 3]),
+            task("write docs", &[2]),
+            ImportedTask {
+                completion_time: Some(now),
+                ..task("outline", &[])
+            },
+            task("fix bugs", &[]),
+            task("party", &[]),
+        ]
+    );
+}
+
+#[test]
+fn markdown_list_markers() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "* [ ] a\n",
+        "\t+ [X] b\n",
+        "1. [ ] c\n",
+        "2) [ ] d\n",
+        "-[ ] not an item\n",
+        "- [ ]\n",
+    );
+    assert_eq!(
+        from_markdown(text, now),
+        [
+            task("a", &[1]),
+            ImportedTask {
+                completion_time: Some(now),
+                ..task("b", &[])
+            },
+            task("c", &[]),
+            task("d", &[]),
+        ]
+    );
+}
+
+#[test]
+fn markdown_items_without_checkbox_group_tasks() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "- [ ] a\n",
+        "  - notes\n",
+        "    - [ ] b\n",
+        "- groceries\n",
+        "  - [ ] milk\n",
+    );
+    assert_eq!(
+        from_markdown(text, now),
+        [task("a", &[1]), task("b", &[]), task("milk", &[])]
+    );
+}
+
+#[test]
+fn org_nested_headlines() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "#+TITLE: Plan\n",
+        "* TODO launch\n",
+        "Some notes.\n",
+        "** TODO write docs\n",
+        "*** DONE outline\n",
+        "** TODO fix bugs\n",
+        "* Someday\n",
+        "** TODO party\n",
+        "*not a headline*\n",
+    );
+    assert_eq!(
+        from_org(text, now),
+        [
+            task("launch", &[1, 3]),
+            task("write docs", &[2]),
+            ImportedTask {
+                completion_time: Some(now),
+                ..task("outline", &[])
+            },
+            task("fix bugs", &[]),
+            task("party", &[]),
+        ]
+    );
+}
+
+#[test]
+fn org_planning() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "* TODO a\n",
+        "  DEADLINE: <2021-04-20 Tue> SCHEDULED: <2021-04-15 Thu 09:30>\n",
+        "* DONE b\n",
+        "  CLOSED: [2021-04-12 Mon 10:00] DEADLINE: <2021-04-13 Tue 17:00>\n",
+        "* TODO c\n",
+        "\n",
+        "  DEADLINE: <2021-04-20 Tue>\n",
+    );
+    assert_eq!(
+        from_org(text, now),
+        [
+            ImportedTask {
+                due_date: Some(ymdhms(2021, 04, 20, 23, 59, 59)),
+                start_date: Some(ymdhms(2021, 04, 15, 09, 30, 00)),
+                ..task("a", &[])
+            },
+            ImportedTask {
+                due_date: Some(ymdhms(2021, 04, 13, 17, 00, 00)),
+                completion_time: Some(ymdhms(2021, 04, 12, 10, 00, 00)),
+                ..task("b", &[])
+            },
+            task("c", &[]),
+        ]
+    );
+}
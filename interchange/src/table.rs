@@ -0,0 +1,131 @@
+//! Tables of tasks for spreadsheets, as CSV (RFC 4180) or TSV.
+//!
+//! There is a row for each task, with a column for each of its fields. Times
+//! are in the local time zone, budgets are in seconds, and deps and adeps are
+//! the positions of the tasks, separated by spaces.
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+const COLUMNS: &[&str] = &[
+    "position",
+    "id",
+    "description",
+    "status",
+    "creation_time",
+    "completion_time",
+    "priority",
+    "implicit_priority",
+    "due_date",
+    "implicit_due_date",
+    "budget",
+    "start_date",
+    "tag",
+    "implicit_tags",
+    "deps",
+    "adeps",
+];
+
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|time| {
+        time.with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    })
+    .unwrap_or_default()
+}
+
+fn positions(list: &TodoList, tasks: &TaskSet) -> String {
+    tasks
+        .iter_sorted(list)
+        .filter_map(|id| list.position(id))
+        .map(|pos| pos.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn row(list: &TodoList, id: TaskId) -> Option<Vec<String>> {
+    let (Some(task), Some(pos)) = (list.get(id), list.position(id)) else {
+        return None;
+    };
+    let status = match list.status(id)? {
+        TaskStatus::Incomplete => "incomplete",
+        TaskStatus::Blocked => "blocked",
+        TaskStatus::Complete => "complete",
+    };
+    Some(vec![
+        pos.to_string(),
+        task.short_id.clone(),
+        task.desc.to_string(),
+        status.to_string(),
+        format_time(Some(task.creation_time)),
+        format_time(task.completion_time),
+        task.priority.to_string(),
+        task.implicit_priority.to_string(),
+        format_time(task.due_date),
+        format_time(task.implicit_due_date),
+        task.budget.0.to_string(),
+        format_time(Some(task.start_date)),
+        task.tag.to_string(),
+        task.implicit_tags
+            .iter()
+            .filter_map(|&tag| list.get(tag))
+            .map(|tag| tag.desc.as_ref())
+            .collect::<Vec<_>>()
+            .join(", "),
+        positions(list, &list.deps(id)),
+        positions(list, &list.adeps(id)),
+    ])
+}
+
+fn table(
+    list: &TodoList,
+    tasks: &TaskSet,
+    format_field: impl Fn(&str) -> String,
+    separator: &str,
+    line_ending: &str,
+) -> String {
+    let header = COLUMNS.iter().map(|column| column.to_string()).collect();
+    std::iter::once(header)
+        .chain(tasks.iter_sorted(list).filter_map(|id| row(list, id)))
+        .map(|fields| {
+            let fields = fields.iter().map(|field| format_field(field));
+            fields.collect::<Vec<_>>().join(separator) + line_ending
+        })
+        .collect()
+}
+
+// Fields with commas, quotes, or line breaks are quoted, with quotes doubled.
+fn quote_csv(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// Fields can't contain tabs or line breaks, so they're escaped the way most
+// tools that read TSV expect.
+fn escape_tsv(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+/// Renders the given tasks as CSV, with a header row and CRLF line endings
+/// as RFC 4180 specifies.
+pub fn to_csv(list: &TodoList, tasks: &TaskSet) -> String {
+    table(list, tasks, quote_csv, ",", "\r\n")
+}
+
+/// Renders the given tasks as tab-separated values, with a header row.
+pub fn to_tsv(list: &TodoList, tasks: &TaskSet) -> String {
+    table(list, tasks, escape_tsv, "\t", "\n")
+}
@@ -0,0 +1,108 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use chrono::Duration;
+use todo_model::CheckOptions;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::to_csv;
+use crate::to_tsv;
+
+const HEADER: &str = concat!(
+    "position,id,description,status,creation_time,completion_time,",
+    "priority,implicit_priority,due_date,implicit_due_date,budget,",
+    "start_date,tag,implicit_tags,deps,adeps",
+);
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+fn short_id(list: &TodoList, id: TaskId) -> String {
+    list.get(id).unwrap().short_id.clone()
+}
+
+fn created(desc: &str) -> NewOptions<'_> {
+    NewOptions::new()
+        .desc(desc)
+        .creation_time(ymdhms(2021, 04, 01, 09, 00, 00))
+}
+
+#[test]
+fn csv_empty_list() {
+    let list = TodoList::default();
+    assert_eq!(to_csv(&list, &all(&list)), format!("{HEADER}\r\n"));
+}
+
+#[test]
+fn csv_rows() {
+    let mut list = TodoList::default();
+    let a = list.add(created("a").budget(Duration::hours(1)));
+    let b = list.add(
+        created("b")
+            .priority(2)
+            .due_date(ymdhms(2021, 04, 20, 17, 00, 00)),
+    );
+    let c = list.add(created("c"));
+    let work = list.add(created("work").as_tag());
+    list.block(b).on(a).unwrap();
+    list.block(work).on(b).unwrap();
+    list.check(CheckOptions {
+        id: c,
+        now: ymdhms(2021, 04, 12, 10, 00, 00),
+    })
+    .unwrap();
+    assert_eq!(
+        to_csv(&list, &all(&list)),
+        format!(
+            concat!(
+                "{header}\r\n",
+                "0,{c},c,complete,2021-04-01 09:00:00,2021-04-12 10:00:00,",
+                "0,0,,,0,2021-04-01 09:00:00,false,,,\r\n",
+                "1,{a},a,incomplete,2021-04-01 09:00:00,,",
+                "0,2,,2021-04-20 17:00:00,3600,2021-04-01 09:00:00,false,",
+                "work,,2\r\n",
+                "2,{b},b,blocked,2021-04-01 09:00:00,,",
+                "2,2,2021-04-20 17:00:00,2021-04-20 17:00:00,0,",
+                "2021-04-01 09:00:00,false,work,1,3\r\n",
+                "3,{work},work,blocked,2021-04-01 09:00:00,,",
+                "0,0,,,0,2021-04-01 09:00:00,true,,2,\r\n",
+            ),
+            header = HEADER,
+            a = short_id(&list, a),
+            b = short_id(&list, b),
+            c = short_id(&list, c),
+            work = short_id(&list, work),
+        )
+    );
+}
+
+#[test]
+fn csv_quotes_fields() {
+    let mut list = TodoList::default();
+    list.add(created("say \"hi\", then\nleave"));
+    let csv = to_csv(&list, &all(&list));
+    assert!(csv.contains(",\"say \"\"hi\"\", then\nleave\",incomplete,"));
+}
+
+#[test]
+fn tsv_escapes_fields() {
+    let mut list = TodoList::default();
+    let a = list.add(created("a\tb\\c\nd"));
+    assert_eq!(
+        to_tsv(&list, &all(&list)),
+        format!(
+            concat!(
+                "{header}\n",
+                "1\t{a}\ta\\tb\\\\c\\nd\tincomplete\t2021-04-01 09:00:00\t\t",
+                "0\t0\t\t\t0\t2021-04-01 09:00:00\tfalse\t\t\t\n",
+            ),
+            header = HEADER.replace(',', "\t"),
+            a = short_id(&list, a),
+        )
+    );
+}
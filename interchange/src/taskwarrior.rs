@@ -0,0 +1,204 @@
+//! The JSON format of Taskwarrior's 'task export' and 'task import'.
+//!
+//! Priorities H, M, and L are priorities 3, 2, and 1, and higher priorities
+//! are H. Blocks are the 'depends' attribute of the blocked task. Tags are
+//! written as Taskwarrior tags of the tasks that block them, with hyphens in
+//! place of spaces, and both tags and projects are read as tags. The 'wait'
+//! date is the date a task is snoozed until, and when reading, 'scheduled' is
+//! too if there is no 'wait'.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+use crate::tag_name;
+use crate::ImportedTask;
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+#[derive(Serialize)]
+struct ExportedTask {
+    uuid: String,
+    description: String,
+    entry: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    depends: Vec<String>,
+}
+
+// Older versions of Taskwarrior write 'depends' as a comma-separated string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Depends {
+    List(Vec<String>),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+struct ReadTask {
+    description: String,
+    uuid: Option<String>,
+    status: Option<String>,
+    entry: Option<String>,
+    end: Option<String>,
+    due: Option<String>,
+    wait: Option<String>,
+    scheduled: Option<String>,
+    priority: Option<String>,
+    project: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    depends: Option<Depends>,
+}
+
+// Taskwarrior requires a UUID, so one is made from the short ID, which keeps
+// it the same every time the task is exported.
+fn uuid(list: &TodoList, id: TaskId) -> String {
+    let short_id = list.get(id).map(|task| task.short_id.as_str());
+    let hex = format!("{:0>32}", short_id.unwrap_or_default());
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn format_date_time(date: DateTime<Utc>) -> String {
+    date.format(DATE_TIME_FORMAT).to_string()
+}
+
+fn parse_date_time(s: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(s, DATE_TIME_FORMAT)
+        .ok()
+        .map(|date_time| date_time.and_utc())
+}
+
+fn export_task(
+    list: &TodoList,
+    tasks: &TaskSet,
+    id: TaskId,
+) -> Option<ExportedTask> {
+    let task = list.get(id)?;
+    let complete = list.status(id) == Some(TaskStatus::Complete);
+    Some(ExportedTask {
+        uuid: uuid(list, id),
+        description: task.desc.to_string(),
+        entry: format_date_time(task.creation_time),
+        status: if complete { "completed" } else { "pending" },
+        end: task
+            .completion_time
+            .filter(|_| complete)
+            .map(format_date_time),
+        due: task.due_date.map(format_date_time),
+        wait: (task.start_date > task.creation_time)
+            .then(|| format_date_time(task.start_date)),
+        priority: match task.priority {
+            3.. => Some("H"),
+            2 => Some("M"),
+            1 => Some("L"),
+            _ => None,
+        },
+        tags: list
+            .adeps(id)
+            .iter_sorted(list)
+            .filter(|&adep| list.get(adep).is_some_and(|adep| adep.tag))
+            .filter_map(|tag| Some(tag_name(&list.get(tag)?.desc)))
+            .collect(),
+        depends: list
+            .deps(id)
+            .iter_sorted(list)
+            .filter(|&dep| tasks.contains(dep))
+            .filter(|&dep| list.get(dep).is_some_and(|dep| !dep.tag))
+            .map(|dep| uuid(list, dep))
+            .collect(),
+    })
+}
+
+/// Renders the given tasks as a JSON array in the format of 'task export'.
+/// Tags are not written as tasks of their own, but as tags of the tasks that
+/// block them.
+pub fn to_taskwarrior(list: &TodoList, tasks: &TaskSet) -> String {
+    let lines = tasks
+        .iter_sorted(list)
+        .filter(|&id| list.get(id).is_some_and(|task| !task.tag))
+        .filter_map(|id| export_task(list, tasks, id))
+        .map(|task| serde_json::to_string(&task).unwrap())
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+/// Reads tasks from the output of 'task export'. Deleted tasks, the templates
+/// of recurring tasks, and tasks without a description are skipped. Complete
+/// tasks without an end date are completed at |now|.
+pub fn from_taskwarrior(
+    text: &str,
+    now: DateTime<Utc>,
+) -> serde_json::Result<Vec<ImportedTask>> {
+    let read: Vec<ReadTask> = serde_json::from_str(text)?;
+    let read: Vec<_> = read
+        .into_iter()
+        .filter(|task| !task.description.trim().is_empty())
+        .filter(|task| {
+            !matches!(task.status.as_deref(), Some("deleted" | "recurring"))
+        })
+        .collect();
+    let indices: HashMap<&str, usize> = read
+        .iter()
+        .enumerate()
+        .filter_map(|(i, task)| Some((task.uuid.as_deref()?, i)))
+        .collect();
+    let date = |s: &Option<String>| s.as_deref().and_then(parse_date_time);
+    Ok(read
+        .iter()
+        .map(|task| ImportedTask {
+            desc: task.description.trim().to_string(),
+            priority: match task.priority.as_deref() {
+                Some("H") => 3,
+                Some("M") => 2,
+                Some("L") => 1,
+                _ => 0,
+            },
+            due_date: date(&task.due),
+            start_date: date(&task.wait).or(date(&task.scheduled)),
+            creation_time: date(&task.entry),
+            completion_time: (task.status.as_deref() == Some("completed"))
+                .then(|| date(&task.end).unwrap_or(now)),
+            tag: false,
+            tags: task.project.iter().chain(&task.tags).cloned().collect(),
+            deps: match &task.depends {
+                Some(Depends::List(uuids)) => {
+                    uuids.iter().map(String::as_str).collect()
+                }
+                Some(Depends::Text(uuids)) => uuids.split(',').collect(),
+                None => Vec::new(),
+            }
+            .into_iter()
+            .filter_map(|uuid| indices.get(uuid.trim()).copied())
+            .collect(),
+        })
+        .collect())
+}
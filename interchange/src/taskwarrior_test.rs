@@ -0,0 +1,233 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use chrono::NaiveDate;
+use todo_model::CheckOptions;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::from_taskwarrior;
+use crate::import;
+use crate::to_taskwarrior;
+use crate::ImportedTask;
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+fn uuid(list: &TodoList, id: TaskId) -> String {
+    format!(
+        "00000000-0000-0000-0000-00000000{}",
+        list.get(id).unwrap().short_id
+    )
+}
+
+fn utc(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    min: u32,
+) -> chrono::DateTime<chrono::Utc> {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(hour, min, 0)
+        .unwrap()
+        .and_utc()
+}
+
+#[test]
+fn export_empty_list() {
+    let list = TodoList::default();
+    assert_eq!(to_taskwarrior(&list, &all(&list)), "[]\n");
+}
+
+#[test]
+fn export_tasks() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .creation_time(utc(2021, 04, 01, 09, 00))
+            .priority(2)
+            .due_date(utc(2021, 04, 20, 17, 00))
+            .start_date(utc(2021, 04, 15, 00, 00)),
+    );
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .creation_time(utc(2021, 04, 01, 09, 00)),
+    );
+    let work = list.add(NewOptions::new().desc("work stuff").as_tag());
+    list.block(b).on(a).unwrap();
+    list.block(work).on(b).unwrap();
+    assert_eq!(
+        to_taskwarrior(&list, &all(&list)),
+        format!(
+            concat!(
+                "[\n",
+                "{{\"uuid\":\"{a}\",\"description\":\"a\",",
+                "\"entry\":\"20210401T090000Z\",\"status\":\"pending\",",
+                "\"due\":\"20210420T170000Z\",\"wait\":\"20210415T000000Z\",",
+                "\"priority\":\"M\"}},\n",
+                "{{\"uuid\":\"{b}\",\"description\":\"b\",",
+                "\"entry\":\"20210401T090000Z\",\"status\":\"pending\",",
+                "\"tags\":[\"work-stuff\"],\"depends\":[\"{a}\"]}}\n",
+                "]\n",
+            ),
+            a = uuid(&list, a),
+            b = uuid(&list, b),
+        )
+    );
+}
+
+#[test]
+fn export_complete_task() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .creation_time(utc(2021, 04, 01, 09, 00))
+            .priority(5),
+    );
+    list.check(CheckOptions {
+        id: a,
+        now: utc(2021, 04, 12, 10, 00),
+    })
+    .unwrap();
+    assert_eq!(
+        to_taskwarrior(&list, &all(&list)),
+        format!(
+            concat!(
+                "[\n",
+                "{{\"uuid\":\"{a}\",\"description\":\"a\",",
+                "\"entry\":\"20210401T090000Z\",\"status\":\"completed\",",
+                "\"end\":\"20210412T100000Z\",\"priority\":\"H\"}}\n",
+                "]\n",
+            ),
+            a = uuid(&list, a),
+        )
+    );
+}
+
+#[test]
+fn import_tasks() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let json = r#"[
+        {"uuid":"1","description":"a","status":"pending",
+         "entry":"20210401T090000Z","due":"20210420T170000Z",
+         "scheduled":"20210415T000000Z","priority":"H",
+         "project":"home","tags":["garden"],"depends":"2,3,unknown"},
+        {"uuid":"2","description":"b","status":"completed",
+         "end":"20210410T100000Z","wait":"20210402T000000Z",
+         "scheduled":"20210403T000000Z","priority":"L"},
+        {"uuid":"3","description":"c","status":"completed"},
+        {"uuid":"4","description":"d","status":"deleted"},
+        {"uuid":"5","description":"e","status":"recurring"},
+        {"uuid":"6","description":"f","depends":["1"],"urgency":2.5}
+    ]"#;
+    assert_eq!(
+        from_taskwarrior(json, now).unwrap(),
+        [
+            ImportedTask {
+                desc: "a".to_string(),
+                priority: 3,
+                due_date: Some(utc(2021, 04, 20, 17, 00)),
+                start_date: Some(utc(2021, 04, 15, 00, 00)),
+                creation_time: Some(utc(2021, 04, 01, 09, 00)),
+                tags: vec!["home".to_string(), "garden".to_string()],
+                deps: vec![1, 2],
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "b".to_string(),
+                priority: 1,
+                start_date: Some(utc(2021, 04, 02, 00, 00)),
+                completion_time: Some(utc(2021, 04, 10, 10, 00)),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "c".to_string(),
+                completion_time: Some(now),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "f".to_string(),
+                deps: vec![0],
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn import_invalid_json() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    assert!(from_taskwarrior("{", now).is_err());
+    assert!(from_taskwarrior("[{\"uuid\":\"1\"}]", now).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .creation_time(utc(2021, 04, 01, 09, 00))
+            .priority(3),
+    );
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .creation_time(utc(2021, 04, 02, 09, 00))
+            .due_date(utc(2021, 04, 20, 17, 00)),
+    );
+    let c = list.add(
+        NewOptions::new()
+            .desc("c")
+            .creation_time(utc(2021, 04, 03, 09, 00))
+            .start_date(utc(2021, 06, 01, 00, 00)),
+    );
+    let work = list.add(
+        NewOptions::new()
+            .desc("work")
+            .creation_time(utc(2021, 04, 01, 09, 00))
+            .as_tag(),
+    );
+    list.block(b).on(a).unwrap();
+    list.block(c).on(b).unwrap();
+    list.block(work).on(b).unwrap();
+    list.check(CheckOptions {
+        id: a,
+        now: utc(2021, 04, 10, 10, 00),
+    })
+    .unwrap();
+    let exported = to_taskwarrior(&list, &all(&list));
+    let mut imported = TodoList::default();
+    import(
+        &mut imported,
+        &from_taskwarrior(&exported, now).unwrap(),
+        now,
+    );
+    // Short IDs depend on the creation time and description, so the tasks
+    // get the same UUIDs again.
+    assert_eq!(to_taskwarrior(&imported, &all(&imported)), exported);
+}
+
+#[test]
+fn reimport_keeps_tags_with_spaces() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let work = list.add(NewOptions::new().desc("work stuff").as_tag());
+    list.block(work).on(a).unwrap();
+    let exported = to_taskwarrior(&list, &all(&list));
+    let created =
+        import(&mut list, &from_taskwarrior(&exported, now).unwrap(), now);
+    assert!(created.is_empty());
+    assert_eq!(list.all_tasks().count(), 2);
+}
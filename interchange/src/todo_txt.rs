@@ -0,0 +1,166 @@
+//! The todo.txt format (https://github.com/todotxt/todo.txt): one task per
+//! line, with optional completion mark, priority, and dates at the start,
+//! and projects and key:value pairs among the words of the description.
+//!
+//! Priorities (A) to (Z) are priorities 26 to 1, so that (A) is the most
+//! important. Projects (+project) are tags, with hyphens in place of spaces,
+//! due: is the due date, and t: (the threshold date) is the date the task is
+//! snoozed until. Contexts (@context) and other key:value pairs are kept as
+//! part of the description.
+
+use std::fmt::Write;
+use std::iter::Peekable;
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use todo_model::TaskId;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+
+use crate::tag_name;
+use crate::ImportedTask;
+
+fn priority_letter(priority: i32) -> Option<char> {
+    (priority > 0).then(|| (b'Z' + 1 - priority.min(26) as u8) as char)
+}
+
+fn parse_priority_letter(letter: &str) -> Option<i32> {
+    match letter.as_bytes() {
+        &[c @ b'A'..=b'Z'] => Some(i32::from(b'Z' + 1 - c)),
+        _ => None,
+    }
+}
+
+fn format_date(date: DateTime<Utc>) -> String {
+    date.with_timezone(&Local).format("%Y-%m-%d").to_string()
+}
+
+// Dates are days in the local time zone, which start at the given time.
+fn parse_date(s: &str, time: NaiveTime) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Local
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+fn start_of_day(s: &str) -> Option<DateTime<Utc>> {
+    parse_date(s, NaiveTime::MIN)
+}
+
+fn end_of_day(s: &str) -> Option<DateTime<Utc>> {
+    parse_date(s, NaiveTime::from_hms_opt(23, 59, 59).unwrap())
+}
+
+fn project(list: &TodoList, tag: TaskId) -> Option<String> {
+    Some(format!("+{}", tag_name(&list.get(tag)?.desc)))
+}
+
+fn format_line(list: &TodoList, id: TaskId) -> Option<String> {
+    let task = list.get(id)?;
+    let mut line = String::new();
+    if let Some(completion_time) = task.completion_time {
+        write!(line, "x {} ", format_date(completion_time)).unwrap();
+    } else if let Some(letter) = priority_letter(task.priority) {
+        write!(line, "({letter}) ").unwrap();
+    }
+    write!(line, "{} {}", format_date(task.creation_time), task.desc).unwrap();
+    list.adeps(id)
+        .iter_sorted(list)
+        .filter(|&adep| list.get(adep).is_some_and(|adep| adep.tag))
+        .filter_map(|tag| project(list, tag))
+        .for_each(|project| write!(line, " {project}").unwrap());
+    if let Some(due_date) = task.due_date {
+        write!(line, " due:{}", format_date(due_date)).unwrap();
+    }
+    if task.start_date > task.creation_time {
+        write!(line, " t:{}", format_date(task.start_date)).unwrap();
+    }
+    // Complete tasks keep their priority as a key:value pair, as the
+    // todo.txt spec suggests.
+    if task.completion_time.is_some() {
+        if let Some(letter) = priority_letter(task.priority) {
+            write!(line, " pri:{letter}").unwrap();
+        }
+    }
+    Some(line)
+}
+
+/// Renders the given tasks in todo.txt format, incomplete tasks first. Tags
+/// are not written as tasks of their own, but as projects of the tasks that
+/// block them. Blocks between other tasks are not written, since todo.txt
+/// has no way to express them.
+pub fn to_todo_txt(list: &TodoList, tasks: &TaskSet) -> String {
+    let (complete, incomplete) = tasks.clone().partition_done(list);
+    incomplete
+        .iter_sorted(list)
+        .chain(complete.iter_sorted(list))
+        .filter(|&id| list.get(id).is_some_and(|task| !task.tag))
+        .filter_map(|id| format_line(list, id))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+// Takes the next word if it's a date.
+fn next_date<'a>(
+    words: &mut Peekable<impl Iterator<Item = &'a str>>,
+) -> Option<DateTime<Utc>> {
+    let date = words.peek().and_then(|word| start_of_day(word))?;
+    words.next();
+    Some(date)
+}
+
+fn parse_line(line: &str, now: DateTime<Utc>) -> Option<ImportedTask> {
+    let mut words = line.split_whitespace().peekable();
+    let mut task = ImportedTask::default();
+    if words.next_if_eq(&"x").is_some() {
+        // The completion date is optional.
+        task.completion_time = Some(next_date(&mut words).unwrap_or(now));
+    }
+    if let Some(priority) = words
+        .peek()
+        .and_then(|word| word.strip_prefix('('))
+        .and_then(|word| word.strip_suffix(')'))
+        .and_then(parse_priority_letter)
+    {
+        words.next();
+        task.priority = priority;
+    }
+    task.creation_time = next_date(&mut words);
+    let mut desc = Vec::new();
+    for word in words {
+        if let Some(project) = word.strip_prefix('+').filter(|p| !p.is_empty())
+        {
+            task.tags.push(project.to_string());
+        } else if let Some(due_date) =
+            word.strip_prefix("due:").and_then(end_of_day)
+        {
+            task.due_date = Some(due_date);
+        } else if let Some(start_date) =
+            word.strip_prefix("t:").and_then(start_of_day)
+        {
+            task.start_date = Some(start_date);
+        } else if let Some(priority) =
+            word.strip_prefix("pri:").and_then(parse_priority_letter)
+        {
+            task.priority = priority;
+        } else {
+            desc.push(word);
+        }
+    }
+    task.desc = desc.join(" ");
+    (!task.desc.is_empty()).then_some(task)
+}
+
+/// Reads tasks from todo.txt text. Complete tasks without a completion date
+/// are completed at |now|. Blank lines, and lines with nothing but projects
+/// and key:value pairs, are skipped.
+pub fn from_todo_txt(text: &str, now: DateTime<Utc>) -> Vec<ImportedTask> {
+    text.lines()
+        .filter_map(|line| parse_line(line, now))
+        .collect()
+}
@@ -0,0 +1,201 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_model::CheckOptions;
+use todo_model::NewOptions;
+use todo_model::TaskSet;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::from_todo_txt;
+use crate::import;
+use crate::to_todo_txt;
+use crate::ImportedTask;
+
+fn all(list: &TodoList) -> TaskSet {
+    list.all_tasks().collect()
+}
+
+fn created(desc: &str) -> NewOptions<'_> {
+    NewOptions::new()
+        .desc(desc)
+        .creation_time(ymdhms(2021, 04, 01, 09, 00, 00))
+}
+
+#[test]
+fn export_empty_list() {
+    let list = TodoList::default();
+    assert_eq!(to_todo_txt(&list, &all(&list)), "");
+}
+
+#[test]
+fn export_incomplete_tasks_in_order() {
+    let mut list = TodoList::default();
+    list.add(created("a"));
+    list.add(created("b").priority(26));
+    list.add(created("c").priority(2));
+    assert_eq!(
+        to_todo_txt(&list, &all(&list)),
+        "(A) 2021-04-01 b\n(Y) 2021-04-01 c\n2021-04-01 a\n"
+    );
+}
+
+#[test]
+fn export_priority_beyond_z() {
+    let mut list = TodoList::default();
+    list.add(created("a").priority(100));
+    list.add(created("b").priority(-1));
+    assert_eq!(
+        to_todo_txt(&list, &all(&list)),
+        "(A) 2021-04-01 a\n2021-04-01 b\n"
+    );
+}
+
+#[test]
+fn export_dates() {
+    let mut list = TodoList::default();
+    list.add(
+        created("a")
+            .due_date(ymdhms(2021, 04, 20, 23, 59, 59))
+            .start_date(ymdhms(2021, 04, 15, 00, 00, 00)),
+    );
+    assert_eq!(
+        to_todo_txt(&list, &all(&list)),
+        "2021-04-01 a due:2021-04-20 t:2021-04-15\n"
+    );
+}
+
+#[test]
+fn export_complete_tasks_last() {
+    let mut list = TodoList::default();
+    let a = list.add(created("a").priority(25));
+    list.add(created("b"));
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2021, 04, 12, 10, 00, 00),
+    })
+    .unwrap();
+    assert_eq!(
+        to_todo_txt(&list, &all(&list)),
+        "2021-04-01 b\nx 2021-04-12 2021-04-01 a pri:B\n"
+    );
+}
+
+#[test]
+fn export_tags_as_projects() {
+    let mut list = TodoList::default();
+    let a = list.add(created("a"));
+    let work = list.add(created("work stuff").as_tag());
+    let home = list.add(created("home").as_tag());
+    list.block(work).on(a).unwrap();
+    list.block(home).on(a).unwrap();
+    assert_eq!(
+        to_todo_txt(&list, &all(&list)),
+        "2021-04-01 a +work-stuff +home\n"
+    );
+}
+
+#[test]
+fn parse_plain_lines() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    assert_eq!(
+        from_todo_txt("call mom\n\n  \nbuy  milk @store\n", now),
+        [
+            ImportedTask {
+                desc: "call mom".to_string(),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "buy milk @store".to_string(),
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_full_line() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    assert_eq!(
+        from_todo_txt(
+            "(A) 2021-04-01 call mom +family due:2021-04-20 t:2021-04-15",
+            now
+        ),
+        [ImportedTask {
+            desc: "call mom".to_string(),
+            priority: 26,
+            due_date: Some(ymdhms(2021, 04, 20, 23, 59, 59)),
+            start_date: Some(ymdhms(2021, 04, 15, 00, 00, 00)),
+            creation_time: Some(ymdhms(2021, 04, 01, 00, 00, 00)),
+            tags: vec!["family".to_string()],
+            ..Default::default()
+        }]
+    );
+}
+
+#[test]
+fn parse_complete_lines() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    assert_eq!(
+        from_todo_txt(
+            "x 2021-04-12 2021-04-01 a pri:C\nx b\nxylophone lessons",
+            now
+        ),
+        [
+            ImportedTask {
+                desc: "a".to_string(),
+                priority: 24,
+                creation_time: Some(ymdhms(2021, 04, 01, 00, 00, 00)),
+                completion_time: Some(ymdhms(2021, 04, 12, 00, 00, 00)),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "b".to_string(),
+                completion_time: Some(now),
+                ..Default::default()
+            },
+            ImportedTask {
+                desc: "xylophone lessons".to_string(),
+                ..Default::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_keeps_malformed_fields_in_description() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    assert_eq!(
+        from_todo_txt("(a) due:soon + pri:1 t:", now),
+        [ImportedTask {
+            desc: "(a) due:soon + pri:1 t:".to_string(),
+            ..Default::default()
+        }]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let text = concat!(
+        "(A) 2021-04-01 a +work due:2021-04-20\n",
+        "2021-04-02 b @home t:2021-06-01\n",
+        "x 2021-04-12 2021-04-01 c +work pri:B\n",
+    );
+    let mut list = TodoList::default();
+    import(&mut list, &from_todo_txt(text, now), now);
+    assert_eq!(to_todo_txt(&list, &all(&list)), text);
+}
+
+#[test]
+fn reimport_keeps_tags_with_spaces() {
+    let now = ymdhms(2021, 05, 01, 12, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(created("a"));
+    let work = list.add(created("work stuff").as_tag());
+    list.block(work).on(a).unwrap();
+    let text = to_todo_txt(&list, &all(&list));
+    let created = import(&mut list, &from_todo_txt(&text, now), now);
+    assert!(created.is_empty());
+    assert_eq!(to_todo_txt(&list, &all(&list)), text);
+}
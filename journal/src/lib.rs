@@ -0,0 +1,124 @@
+use std::io::Write;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use thiserror::Error;
+use todo_model::TodoList;
+
+/// A snapshot of the list, along with the command that moves the list away
+/// from (in the undo stack) or back to (in the redo stack) that snapshot.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JournalEntry<'ser> {
+    pub command: String,
+    pub time: DateTime<Utc>,
+    #[serde(borrow)]
+    pub list: TodoList<'ser>,
+}
+
+/// A bounded history of snapshots of the list, used to implement undo and
+/// redo.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Journal<'ser> {
+    // Both stacks are ordered from oldest to most recent.
+    #[serde(borrow)]
+    undo: Vec<JournalEntry<'ser>>,
+    #[serde(borrow)]
+    redo: Vec<JournalEntry<'ser>>,
+    // Whether undo() or redo() has been called since the last record().
+    #[serde(skip)]
+    touched: bool,
+}
+
+impl<'ser> Journal<'ser> {
+    /// Records the state of the list before the given command was run, and
+    /// forgets the oldest entries if there are more than |limit|. Recording a
+    /// new entry clears the redo stack.
+    ///
+    /// If undo() or redo() was called since the last call to record(), then
+    /// the change to the list was made by the journal itself, so nothing is
+    /// recorded.
+    pub fn record(
+        &mut self,
+        command: String,
+        time: DateTime<Utc>,
+        before: TodoList<'ser>,
+        limit: usize,
+    ) {
+        if std::mem::take(&mut self.touched) {
+            return;
+        }
+        self.undo.push(JournalEntry {
+            command,
+            time,
+            list: before,
+        });
+        if self.undo.len() > limit {
+            self.undo.drain(..self.undo.len() - limit);
+        }
+        self.redo.clear();
+    }
+
+    /// Replaces the list with the snapshot taken before the most recent
+    /// command, and returns the command that was undone. Returns None if there
+    /// is nothing to undo.
+    pub fn undo(&mut self, list: &mut TodoList<'ser>) -> Option<String> {
+        let entry = self.undo.pop()?;
+        self.touched = true;
+        let command = entry.command.clone();
+        self.redo.push(JournalEntry {
+            list: std::mem::replace(list, entry.list),
+            ..entry
+        });
+        Some(command)
+    }
+
+    /// Reapplies the most recently undone command, and returns it. Returns
+    /// None if there is nothing to redo.
+    pub fn redo(&mut self, list: &mut TodoList<'ser>) -> Option<String> {
+        let entry = self.redo.pop()?;
+        self.touched = true;
+        let command = entry.command.clone();
+        self.undo.push(JournalEntry {
+            list: std::mem::replace(list, entry.list),
+            ..entry
+        });
+        Some(command)
+    }
+
+    /// Iterates over the undoable commands, most recent first.
+    pub fn undo_entries(&self) -> impl Iterator<Item = &JournalEntry<'ser>> {
+        self.undo.iter().rev()
+    }
+
+    /// Iterates over the redoable commands, most recently undone first.
+    pub fn redo_entries(&self) -> impl Iterator<Item = &JournalEntry<'ser>> {
+        self.redo.iter().rev()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("Deserialize error")]
+    DeserializeError(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("IO error")]
+    IoError(#[from] std::io::Error),
+    #[error("Serialize error")]
+    SerializeError(#[from] serde_json::Error),
+}
+
+pub fn load(s: &str) -> Result<Journal<'_>, LoadError> {
+    Ok(serde_json::from_str(s)?)
+}
+
+pub fn save<W: Write>(writer: W, journal: &Journal) -> Result<(), SaveError> {
+    Ok(serde_json::to_writer(writer, journal)?)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,116 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::TimeZone;
+use chrono::Utc;
+use todo_model::TodoList;
+
+use crate::*;
+
+fn descs(list: &TodoList) -> Vec<String> {
+    list.all_tasks()
+        .map(|id| list.get(id).unwrap().desc.to_string())
+        .collect()
+}
+
+fn record_new<'a>(
+    journal: &mut Journal<'a>,
+    list: &mut TodoList<'a>,
+    desc: &'static str,
+) {
+    let before = list.clone();
+    list.add(desc);
+    journal.record(
+        format!("todo new {desc}"),
+        Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap(),
+        before,
+        3,
+    );
+}
+
+#[test]
+fn undo_empty_journal() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    assert_eq!(journal.undo(&mut list), None);
+}
+
+#[test]
+fn undo_restores_previous_list() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    record_new(&mut journal, &mut list, "a");
+    record_new(&mut journal, &mut list, "b");
+    assert_eq!(journal.undo(&mut list), Some("todo new b".to_string()));
+    assert_eq!(descs(&list), vec!["a"]);
+    assert_eq!(journal.undo(&mut list), Some("todo new a".to_string()));
+    assert_eq!(descs(&list), Vec::<String>::new());
+    assert_eq!(journal.undo(&mut list), None);
+}
+
+#[test]
+fn redo_reapplies_undone_command() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    record_new(&mut journal, &mut list, "a");
+    record_new(&mut journal, &mut list, "b");
+    journal.undo(&mut list);
+    journal.undo(&mut list);
+    assert_eq!(journal.redo(&mut list), Some("todo new a".to_string()));
+    assert_eq!(descs(&list), vec!["a"]);
+    assert_eq!(journal.redo(&mut list), Some("todo new b".to_string()));
+    assert_eq!(descs(&list), vec!["a", "b"]);
+    assert_eq!(journal.redo(&mut list), None);
+}
+
+#[test]
+fn record_clears_redo_stack() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    record_new(&mut journal, &mut list, "a");
+    let before = list.clone();
+    journal.undo(&mut list);
+    journal.record("todo undo".to_string(), Utc::now(), before, 3);
+    record_new(&mut journal, &mut list, "b");
+    assert_eq!(journal.redo(&mut list), None);
+    assert_eq!(descs(&list), vec!["b"]);
+}
+
+#[test]
+fn record_after_undo_is_ignored() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    record_new(&mut journal, &mut list, "a");
+    let before = list.clone();
+    journal.undo(&mut list);
+    journal.record("todo undo".to_string(), Utc::now(), before, 3);
+    assert_eq!(journal.undo_entries().count(), 0);
+    assert_eq!(journal.redo(&mut list), Some("todo new a".to_string()));
+}
+
+#[test]
+fn record_forgets_oldest_entries_past_limit() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    for desc in ["a", "b", "c", "d", "e"] {
+        record_new(&mut journal, &mut list, desc);
+    }
+    let commands = journal
+        .undo_entries()
+        .map(|entry| entry.command.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(commands, vec!["todo new e", "todo new d", "todo new c"]);
+}
+
+#[test]
+fn save_and_load_round_trip() {
+    let mut journal = Journal::default();
+    let mut list = TodoList::default();
+    record_new(&mut journal, &mut list, "a");
+    record_new(&mut journal, &mut list, "b");
+    let mut out = Vec::new();
+    save(&mut out, &journal).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let mut loaded = load(&text).unwrap();
+    assert_eq!(loaded.undo(&mut list), Some("todo new b".to_string()));
+    assert_eq!(descs(&list), vec!["a"]);
+}
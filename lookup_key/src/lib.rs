@@ -6,6 +6,8 @@ pub enum Key {
     ByNumber(i32),
     ByName(String),
     ByRange(i32, i32),
+    /// A task's short ID, written with a leading '#', e.g. "#a3f9".
+    ById(String),
 }
 
 impl FromStr for Key {
@@ -14,6 +16,11 @@ impl FromStr for Key {
         if let Ok(n) = s.parse::<i32>() {
             return Ok(Key::ByNumber(n));
         }
+        if let Some(id) = s.strip_prefix('#') {
+            if !id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Ok(Key::ById(id.to_string()));
+            }
+        }
         if let Some((prefix, suffix)) = s.split_once("..") {
             let prefix = prefix.strip_prefix('[').unwrap_or(prefix);
             let suffix = suffix.strip_suffix(']').unwrap_or(suffix);
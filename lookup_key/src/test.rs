@@ -47,3 +47,23 @@ fn parse_key_from_backwards_range() {
 fn parse_key_from_redundant_range() {
     assert_eq!(Ok(Key::ByRange(1, 1)), Key::from_str("1..1"));
 }
+
+#[test]
+fn parse_key_from_id() {
+    assert_eq!(Ok(ById("a3f9".to_string())), Key::from_str("#a3f9"));
+}
+
+#[test]
+fn parse_key_from_id_keeps_case() {
+    assert_eq!(Ok(ById("A3F9".to_string())), Key::from_str("#A3F9"));
+}
+
+#[test]
+fn parse_key_from_hash_without_id() {
+    assert_eq!(Ok(ByName("#".to_string())), Key::from_str("#"));
+}
+
+#[test]
+fn parse_key_from_hash_with_non_hex_name() {
+    assert_eq!(Ok(ByName("#home".to_string())), Key::from_str("#home"));
+}
@@ -1,7 +1,21 @@
+use clap::CommandFactory;
 use clap::Parser;
+use todo_builtin_apps::App;
 use todo_cli::Options;
+use todo_cli::SubCommand;
 use todo_runner::TodoResult;
 
 fn main() -> TodoResult {
-    todo_runner::run(todo_builtin_apps::App::new(Options::parse()))
+    let options = Options::parse();
+    if let Some(SubCommand::Shell(_)) = options.cmd {
+        let commands = Options::command()
+            .get_subcommands()
+            .filter(|cmd| !cmd.is_hide_set())
+            .map(|cmd| cmd.get_name().to_string())
+            .collect::<Vec<_>>();
+        return todo_runner::run_shell(&commands, options.dry_run, |args| {
+            Options::try_parse_from(args).map(App::new)
+        });
+    }
+    todo_runner::run(App::new(options))
 }
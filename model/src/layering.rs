@@ -11,7 +11,7 @@ pub fn remove_first_occurrence_from_vec<T: PartialEq>(
     vec.iter().position(|x| x == data).map(|i| vec.remove(i))
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Layering<T: Copy + Eq + Hash> {
     layers: Vec<Vec<T>>,
     depth: HashMap<T, usize>,
@@ -1,5 +1,7 @@
 mod duration;
 mod layering;
+mod schedule;
+mod short_id;
 mod task;
 mod task_id;
 mod task_set;
@@ -7,12 +9,16 @@ mod task_status;
 mod todo_list;
 
 pub use self::duration::*;
+pub use self::schedule::*;
 pub use self::task::*;
 pub use self::task_id::*;
 pub use self::task_set::*;
 pub use self::task_status::*;
 pub use self::todo_list::*;
 
+#[cfg(test)]
+mod short_id_test;
+
 #[cfg(test)]
 mod task_test;
 
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+
+use crate::Task;
+use crate::TaskId;
+use crate::TodoList;
+
+/// When an incomplete task can be done, assuming each task takes the rest
+/// of its budget and is started as soon as everything it's blocked on is
+/// done.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledTask {
+    /// The earliest the task can be started: now, when it's unsnoozed, or
+    /// when its last incomplete dep can be done, whichever is latest.
+    pub earliest_start: DateTime<Utc>,
+    /// The earliest the task can be done.
+    pub earliest_finish: DateTime<Utc>,
+    /// The latest the task can be done without making it or any of its
+    /// transitive adeps miss their due dates, or None if none of them have
+    /// due dates. This is like the implicit due date, but only counts the
+    /// part of each adep's budget that hasn't been spent yet.
+    pub latest_finish: Option<DateTime<Utc>>,
+}
+
+impl ScheduledTask {
+    /// How much the task can be delayed without missing a due date. This is
+    /// negative if a due date can no longer be met.
+    pub fn slack(&self) -> Option<Duration> {
+        self.latest_finish
+            .map(|latest_finish| latest_finish - self.earliest_finish)
+    }
+}
+
+// The part of the task's budget that hasn't been spent yet.
+fn remaining_work(task: &Task, now: DateTime<Utc>) -> Duration {
+    (Duration::seconds(task.budget.0.into()) - task.time_spent(now))
+        .max(Duration::zero())
+}
+
+impl TodoList<'_> {
+    /// Schedules all incomplete tasks, like the critical path method: a
+    /// forward pass over the deps finds when each task can be done at the
+    /// earliest, and a backward pass over the adeps finds when it must be
+    /// done at the latest.
+    pub fn schedule(
+        &self,
+        now: DateTime<Utc>,
+    ) -> HashMap<TaskId, ScheduledTask> {
+        // Deps are always in shallower layers than their adeps, so this is a
+        // topological order.
+        let order: Vec<_> = self.incomplete_tasks().collect();
+        let mut schedule: HashMap<TaskId, ScheduledTask> = HashMap::new();
+        for &id in &order {
+            let task = self.get(id).unwrap();
+            let earliest_start = self
+                .deps(id)
+                .iter_unsorted()
+                .filter_map(|dep| schedule.get(&dep))
+                .map(|dep| dep.earliest_finish)
+                .chain([now, task.start_date])
+                .max()
+                .unwrap();
+            schedule.insert(
+                id,
+                ScheduledTask {
+                    earliest_start,
+                    earliest_finish: earliest_start + remaining_work(task, now),
+                    latest_finish: None,
+                },
+            );
+        }
+        for &id in order.iter().rev() {
+            let latest_finish = self
+                .adeps(id)
+                .iter_unsorted()
+                .filter_map(|adep| {
+                    let latest_finish = schedule.get(&adep)?.latest_finish?;
+                    let adep = self.get(adep)?;
+                    Some(latest_finish - remaining_work(adep, now))
+                })
+                .chain(self.get(id).unwrap().due_date)
+                .min();
+            if let Some(scheduled) = schedule.get_mut(&id) {
+                scheduled.latest_finish = latest_finish;
+            }
+        }
+        schedule
+    }
+}
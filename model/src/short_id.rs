@@ -0,0 +1,50 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+// Short IDs start out this long, and only get longer if every attempt at this
+// length collides with an existing ID.
+const MIN_LEN: usize = 4;
+const MAX_LEN: usize = 16;
+const ATTEMPTS_PER_LEN: u64 = 8;
+
+// FNV-1a followed by the splitmix64 finalizer, so that the leading hex digits
+// are well distributed. Unlike std's hasher, this is guaranteed to give the
+// same result on every platform and Rust version, so IDs assigned to old lists
+// on the fly are the same every time until they're saved.
+fn hash(creation_time: DateTime<Utc>, desc: &str, attempt: u64) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    let bytes = creation_time
+        .timestamp()
+        .to_le_bytes()
+        .into_iter()
+        .chain(creation_time.timestamp_subsec_nanos().to_le_bytes())
+        .chain(desc.bytes())
+        .chain(attempt.to_le_bytes());
+    for byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58476d1ce4e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d049bb133111eb);
+    hash ^ (hash >> 31)
+}
+
+/// Generates a short hexadecimal ID for a task with the given creation time
+/// and description for which |is_taken| returns false. The result depends
+/// only on the arguments.
+pub(crate) fn generate_short_id(
+    creation_time: DateTime<Utc>,
+    desc: &str,
+    is_taken: impl Fn(&str) -> bool,
+) -> String {
+    (MIN_LEN..=MAX_LEN)
+        .flat_map(|len| (0..ATTEMPTS_PER_LEN).map(move |i| (len, i)))
+        .map(|(len, attempt)| {
+            let hash = hash(creation_time, desc, attempt);
+            format!("{hash:016x}")[..len].to_string()
+        })
+        .find(|id| !is_taken(id))
+        .expect("Ran out of short IDs")
+}
@@ -0,0 +1,57 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+
+use crate::short_id::generate_short_id;
+
+#[test]
+fn short_id_is_four_hex_digits() {
+    let time = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    let short_id = generate_short_id(time, "a", |_| false);
+    assert_eq!(short_id.len(), 4);
+    assert!(short_id.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn short_id_is_deterministic() {
+    let time = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    assert_eq!(
+        generate_short_id(time, "a", |_| false),
+        generate_short_id(time, "a", |_| false)
+    );
+}
+
+#[test]
+fn short_id_depends_on_desc_and_creation_time() {
+    let time = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    let later = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 01).unwrap();
+    let a = generate_short_id(time, "a", |_| false);
+    assert_ne!(a, generate_short_id(time, "b", |_| false));
+    assert_ne!(a, generate_short_id(later, "a", |_| false));
+}
+
+#[test]
+fn short_id_avoids_taken_ids() {
+    let time = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    let mut taken = HashSet::new();
+    for _ in 0..100 {
+        let short_id = generate_short_id(time, "a", |id| taken.contains(id));
+        assert!(!taken.contains(&short_id));
+        taken.insert(short_id);
+    }
+}
+
+#[test]
+fn short_id_gets_longer_when_short_ids_are_taken() {
+    let time = Utc.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    let taken = (0..=0xffff)
+        .map(|n| format!("{n:04x}"))
+        .collect::<HashSet<_>>();
+    assert_eq!(
+        generate_short_id(time, "a", |id| taken.contains(id)).len(),
+        5
+    );
+}
@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 
 use chrono::DateTime;
+use chrono::Duration;
 use chrono::Utc;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
+use todo_time_format::Recurrence;
 
 use crate::DurationInSeconds;
 use crate::TaskId;
@@ -13,6 +15,13 @@ fn default_creation_time() -> DateTime<Utc> {
     Utc::now()
 }
 
+/// A stretch of time spent working on a task, recorded by a timer.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct WorkInterval {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
 // NOTE: all new fields need to be Options or be marked #[serde(default)] to
 // allow backwards compatibility.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -46,6 +55,26 @@ pub struct Task<'ser> {
     // Cache of all the tags that depend on this task.
     #[serde(default)]
     pub implicit_tags: Vec<TaskId>,
+    // If set, checking this task creates the next instance of it.
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+    // A short identifier that, unlike the task's position, never changes.
+    // Assigned by the TodoList when the task is added, or when a list saved
+    // before short IDs existed is loaded.
+    #[serde(default)]
+    pub short_id: String,
+    // Free-form text about the task that doesn't fit in the description,
+    // like links, ticket numbers, or meeting notes. May span many lines.
+    #[serde(default)]
+    pub notes: String,
+    // The times that were spent working on the task, oldest first, not
+    // counting the running timer.
+    #[serde(default)]
+    pub work_log: Vec<WorkInterval>,
+    // When the timer on this task was started, if it's running. At most one
+    // task in the list has a running timer.
+    #[serde(default)]
+    pub timer_start: Option<DateTime<Utc>>,
 }
 
 pub struct NewOptions<'ser> {
@@ -56,6 +85,7 @@ pub struct NewOptions<'ser> {
     pub budget: DurationInSeconds,
     pub start_date: Option<DateTime<Utc>>,
     pub tag: bool,
+    pub recurrence: Option<Recurrence>,
 }
 
 impl<'ser> NewOptions<'ser> {
@@ -70,6 +100,7 @@ impl<'ser> NewOptions<'ser> {
             budget: DurationInSeconds::default(),
             start_date: None,
             tag: false,
+            recurrence: None,
         }
     }
 
@@ -107,6 +138,11 @@ impl<'ser> NewOptions<'ser> {
         self.tag = true;
         self
     }
+
+    pub fn recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
 }
 
 impl<'ser, S: Into<Cow<'ser, str>>> From<S> for NewOptions<'ser> {
@@ -120,6 +156,7 @@ impl<'ser, S: Into<Cow<'ser, str>>> From<S> for NewOptions<'ser> {
             budget: DurationInSeconds::default(),
             start_date: None,
             tag: false,
+            recurrence: None,
         }
     }
 }
@@ -142,9 +179,56 @@ impl<'ser> Task<'ser> {
             tag: options.tag,
             implicit_tags: vec![],
             cached_status: None,
+            recurrence: options.recurrence,
+            short_id: String::new(),
+            notes: String::new(),
+            work_log: vec![],
+            timer_start: None,
+        }
+    }
+
+    /// Copies the description if it's borrowed, so that the task no longer
+    /// depends on the text it was deserialized from.
+    pub fn into_owned<'a>(self) -> Task<'a> {
+        Task {
+            desc: Cow::Owned(self.desc.into_owned()),
+            creation_time: self.creation_time,
+            completion_time: self.completion_time,
+            cached_status: self.cached_status,
+            priority: self.priority,
+            implicit_priority: self.implicit_priority,
+            due_date: self.due_date,
+            implicit_due_date: self.implicit_due_date,
+            budget: self.budget,
+            start_date: self.start_date,
+            tag: self.tag,
+            implicit_tags: self.implicit_tags,
+            recurrence: self.recurrence,
+            short_id: self.short_id,
+            notes: self.notes,
+            work_log: self.work_log,
+            timer_start: self.timer_start,
         }
     }
 
+    /// The total time recorded in the work log, not counting the running
+    /// timer.
+    pub fn logged_time(&self) -> Duration {
+        self.work_log
+            .iter()
+            .map(|interval| interval.end - interval.start)
+            .sum()
+    }
+
+    /// The time spent on the task up to |now|, including the running timer.
+    pub fn time_spent(&self, now: DateTime<Utc>) -> Duration {
+        self.logged_time()
+            + self
+                .timer_start
+                .map(|start| (now - start).max(Duration::zero()))
+                .unwrap_or_else(Duration::zero)
+    }
+
     pub(crate) fn is_snoozed(&self) -> bool {
         self.start_date > self.creation_time
     }
@@ -23,4 +23,7 @@ fn deserialize_task_with_missing_creation_time() {
     assert_eq!(task.budget, DurationInSeconds::default());
     assert!(!task.tag);
     assert_eq!(task.implicit_tags, vec![]);
+    assert_eq!(task.notes, "");
+    assert_eq!(task.work_log, vec![]);
+    assert_eq!(task.timer_start, None);
 }
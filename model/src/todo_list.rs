@@ -1,30 +1,72 @@
 use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use chrono::DateTime;
 use chrono::Duration;
+use chrono::Local;
+use chrono::TimeZone;
 use chrono::Utc;
 use daggy::stable_dag::StableDag;
 use daggy::Walker;
+use serde::Deserialize;
+use serde::Deserializer;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 use thiserror::Error;
 
 use crate::layering::remove_first_occurrence_from_vec;
 use crate::layering::Layering;
+use crate::short_id::generate_short_id;
 use crate::DurationInSeconds;
 use crate::NewOptions;
 use crate::Task;
 use crate::TaskId;
 use crate::TaskSet;
 use crate::TaskStatus;
+use crate::WorkInterval;
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct TodoList<'ser> {
-    #[serde(borrow)]
+    #[serde(borrow, deserialize_with = "deserialize_tasks")]
     tasks: StableDag<Task<'ser>, ()>,
     complete: Vec<TaskId>,
     incomplete: Layering<TaskId>,
+    // Finds tasks by short ID. Built the first time it's needed, and kept up
+    // to date as tasks are added and removed.
+    #[serde(skip)]
+    short_ids: OnceCell<HashMap<String, TaskId>>,
+}
+
+// Gives every task that doesn't have a short ID one, in order of creation.
+fn assign_missing_short_ids(tasks: &mut StableDag<Task, ()>) {
+    let mut taken = tasks
+        .graph()
+        .node_weights()
+        .map(|task| task.short_id.clone())
+        .filter(|short_id| !short_id.is_empty())
+        .collect::<HashSet<_>>();
+    let indices = tasks.graph().node_indices().collect::<Vec<_>>();
+    for index in indices {
+        let task = &mut tasks[index];
+        if task.short_id.is_empty() {
+            task.short_id =
+                generate_short_id(task.creation_time, &task.desc, |id| {
+                    taken.contains(id)
+                });
+            taken.insert(task.short_id.clone());
+        }
+    }
+}
+
+// Lists saved before tasks had short IDs get them when they're loaded.
+fn deserialize_tasks<'de: 'ser, 'ser, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<StableDag<Task<'ser>, ()>, D::Error> {
+    let mut tasks = StableDag::deserialize(deserializer)?;
+    assign_missing_short_ids(&mut tasks);
+    Ok(tasks)
 }
 
 impl TodoList<'_> {
@@ -309,14 +351,45 @@ impl TodoList<'_> {
 }
 
 impl<'ser> TodoList<'ser> {
+    /// Copies any borrowed task descriptions, so that the list no longer
+    /// depends on the text it was deserialized from.
+    pub fn into_owned<'a>(self) -> TodoList<'a> {
+        TodoList {
+            tasks: self
+                .tasks
+                .map(|_, task| task.clone().into_owned(), |_, &()| ()),
+            complete: self.complete,
+            incomplete: self.incomplete,
+            short_ids: self.short_ids,
+        }
+    }
+
     pub fn add<T: Into<NewOptions<'ser>>>(&mut self, task: T) -> TaskId {
-        let task = Task::new(task.into());
-        let id = TaskId(self.tasks.add_node(task));
+        let id = TaskId(self.tasks.add_node(Task::new(task.into())));
+        // Build the index, if it hasn't been built yet, before adding to it.
+        self.short_id_index();
+        let short_ids = self.short_ids.get_mut().unwrap();
+        let task = &mut self.tasks[id.0];
+        task.short_id =
+            generate_short_id(task.creation_time, &task.desc, |short_id| {
+                short_ids.contains_key(short_id)
+            });
+        short_ids.insert(task.short_id.clone(), id);
         self.put_in_incomplete_layer(id, 0);
         id
     }
 }
 
+fn start_of_day(datetime: &DateTime<Local>) -> DateTime<Utc> {
+    Local
+        .from_local_datetime(
+            &datetime.date_naive().and_hms_opt(0, 0, 0).unwrap(),
+        )
+        .earliest()
+        .unwrap_or(*datetime)
+        .with_timezone(&Utc)
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct ForceChecked {
     pub completed: TaskSet,
@@ -365,7 +438,9 @@ impl TodoList<'_> {
         if !incomplete_deps.is_empty() {
             return Err(CheckError::TaskIsBlockedBy(incomplete_deps));
         }
+        self.stop_timer_on(options.id, options.now);
         let task = &mut self.tasks[options.id.0];
+        let start_date = task.start_date;
         task.completion_time = Some(options.now);
         // It's legal to complete a task that's snoozed, but reset the snoozed
         // date to the task's creation time.
@@ -377,6 +452,7 @@ impl TodoList<'_> {
             assert!(depth == 0);
             self.incomplete.remove_from_layer(&options.id, depth);
             self.complete.push(options.id);
+            self.respawn(options.id, start_date, options.now);
             // Update adeps.
             return Ok(self
                 .adeps(options.id)
@@ -401,6 +477,50 @@ impl TodoList<'_> {
         panic!("Checked task didn't have a depth.");
     }
 
+    /// If the task recurs, adds its next instance with the same deps, adeps,
+    /// and properties, snoozed until the first occurrence that starts after
+    /// |now|. The due date is moved forward by the same amount as the start
+    /// date. The recurrence moves to the new instance, so restoring and
+    /// checking the old instance again will not spawn a duplicate.
+    fn respawn(
+        &mut self,
+        id: TaskId,
+        start_date: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Option<TaskId> {
+        let task = self.tasks.node_weight_mut(id.0)?;
+        let recurrence = task.recurrence.take()?;
+        let mut next = recurrence.next_after(start_date.with_timezone(&Local));
+        while start_of_day(&next) <= now {
+            next = recurrence.next_after(next);
+        }
+        let shift = next.with_timezone(&Utc) - start_date;
+        let options = NewOptions {
+            desc: task.desc.clone(),
+            now,
+            priority: task.priority,
+            due_date: task.due_date.map(|due_date| due_date + shift),
+            budget: task.budget,
+            start_date: Some(start_of_day(&next)),
+            tag: task.tag,
+            recurrence: Some(recurrence),
+        };
+        let deps = self.deps(id).iter_sorted(self).collect::<Vec<_>>();
+        let adeps = self.adeps(id).iter_sorted(self).collect::<Vec<_>>();
+        let respawned = self.add(options);
+        deps.into_iter().for_each(|dep| {
+            self.block(respawned)
+                .on(dep)
+                .expect("respawned task is new");
+        });
+        adeps.into_iter().for_each(|adep| {
+            self.block(adep)
+                .on(respawned)
+                .expect("respawned task is new");
+        });
+        Some(respawned)
+    }
+
     pub fn force_check<Options: Into<CheckOptions>>(
         &mut self,
         options: Options,
@@ -673,7 +793,7 @@ impl TodoList<'_> {
 }
 
 impl<'ser> TodoList<'ser> {
-    pub fn get(&self, id: TaskId) -> Option<&Task> {
+    pub fn get(&self, id: TaskId) -> Option<&Task<'_>> {
         self.tasks.node_weight(id.0)
     }
 
@@ -705,6 +825,20 @@ impl<'ser> TodoList<'ser> {
             .unwrap_or_default()
     }
 
+    pub fn set_notes<S: Into<String>>(
+        &mut self,
+        id: TaskId,
+        notes: S,
+    ) -> TaskSet {
+        match self.tasks.node_weight_mut(id.0) {
+            Some(task) => {
+                task.notes = notes.into();
+                TaskSet::of(id)
+            }
+            None => TaskSet::default(),
+        }
+    }
+
     pub fn set_priority(&mut self, id: TaskId, priority: i32) -> TaskSet {
         match self.tasks.node_weight_mut(id.0) {
             Some(task) => {
@@ -804,6 +938,26 @@ impl<'ser> TodoList<'ser> {
         }
     }
 
+    // Short IDs are lowercase hex, so they're indexed as they are.
+    fn short_id_index(&self) -> &HashMap<String, TaskId> {
+        self.short_ids.get_or_init(|| {
+            self.tasks
+                .graph()
+                .node_indices()
+                .map(|index| {
+                    (self.tasks[index].short_id.clone(), TaskId(index))
+                })
+                .collect()
+        })
+    }
+
+    /// Finds the task with the given short ID, ignoring case.
+    pub fn lookup_by_short_id(&self, short_id: &str) -> Option<TaskId> {
+        self.short_id_index()
+            .get(&short_id.to_ascii_lowercase())
+            .copied()
+    }
+
     pub fn incomplete_tasks(&self) -> impl Iterator<Item = TaskId> + '_ {
         self.incomplete.iter().copied()
     }
@@ -867,7 +1021,11 @@ impl<'ser> TodoList<'ser> {
         } else if self.complete.contains(&id) {
             remove_first_occurrence_from_vec(&mut self.complete, &id);
         };
-        self.tasks.remove_node(id.0);
+        if let Some(task) = self.tasks.remove_node(id.0) {
+            if let Some(short_ids) = self.short_ids.get_mut() {
+                short_ids.remove(&task.short_id);
+            }
+        }
         adeps.iter_sorted(self).for_each(|adep| {
             self.update_depth(adep, None);
         });
@@ -972,6 +1130,91 @@ impl TodoList<'_> {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum StartTimerError {
+    #[error("cannot time complete task")]
+    TaskIsComplete,
+    #[error("timer is already running on task")]
+    AlreadyRunning,
+}
+
+impl TodoList<'_> {
+    /// The task whose timer is running, if any.
+    pub fn running_timer(&self) -> Option<TaskId> {
+        self.all_tasks().find(|&id| {
+            self.get(id).is_some_and(|task| task.timer_start.is_some())
+        })
+    }
+
+    /// Starts timing the given task at |now|. Only one timer runs at a time,
+    /// so if another task was being timed, its timer is stopped, and that
+    /// task is returned.
+    pub fn start_timer(
+        &mut self,
+        id: TaskId,
+        now: DateTime<Utc>,
+    ) -> Result<Option<TaskId>, StartTimerError> {
+        if !self.incomplete.contains(&id) {
+            return Err(StartTimerError::TaskIsComplete);
+        }
+        let stopped = match self.running_timer() {
+            Some(running) if running == id => {
+                return Err(StartTimerError::AlreadyRunning)
+            }
+            Some(running) => {
+                self.stop_timer_on(running, now).then_some(running)
+            }
+            None => None,
+        };
+        self.tasks[id.0].timer_start = Some(now);
+        Ok(stopped)
+    }
+
+    /// Stops the running timer at |now|, adding the time since it started
+    /// to the work log of the task that was being timed, which is returned.
+    pub fn stop_timer(&mut self, now: DateTime<Utc>) -> Option<TaskId> {
+        let running = self.running_timer()?;
+        self.stop_timer_on(running, now);
+        Some(running)
+    }
+
+    /// Moves the work log of |from| onto |to|, keeping the log of |to|
+    /// ordered oldest first. If the timer on |from| is running, it keeps
+    /// running on |to|. Returns the tasks that changed.
+    pub fn move_work(&mut self, from: TaskId, to: TaskId) -> TaskSet {
+        if from == to || self.tasks.node_weight(to.0).is_none() {
+            return TaskSet::default();
+        }
+        let Some(task) = self.tasks.node_weight_mut(from.0) else {
+            return TaskSet::default();
+        };
+        let work_log = std::mem::take(&mut task.work_log);
+        let timer_start = task.timer_start.take();
+        let task = &mut self.tasks[to.0];
+        task.work_log.extend(work_log);
+        task.work_log.sort_by_key(|interval| interval.start);
+        task.timer_start = task.timer_start.or(timer_start);
+        TaskSet::of(from) | TaskSet::of(to)
+    }
+
+    // Returns whether the task's timer was running.
+    fn stop_timer_on(&mut self, id: TaskId, now: DateTime<Utc>) -> bool {
+        let Some(task) = self.tasks.node_weight_mut(id.0) else {
+            return false;
+        };
+        match task.timer_start.take() {
+            Some(start) => {
+                task.work_log.push(WorkInterval {
+                    start,
+                    end: now.max(start),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 impl TodoList<'_> {
     pub fn clean(&mut self) -> TaskSet {
         let incomplete_tasks: TaskSet = self
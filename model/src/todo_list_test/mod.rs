@@ -28,13 +28,18 @@ mod due_date_test;
 mod get_test;
 mod iter_test;
 mod lookup_by_number_test;
+mod notes_test;
 mod position_test;
 mod punt_test;
+mod recurrence_test;
 mod reload_test;
 mod remove_test;
 mod restore_test;
+mod schedule_test;
 mod set_desc_test;
+mod short_id_test;
 mod snooze_test;
 mod status_test;
 mod tag_test;
+mod timer_test;
 mod unblock_test;
@@ -0,0 +1,49 @@
+use ::pretty_assertions::assert_eq;
+
+use super::*;
+
+#[test]
+fn new_task_has_no_notes() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    assert_eq!(list.get(a).unwrap().notes, "");
+}
+
+#[test]
+fn set_notes_existent() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    assert_eq!(list.set_notes(a, "line 1\nline 2"), TaskSet::of(a));
+    assert_eq!(list.get(a).unwrap().notes, "line 1\nline 2");
+}
+
+#[test]
+fn set_notes_nonexistent() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.remove(a);
+    assert!(list.set_notes(a, "b").is_empty());
+    assert_eq!(list.get(a), None);
+}
+
+#[test]
+fn set_notes_does_not_change_other_tasks() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    list.block(b).on(a)?;
+    assert_eq!(list.set_notes(b, "x"), TaskSet::of(b));
+    assert_eq!(list.get(a).unwrap().notes, "");
+    Ok(())
+}
+
+#[test]
+fn notes_survive_serialization() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.set_notes(a, "see https://example.com");
+    let json = serde_json::to_string(&list).unwrap();
+    let reloaded: TodoList = serde_json::from_str(&json).unwrap();
+    let a = reloaded.all_tasks().next().unwrap();
+    assert_eq!(reloaded.get(a).unwrap().notes, "see https://example.com");
+}
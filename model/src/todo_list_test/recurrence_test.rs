@@ -0,0 +1,159 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use todo_testing::ymdhms;
+use todo_time_format::Recurrence;
+use todo_time_format::RecurrenceUnit;
+
+use super::*;
+
+fn daily() -> Recurrence {
+    Recurrence::Every(1, RecurrenceUnit::Day)
+}
+
+#[test]
+fn check_task_without_recurrence_does_not_respawn() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.check(a)?;
+    assert_eq!(list.all_tasks().count(), 1);
+    Ok(())
+}
+
+#[test]
+fn check_recurring_task_respawns_snoozed_instance() -> TestResult {
+    let mut list = TodoList::default();
+    let now = ymdhms(2024, 01, 01, 09, 00, 00);
+    let a = list.add(
+        NewOptions::new()
+            .desc("standup")
+            .creation_time(now)
+            .recurrence(daily()),
+    );
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2024, 01, 01, 10, 00, 00),
+    })?;
+    let respawned = list.incomplete_tasks().next().unwrap();
+    assert_ne!(respawned, a);
+    let task = list.get(respawned).unwrap();
+    assert_eq!(task.desc, "standup");
+    assert_eq!(task.start_date, ymdhms(2024, 01, 02, 00, 00, 00));
+    assert_eq!(task.recurrence, Some(daily()));
+    assert_eq!(list.status(respawned), Some(TaskStatus::Blocked));
+    Ok(())
+}
+
+#[test]
+fn recurrence_moves_to_respawned_instance() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add(NewOptions::new().desc("a").recurrence(daily()));
+    list.check(a)?;
+    assert_eq!(list.get(a).unwrap().recurrence, None);
+    list.restore(a)?;
+    list.check(a)?;
+    assert_eq!(list.all_tasks().count(), 2);
+    Ok(())
+}
+
+#[test]
+fn respawned_instance_keeps_properties() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .priority(2)
+            .budget(chrono::Duration::hours(1))
+            .as_tag()
+            .recurrence(daily()),
+    );
+    list.check(a)?;
+    let respawned = list.incomplete_tasks().next().unwrap();
+    let task = list.get(respawned).unwrap();
+    assert_eq!(task.priority, 2);
+    assert_eq!(task.budget, DurationInSeconds(3600));
+    assert!(task.tag);
+    Ok(())
+}
+
+#[test]
+fn respawned_instance_has_same_deps_and_adeps() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(NewOptions::new().desc("b").recurrence(daily()));
+    let c = list.add("c");
+    list.block(b).on(a)?;
+    list.block(c).on(b)?;
+    list.check(a)?;
+    list.check(b)?;
+    let respawned = list.incomplete_tasks().find(|&id| id != c).unwrap();
+    assert_eq!(list.deps(respawned), TaskSet::of(a));
+    assert_eq!(list.adeps(respawned), TaskSet::of(c));
+    assert_eq!(list.status(c), Some(TaskStatus::Blocked));
+    Ok(())
+}
+
+#[test]
+fn respawned_instance_due_date_moves_forward() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("report")
+            .creation_time(ymdhms(2024, 01, 01, 00, 00, 00))
+            .start_date(ymdhms(2024, 01, 01, 00, 00, 00))
+            .due_date(ymdhms(2024, 01, 05, 17, 00, 00))
+            .recurrence(Recurrence::Every(1, RecurrenceUnit::Week)),
+    );
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2024, 01, 04, 12, 00, 00),
+    })?;
+    let respawned = list.incomplete_tasks().next().unwrap();
+    let task = list.get(respawned).unwrap();
+    assert_eq!(task.start_date, ymdhms(2024, 01, 08, 00, 00, 00));
+    assert_eq!(task.due_date, Some(ymdhms(2024, 01, 12, 17, 00, 00)));
+    Ok(())
+}
+
+#[test]
+fn respawn_skips_occurrences_that_already_passed() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("invoice")
+            .creation_time(ymdhms(2024, 01, 01, 00, 00, 00))
+            .recurrence(Recurrence::MonthlyOnDay(1)),
+    );
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2024, 03, 15, 12, 00, 00),
+    })?;
+    let respawned = list.incomplete_tasks().next().unwrap();
+    assert_eq!(
+        list.get(respawned).unwrap().start_date,
+        ymdhms(2024, 04, 01, 00, 00, 00)
+    );
+    Ok(())
+}
+
+#[test]
+fn checking_snoozed_recurring_task_early_respawns_after_snooze() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("invoice")
+            .creation_time(ymdhms(2024, 01, 20, 00, 00, 00))
+            .start_date(ymdhms(2024, 02, 01, 00, 00, 00))
+            .recurrence(Recurrence::MonthlyOnDay(1)),
+    );
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2024, 01, 28, 12, 00, 00),
+    })?;
+    let respawned = list.incomplete_tasks().next().unwrap();
+    assert_eq!(
+        list.get(respawned).unwrap().start_date,
+        ymdhms(2024, 03, 01, 00, 00, 00)
+    );
+    Ok(())
+}
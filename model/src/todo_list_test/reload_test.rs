@@ -35,3 +35,20 @@ fn reload_three_tasks() {
     list.add("third");
     reload(&list);
 }
+
+#[test]
+fn into_owned_outlives_serialized_text() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    list.block(b).on(a).unwrap();
+    let owned = {
+        let serialized = serde_json::to_string(&list).unwrap();
+        serde_json::from_str::<TodoList>(&serialized)
+            .unwrap()
+            .into_owned()
+    };
+    assert_eq!(all_tasks_ordered(&list), all_tasks_ordered(&owned));
+    assert_eq!(owned.get(b).unwrap().desc, "b");
+    assert_eq!(owned.deps(b), TaskSet::of(a));
+}
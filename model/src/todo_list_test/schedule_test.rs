@@ -0,0 +1,135 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use todo_testing::ymdhms;
+
+use super::*;
+
+fn task_with_budget(
+    desc: &str,
+    now: DateTime<Utc>,
+    hours: i64,
+) -> NewOptions<'_> {
+    NewOptions::new()
+        .desc(desc)
+        .creation_time(now)
+        .budget(Duration::hours(hours))
+}
+
+#[test]
+fn schedule_task_without_budget_or_due_date() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(NewOptions::new().desc("a").creation_time(now));
+    let schedule = list.schedule(now);
+    assert_eq!(
+        schedule[&a],
+        ScheduledTask {
+            earliest_start: now,
+            earliest_finish: now,
+            latest_finish: None,
+        }
+    );
+    assert_eq!(schedule[&a].slack(), None);
+}
+
+#[test]
+fn schedule_chain_adds_up_budgets() -> TestResult {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(task_with_budget("a", now, 2));
+    let b = list.add(
+        task_with_budget("b", now, 3)
+            .due_date(ymdhms(2024, 03, 01, 17, 00, 00)),
+    );
+    list.block(b).on(a)?;
+    let schedule = list.schedule(now);
+    assert_eq!(
+        schedule[&a].earliest_finish,
+        ymdhms(2024, 03, 01, 11, 00, 00)
+    );
+    assert_eq!(
+        schedule[&b].earliest_start,
+        ymdhms(2024, 03, 01, 11, 00, 00)
+    );
+    assert_eq!(
+        schedule[&b].earliest_finish,
+        ymdhms(2024, 03, 01, 14, 00, 00)
+    );
+    assert_eq!(
+        schedule[&a].latest_finish,
+        Some(ymdhms(2024, 03, 01, 14, 00, 00))
+    );
+    assert_eq!(schedule[&a].slack(), Some(Duration::hours(3)));
+    assert_eq!(schedule[&b].slack(), Some(Duration::hours(3)));
+    Ok(())
+}
+
+#[test]
+fn schedule_waits_for_slowest_dep() -> TestResult {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(task_with_budget("a", now, 1));
+    let b = list.add(task_with_budget("b", now, 4));
+    let c = list.add(
+        task_with_budget("c", now, 1)
+            .due_date(ymdhms(2024, 03, 01, 12, 00, 00)),
+    );
+    list.block(c).on(a)?;
+    list.block(c).on(b)?;
+    let schedule = list.schedule(now);
+    assert_eq!(
+        schedule[&c].earliest_start,
+        ymdhms(2024, 03, 01, 13, 00, 00)
+    );
+    assert_eq!(schedule[&c].slack(), Some(Duration::hours(-2)));
+    assert_eq!(schedule[&b].slack(), Some(Duration::hours(-2)));
+    assert_eq!(schedule[&a].slack(), Some(Duration::hours(1)));
+    Ok(())
+}
+
+#[test]
+fn schedule_ignores_complete_deps() -> TestResult {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(task_with_budget("a", now, 5));
+    let b = list.add(task_with_budget("b", now, 1));
+    list.block(b).on(a)?;
+    list.check(a)?;
+    let schedule = list.schedule(now);
+    assert!(!schedule.contains_key(&a));
+    assert_eq!(schedule[&b].earliest_start, now);
+    Ok(())
+}
+
+#[test]
+fn schedule_starts_snoozed_task_when_unsnoozed() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(
+        task_with_budget("a", now, 1)
+            .start_date(ymdhms(2024, 03, 02, 00, 00, 00)),
+    );
+    let schedule = list.schedule(now);
+    assert_eq!(
+        schedule[&a].earliest_finish,
+        ymdhms(2024, 03, 02, 01, 00, 00)
+    );
+}
+
+#[test]
+fn schedule_subtracts_time_spent_from_budget() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let mut list = TodoList::default();
+    let a = list.add(task_with_budget("a", now, 2));
+    list.start_timer(a, now).unwrap();
+    let later = ymdhms(2024, 03, 01, 10, 30, 00);
+    let schedule = list.schedule(later);
+    assert_eq!(
+        schedule[&a].earliest_finish,
+        ymdhms(2024, 03, 01, 11, 00, 00)
+    );
+}
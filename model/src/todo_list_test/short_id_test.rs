@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+use super::*;
+
+#[test]
+fn added_tasks_have_short_ids() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    assert_eq!(list.get(a).unwrap().short_id.len(), 4);
+}
+
+#[test]
+fn added_tasks_have_distinct_short_ids() {
+    let mut list = TodoList::default();
+    let now = chrono::Utc::now();
+    let tasks = (0..50)
+        .map(|_| list.add(NewOptions::new().desc("same").creation_time(now)))
+        .collect::<Vec<_>>();
+    let ids = tasks
+        .into_iter()
+        .map(|id| list.get(id).unwrap().short_id.clone())
+        .collect::<HashSet<_>>();
+    assert_eq!(ids.len(), 50);
+}
+
+#[test]
+fn lookup_by_short_id() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let short_id = list.get(b).unwrap().short_id.clone();
+    assert_eq!(list.lookup_by_short_id(&short_id), Some(b));
+    let short_id = list.get(a).unwrap().short_id.to_uppercase();
+    assert_eq!(list.lookup_by_short_id(&short_id), Some(a));
+    assert_eq!(list.lookup_by_short_id("xyz"), None);
+}
+
+#[test]
+fn lookup_by_short_id_after_remove() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let short_id = list.get(a).unwrap().short_id.clone();
+    assert_eq!(list.lookup_by_short_id(&short_id), Some(a));
+    list.remove(a);
+    assert_eq!(list.lookup_by_short_id(&short_id), None);
+    let b = list.add("b");
+    let short_id = list.get(b).unwrap().short_id.clone();
+    assert_eq!(list.lookup_by_short_id(&short_id), Some(b));
+}
+
+#[test]
+fn short_id_survives_check_and_punt() -> TestResult {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let short_id_a = list.get(a).unwrap().short_id.clone();
+    let short_id_b = list.get(b).unwrap().short_id.clone();
+    list.punt(a)?;
+    list.check(b)?;
+    assert_eq!(list.lookup_by_short_id(&short_id_a), Some(a));
+    assert_eq!(list.lookup_by_short_id(&short_id_b), Some(b));
+    Ok(())
+}
+
+#[test]
+fn short_id_survives_reload() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let short_id = list.get(a).unwrap().short_id.clone();
+    let serialized = serde_json::to_string(&list).unwrap();
+    let reloaded = serde_json::from_str::<TodoList>(&serialized).unwrap();
+    assert_eq!(reloaded.get(a).unwrap().short_id, short_id);
+    assert_eq!(reloaded.lookup_by_short_id(&short_id), Some(a));
+}
+
+#[test]
+fn loading_list_without_short_ids_assigns_them() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let mut json = serde_json::to_value(&list).unwrap();
+    for node in json["tasks"]["nodes"].as_array_mut().unwrap() {
+        node.as_object_mut().unwrap().remove("short_id");
+    }
+    let serialized = json.to_string();
+    let migrated = serde_json::from_str::<TodoList>(&serialized).unwrap();
+    let short_id_a = &migrated.get(a).unwrap().short_id;
+    let short_id_b = &migrated.get(b).unwrap().short_id;
+    assert_eq!(short_id_a.len(), 4);
+    assert_eq!(short_id_b.len(), 4);
+    assert_ne!(short_id_a, short_id_b);
+    // Migrating the same list again gives the same IDs.
+    let again = serde_json::from_str::<TodoList>(&serialized).unwrap();
+    assert_eq!(&again.get(a).unwrap().short_id, short_id_a);
+    assert_eq!(&again.get(b).unwrap().short_id, short_id_b);
+}
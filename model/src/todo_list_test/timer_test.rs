@@ -0,0 +1,149 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use ::pretty_assertions::assert_eq;
+use chrono::Duration;
+use todo_testing::ymdhms;
+
+use super::*;
+
+#[test]
+fn no_timer_running_by_default() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    assert_eq!(list.running_timer(), None);
+    assert_eq!(list.get(a).unwrap().work_log, vec![]);
+}
+
+#[test]
+fn start_timer() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    assert_eq!(list.start_timer(a, now), Ok(None));
+    assert_eq!(list.running_timer(), Some(a));
+    assert_eq!(list.get(a).unwrap().timer_start, Some(now));
+}
+
+#[test]
+fn stop_timer_logs_interval() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let start = ymdhms(2024, 03, 01, 09, 00, 00);
+    let end = ymdhms(2024, 03, 01, 09, 30, 00);
+    list.start_timer(a, start).unwrap();
+    assert_eq!(list.stop_timer(end), Some(a));
+    assert_eq!(list.running_timer(), None);
+    let task = list.get(a).unwrap();
+    assert_eq!(task.timer_start, None);
+    assert_eq!(task.work_log, vec![WorkInterval { start, end }]);
+    assert_eq!(task.logged_time(), Duration::minutes(30));
+}
+
+#[test]
+fn stop_timer_when_none_running() {
+    let mut list = TodoList::default();
+    list.add("a");
+    assert_eq!(list.stop_timer(ymdhms(2024, 03, 01, 09, 00, 00)), None);
+}
+
+#[test]
+fn start_timer_stops_running_timer() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    list.start_timer(a, ymdhms(2024, 03, 01, 09, 00, 00))
+        .unwrap();
+    assert_eq!(
+        list.start_timer(b, ymdhms(2024, 03, 01, 10, 00, 00)),
+        Ok(Some(a))
+    );
+    assert_eq!(list.running_timer(), Some(b));
+    assert_eq!(list.get(a).unwrap().logged_time(), Duration::hours(1));
+}
+
+#[test]
+fn start_timer_twice_on_same_task() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    list.start_timer(a, now).unwrap();
+    assert_eq!(
+        list.start_timer(a, now + Duration::minutes(5)),
+        Err(StartTimerError::AlreadyRunning)
+    );
+    assert_eq!(list.get(a).unwrap().timer_start, Some(now));
+}
+
+#[test]
+fn start_timer_on_complete_task() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.check(a).unwrap();
+    assert_eq!(
+        list.start_timer(a, ymdhms(2024, 03, 01, 09, 00, 00)),
+        Err(StartTimerError::TaskIsComplete)
+    );
+    assert_eq!(list.running_timer(), None);
+}
+
+#[test]
+fn check_stops_timer() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.start_timer(a, ymdhms(2024, 03, 01, 09, 00, 00))
+        .unwrap();
+    list.check(CheckOptions {
+        id: a,
+        now: ymdhms(2024, 03, 01, 11, 00, 00),
+    })
+    .unwrap();
+    assert_eq!(list.running_timer(), None);
+    assert_eq!(list.get(a).unwrap().logged_time(), Duration::hours(2));
+}
+
+#[test]
+fn time_spent_includes_running_timer() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    list.start_timer(a, ymdhms(2024, 03, 01, 09, 00, 00))
+        .unwrap();
+    list.stop_timer(ymdhms(2024, 03, 01, 09, 20, 00));
+    list.start_timer(a, ymdhms(2024, 03, 01, 13, 00, 00))
+        .unwrap();
+    let task = list.get(a).unwrap();
+    assert_eq!(task.logged_time(), Duration::minutes(20));
+    assert_eq!(
+        task.time_spent(ymdhms(2024, 03, 01, 13, 10, 00)),
+        Duration::minutes(30)
+    );
+}
+
+#[test]
+fn move_work_merges_logs_and_moves_timer() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let (t0, t1, t2, t3) = (
+        ymdhms(2024, 03, 01, 09, 00, 00),
+        ymdhms(2024, 03, 01, 09, 30, 00),
+        ymdhms(2024, 03, 01, 10, 00, 00),
+        ymdhms(2024, 03, 01, 10, 30, 00),
+    );
+    list.start_timer(b, t0).unwrap();
+    list.stop_timer(t1);
+    list.start_timer(a, t2).unwrap();
+    list.stop_timer(t3);
+    list.start_timer(a, t3).unwrap();
+    assert_eq!(list.move_work(a, b), TaskSet::of(a) | TaskSet::of(b));
+    assert_eq!(list.running_timer(), Some(b));
+    let task = list.get(b).unwrap();
+    assert_eq!(
+        task.work_log,
+        vec![
+            WorkInterval { start: t0, end: t1 },
+            WorkInterval { start: t2, end: t3 },
+        ]
+    );
+    assert_eq!(task.timer_start, Some(t3));
+    assert_eq!(list.get(a).unwrap().work_log, vec![]);
+}
@@ -2,6 +2,8 @@ use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use serde_derive::Serialize;
+
 use crate::format_util::format_number;
 use crate::Status;
 
@@ -10,7 +12,7 @@ use crate::Status;
 /// When formatted, the representation will use ANSI colors to display the
 /// task number (its position in the list) with a color corresponding to its
 /// status.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct BriefPrintableTask {
     pub number: i32,
     pub status: Status,
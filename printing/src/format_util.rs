@@ -18,6 +18,7 @@ pub fn format_key(key: &Key) -> impl fmt::Display + '_ {
                 Key::ByRange(start, end) => {
                     write!(f, "range({}..{})", start, end)
                 }
+                Key::ById(ref id) => write!(f, "\"#{}\"", id),
             }
         }
     }
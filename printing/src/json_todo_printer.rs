@@ -0,0 +1,311 @@
+//! Prints output as JSON for consumption by scripts.
+//!
+//! Every printed item is a JSON object. Tasks look like this:
+//!
+//! ```json
+//! {
+//!   "number": 1,
+//!   "id": "a3f9",
+//!   "desc": "walk the dog",
+//!   "status": "incomplete",
+//!   "action": "new",
+//!   "log_date": null,
+//!   "priority": { "value": 1, "explicit": true },
+//!   "due_date": { "value": "2021-05-01T17:00:00Z", "explicit": false },
+//!   "punctuality_secs": null,
+//!   "budget_secs": 3600,
+//!   "start_date": "2021-04-30T00:00:00Z",
+//!   "deps": { "immediate": 0, "total": 0 },
+//!   "adeps": { "immediate": 1, "total": 2 },
+//!   "is_tag": false,
+//!   "tags": ["chores"],
+//!   "tree": null,
+//!   "notes": "Ask the neighbors\nabout the leash",
+//!   "time_spent_secs": 1800,
+//!   "timer_start": "2021-04-30T09:00:00Z",
+//!   "time_spent_with_deps_secs": null,
+//!   "earliest_finish": null,
+//!   "slack_secs": null,
+//!   "is_critical": false,
+//!   "agenda": null
+//! }
+//! ```
+//!
+//! - "id" is the task's short ID, which never changes, unlike "number". It
+//!   can be passed to other commands as "#a3f9".
+//! - "status" is one of "incomplete", "complete", or "blocked".
+//! - "action" is one of "none", "new", "delete", "check", "uncheck", "lock",
+//!   "unlock", "select", "punt", "snooze", "unsnooze", "start", or "stop".
+//! - "log_date" is a "YYYY-MM-DD" string for the first completed task of each
+//!   day in 'todo log', and null otherwise.
+//! - "priority" and "due_date" are null if unset. "explicit" is false if the
+//!   value is inherited from an antidependency.
+//! - Times are RFC 3339 strings in UTC, and durations are whole seconds.
+//!   "punctuality_secs" is how long before (negative) or after (positive) its
+//!   due date a complete task was completed.
+//! - "tags" lists the tags that the task inherits from its antidependencies.
+//! - "tree" is null except in 'todo tree', where it is an object with the
+//!   task's "depth" in the tree (0 for roots) and "back_reference", which is
+//!   true if the task already appeared earlier in the tree. The tree is
+//!   printed depth-first, so a task's parent is the closest earlier task with
+//!   a smaller depth.
+//! - "notes" is the task's multi-line notes, or null if it has none.
+//! - "time_spent_secs" is the time recorded by timers on the task, not
+//!   counting the running timer, or null if none was recorded. If the timer
+//!   is running, "timer_start" is when it started. 'todo time' also gives
+//!   "time_spent_with_deps_secs", which includes the time spent on the
+//!   task's transitive deps and the running timer.
+//! - "earliest_finish", "slack_secs", and "is_critical" are only set by 'todo
+//!   schedule'. "slack_secs" is how long the task can be delayed without
+//!   missing a due date, which is negative if one will be missed, or null if
+//!   no due date depends on the task.
+//! - "agenda" is set on the first task under each heading in 'todo agenda',
+//!   and is one of "overdue", "today", "tomorrow", "later", or a
+//!   "YYYY-MM-DD" date for the days after tomorrow.
+//!
+//! Infos, warnings, and errors have a "kind" naming the variant in
+//! snake_case (e.g. "no_match_for_keys"), a "details" object with the fields
+//! of the variant (absent for variants without fields), and a "message" with
+//! the plain-text message that would be shown on a terminal. Tasks in
+//! "details" are summarized as "number" and "status", and task keys are given
+//! as they would be typed on the command line.
+//!
+//! Documents, like the diagrams printed by 'todo export', are objects with
+//! the text of the document in "content". Reports, like the one printed by
+//! 'todo stats', are objects with the fields of the report.
+//!
+//! With `JsonFormat::Json`, the output is a single object:
+//!
+//! ```json
+//! {
+//!   "mutated": true,
+//!   "tasks": [...],
+//!   "infos": [...],
+//!   "warnings": [...],
+//!   "errors": [...],
+//!   "documents": [...],
+//!   "reports": [...]
+//! }
+//! ```
+//!
+//! With `JsonFormat::Jsonl`, each item is printed on its own line with a
+//! "type" field of "task", "info", "warning", "error", "document", or
+//! "report", and the last line is `{"type": "result", "mutated": true}`.
+
+use std::io::Write;
+
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::json;
+use serde_json::Value;
+use todo_lookup_key::Key;
+
+use crate::AgendaHeading;
+use crate::LogDate;
+use crate::Plicit;
+use crate::PrintableError;
+use crate::PrintableInfo;
+use crate::PrintableReport;
+use crate::PrintableTask;
+use crate::PrintableWarning;
+use crate::TodoPrinter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormat {
+    /// A single JSON object, printed once the command has finished.
+    Json,
+    /// One JSON object per line, printed as soon as each item is available.
+    Jsonl,
+}
+
+pub struct JsonTodoPrinter<W: Write> {
+    out: W,
+    format: JsonFormat,
+    // Items waiting to be printed in a single object, with their types.
+    collected: Vec<(&'static str, Value)>,
+}
+
+fn format_key(key: &Key) -> String {
+    match key {
+        Key::ByNumber(n) => n.to_string(),
+        Key::ByName(name) => name.clone(),
+        Key::ByRange(start, end) => format!("{start}..{end}"),
+        Key::ById(id) => format!("#{id}"),
+    }
+}
+
+pub(crate) fn serialize_key<S: Serializer>(
+    key: &Key,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_key(key))
+}
+
+pub(crate) fn serialize_keys<S: Serializer>(
+    keys: &[Key],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(keys.iter().map(format_key))
+}
+
+fn strip_ansi_codes(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip the escape sequence up to and including its final letter.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+fn plicit<T: Serialize>(plicit: &Plicit<T>) -> Value {
+    match plicit {
+        Plicit::Implicit(value) => json!({"value": value, "explicit": false}),
+        Plicit::Explicit(value) => json!({"value": value, "explicit": true}),
+    }
+}
+
+fn task_to_json(task: &PrintableTask) -> Value {
+    json!({
+        "number": task.number,
+        "id": task.short_id,
+        "desc": task.desc,
+        "status": task.status,
+        "action": task.action,
+        "log_date": match task.log_date {
+            Some(LogDate::YearMonthDay(y, m, d)) => {
+                Some(format!("{y:04}-{m:02}-{d:02}"))
+            }
+            Some(LogDate::Invisible) | None => None,
+        },
+        "priority": task.priority.as_ref().map(plicit),
+        "due_date": task.due_date.as_ref().map(plicit),
+        "punctuality_secs": task.punctuality.map(|d| d.num_seconds()),
+        "budget_secs": task.budget.map(|d| d.num_seconds()),
+        "start_date": task.start_date,
+        "deps": {
+            "immediate": task.deps_stats.0,
+            "total": task.deps_stats.1,
+        },
+        "adeps": {
+            "immediate": task.adeps_stats.0,
+            "total": task.adeps_stats.1,
+        },
+        "is_tag": task.is_explicit_tag,
+        "tags": task.implicit_tags,
+        "tree": task.tree_position.as_ref().map(|position| json!({
+            "depth": position.depth(),
+            "back_reference": position.back_reference,
+        })),
+        "notes": task.notes,
+        "time_spent_secs": task.time_spent.map(|d| d.num_seconds()),
+        "timer_start": task.timer_start,
+        "time_spent_with_deps_secs":
+            task.time_spent_with_deps.map(|d| d.num_seconds()),
+        "earliest_finish": task.earliest_finish,
+        "slack_secs": task.slack.map(|d| d.num_seconds()),
+        "is_critical": task.is_critical,
+        "agenda": task.agenda_heading.map(|heading| match heading {
+            AgendaHeading::Day(date) => date.format("%Y-%m-%d").to_string(),
+            heading => heading.to_string().to_lowercase(),
+        }),
+    })
+}
+
+// Serializes an info, warning, or error along with its message, minus the
+// "info: " style prefix, which is redundant with the record type.
+fn message_to_json(
+    record_type: &str,
+    item: &(impl Serialize + std::fmt::Display),
+) -> Value {
+    let mut value = serde_json::to_value(item).unwrap_or_default();
+    let message = strip_ansi_codes(&item.to_string());
+    let message = message
+        .strip_prefix(&format!("{record_type}: "))
+        .map(str::to_string)
+        .unwrap_or(message);
+    if let Value::Object(map) = &mut value {
+        map.insert("message".to_string(), Value::String(message));
+    }
+    value
+}
+
+impl<W: Write> JsonTodoPrinter<W> {
+    pub fn new(out: W, format: JsonFormat) -> Self {
+        JsonTodoPrinter {
+            out,
+            format,
+            collected: Vec::new(),
+        }
+    }
+
+    fn emit(&mut self, record_type: &'static str, mut value: Value) {
+        match self.format {
+            JsonFormat::Json => self.collected.push((record_type, value)),
+            JsonFormat::Jsonl => {
+                if let Value::Object(map) = &mut value {
+                    map.insert("type".to_string(), record_type.into());
+                }
+                writeln!(self.out, "{value}").unwrap_or_default();
+            }
+        }
+    }
+
+    /// Prints whether the list was modified, and with `JsonFormat::Json`,
+    /// everything else that was printed. Must be called once all output has
+    /// been printed.
+    pub fn finish(mut self, mutated: bool) {
+        let value = match self.format {
+            JsonFormat::Json => {
+                let mut value = json!({"mutated": mutated});
+                for record_type in
+                    ["task", "info", "warning", "error", "document", "report"]
+                {
+                    value[format!("{record_type}s")] = self
+                        .collected
+                        .iter()
+                        .filter(|(t, _)| *t == record_type)
+                        .map(|(_, item)| item.clone())
+                        .collect();
+                }
+                value
+            }
+            JsonFormat::Jsonl => json!({"type": "result", "mutated": mutated}),
+        };
+        writeln!(self.out, "{value}").unwrap_or_default();
+    }
+}
+
+impl<'a, W: Write> TodoPrinter<'a> for JsonTodoPrinter<W> {
+    fn print_task(&mut self, task: &PrintableTask<'a>) {
+        self.emit("task", task_to_json(task));
+    }
+
+    fn print_info(&mut self, info: &PrintableInfo) {
+        self.emit("info", message_to_json("info", info));
+    }
+
+    fn print_warning(&mut self, warning: &PrintableWarning) {
+        self.emit("warning", message_to_json("warning", warning));
+    }
+
+    fn print_error(&mut self, error: &PrintableError) {
+        self.emit("error", message_to_json("error", error));
+    }
+
+    fn print_document(&mut self, document: &str) {
+        self.emit("document", json!({"content": document}));
+    }
+
+    fn print_report(&mut self, report: &PrintableReport) {
+        self.emit("report", report.json.clone());
+    }
+}
@@ -1,7 +1,9 @@
 mod brief_printable_task;
 mod format_util;
+mod json_todo_printer;
 mod printable_error;
 mod printable_info;
+mod printable_report;
 mod printable_task;
 mod printable_warning;
 mod scripting_todo_printer;
@@ -10,8 +12,11 @@ mod todo_printer;
 mod truncate;
 
 pub use self::brief_printable_task::*;
+pub use self::json_todo_printer::JsonFormat;
+pub use self::json_todo_printer::JsonTodoPrinter;
 pub use self::printable_error::*;
 pub use self::printable_info::*;
+pub use self::printable_report::*;
 pub use self::printable_task::*;
 pub use self::printable_warning::*;
 pub use self::scripting_todo_printer::*;
@@ -23,6 +28,8 @@ pub struct PrintableAppSuccess<'list> {
     pub warnings: Vec<PrintableWarning>,
     pub infos: Vec<PrintableInfo>,
     pub tasks: Vec<PrintableTask<'list>>,
+    pub document: Option<String>,
+    pub reports: Vec<PrintableReport>,
     pub mutated: bool,
 }
 
@@ -65,6 +72,8 @@ where
                 warnings,
                 infos,
                 tasks,
+                document,
+                reports,
                 mutated,
             }) => {
                 for warning in warnings {
@@ -76,6 +85,12 @@ where
                 for task in tasks {
                     printer.print_task(task);
                 }
+                if let Some(document) = document {
+                    printer.print_document(document);
+                }
+                for report in reports {
+                    printer.print_report(report);
+                }
                 *mutated
             }
             Self::Err(errors) => {
@@ -90,6 +105,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    mod json_todo_printer_test;
     mod printable_error_test;
     mod printable_info_test;
     mod printable_result_test;
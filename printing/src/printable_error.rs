@@ -2,6 +2,7 @@ use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use serde_derive::Serialize;
 use todo_lookup_key::Key;
 use yansi::Paint;
 
@@ -9,7 +10,8 @@ use crate::format_util::format_keys;
 use crate::format_util::format_numbers;
 use crate::BriefPrintableTask;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "details", rename_all = "snake_case")]
 pub enum PrintableError {
     CannotCheckBecauseBlocked {
         cannot_check: BriefPrintableTask,
@@ -35,6 +37,7 @@ pub enum PrintableError {
     },
     FailedToUseTextEditor,
     NoMatchForKeys {
+        #[serde(serialize_with = "crate::json_todo_printer::serialize_keys")]
         keys: Vec<Key>,
     },
     EmptyDate {
@@ -46,6 +49,12 @@ pub enum PrintableError {
     CannotParseDuration {
         cannot_parse: String,
     },
+    CannotParseSinceDate {
+        cannot_parse: String,
+    },
+    CannotParseRecurrence {
+        cannot_parse: String,
+    },
     DurationIsTooLong {
         duration: u64,
         string_repr: String,
@@ -56,115 +65,253 @@ pub enum PrintableError {
         adeps_of: Vec<BriefPrintableTask>,
         deps_of: Vec<BriefPrintableTask>,
     },
+    CannotMergeRecurrences {
+        recurring: Vec<BriefPrintableTask>,
+    },
+    UnknownConfigKey {
+        key: String,
+        valid_keys: Vec<String>,
+    },
+    CannotParseConfigValue {
+        key: String,
+        value: String,
+        explanation: String,
+    },
+    NoSuchBackup {
+        index: usize,
+    },
+    CannotReadBackup {
+        index: usize,
+    },
+    CannotParseQuery {
+        query: String,
+        explanation: String,
+    },
+    CannotReadFile {
+        path: String,
+        explanation: String,
+    },
+    CannotParseFile {
+        path: String,
+        explanation: String,
+    },
+    CannotUseTerminal {
+        explanation: String,
+    },
+    CannotParseBatchCommand {
+        explanation: String,
+    },
+    UnknownBatchName {
+        name: String,
+    },
+    CannotNameBatchTasks {
+        name: String,
+        created: usize,
+    },
+    BatchCommandFailed {
+        line: usize,
+        command: String,
+    },
+    AlreadyInShell,
 }
 
 impl Display for PrintableError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}: {}",
-            "error".red().bold(),
-            match self {
-                PrintableError::CannotCheckBecauseBlocked {
-                    cannot_check,
-                    blocked_by,
-                } => format!(
-                    "Cannot complete {} because it is blocked by {}",
-                    cannot_check,
-                    format_numbers(blocked_by.iter()),
-                ),
-                PrintableError::CannotRestoreBecauseAntidependencyIsComplete{
-                    cannot_restore,
-                    complete_antidependencies,
-                } => format!(
-                    "Cannot restore {} because it blocks complete tasks {}",
-                    cannot_restore,
-                    format_numbers(complete_antidependencies.iter())
-                ),
-                PrintableError::CannotBlockBecauseWouldCauseCycle {
-                    cannot_block,
-                    requested_dependency,
-                } => format!(
-                    "Cannot block {} on {} because it would create a cycle",
-                    cannot_block,
-                    requested_dependency,
-                ),
-                PrintableError::CannotEditBecauseUnexpectedNumber {
-                    requested,
-                } => format!(
+        write!(f, "{}: {}", "error".red().bold(), self.message())
+    }
+}
+
+impl PrintableError {
+    // The error without the "error:" label, for use inside other messages.
+    pub(crate) fn message(&self) -> String {
+        match self {
+            PrintableError::CannotCheckBecauseBlocked {
+                cannot_check,
+                blocked_by,
+            } => format!(
+                "Cannot complete {} because it is blocked by {}",
+                cannot_check,
+                format_numbers(blocked_by.iter()),
+            ),
+            PrintableError::CannotRestoreBecauseAntidependencyIsComplete {
+                cannot_restore,
+                complete_antidependencies,
+            } => format!(
+                "Cannot restore {} because it blocks complete tasks {}",
+                cannot_restore,
+                format_numbers(complete_antidependencies.iter())
+            ),
+            PrintableError::CannotBlockBecauseWouldCauseCycle {
+                cannot_block,
+                requested_dependency,
+            } => format!(
+                "Cannot block {} on {} because it would create a cycle",
+                cannot_block, requested_dependency,
+            ),
+            PrintableError::CannotEditBecauseUnexpectedNumber { requested } => {
+                format!(
                     "Number {}) doesn't correspond to any of requested tasks",
                     requested,
-                ),
-                PrintableError::CannotEditBecauseNoTaskWithNumber {
-                    requested,
-                } => format!("No task with number {})", requested),
-                PrintableError::CannotEditBecauseInvalidLine{
-                    malformed_line,
+                )
+            }
+            PrintableError::CannotEditBecauseNoTaskWithNumber { requested } => {
+                format!("No task with number {})", requested)
+            }
+            PrintableError::CannotEditBecauseInvalidLine {
+                malformed_line,
+                explanation,
+            } => format!(
+                "Could not parse line: \"{}\"; {}",
+                malformed_line, explanation,
+            ),
+            PrintableError::FailedToUseTextEditor => {
+                "Failed to open text editor".to_string()
+            }
+            PrintableError::NoMatchForKeys { keys } => {
+                format!("No match for keys {}", format_keys(keys),)
+            }
+            PrintableError::EmptyDate { flag } => match flag {
+                Some(flag) => {
+                    format!("Empty date for flag {}", flag.white().bold(),)
+                }
+                None => "Empty date".to_string(),
+            },
+            PrintableError::CannotParseDueDate { cannot_parse } => {
+                format!(
+                    "Cannot parse due date: {}",
+                    cannot_parse.white().bold(),
+                )
+            }
+            PrintableError::CannotParseDuration { cannot_parse } => {
+                format!(
+                    "Cannot parse duration: {}",
+                    cannot_parse.white().bold(),
+                )
+            }
+            PrintableError::CannotParseSinceDate { cannot_parse } => {
+                format!(
+                    "Cannot parse start of period: {}",
+                    cannot_parse.white().bold(),
+                )
+            }
+            PrintableError::CannotParseRecurrence { cannot_parse } => {
+                format!(
+                    "Cannot parse recurrence: {}.\n{}: {}",
+                    cannot_parse.white().bold(),
+                    "note".white().bold().dim(),
+                    "Try something like \"daily\", \"every weekday\", \
+                         \"every 2 weeks\", or \"monthly on the 1st\"."
+                )
+            }
+            PrintableError::DurationIsTooLong {
+                duration,
+                string_repr,
+            } => {
+                format!(
+                    "Time budget is too long: {} (from {}).\n{}: {}",
+                    format!("{duration} secs").white().bold(),
+                    string_repr.white().bold(),
+                    "note".white().bold().dim(),
+                    "Must be less than ~136 years, or 2^32 seconds."
+                )
+            }
+            PrintableError::ConflictingArgs((a, b)) => {
+                format!(
+                    "Cannot pass {} and {} at the same time",
+                    a.white().bold(),
+                    b.white().bold(),
+                )
+            }
+            PrintableError::CannotMerge {
+                cycle_through,
+                adeps_of,
+                deps_of,
+            } => {
+                format!(
+                    "Cannot merge: tasks {} are adeps of {} but deps of {}",
+                    format_numbers(cycle_through.iter()),
+                    format_numbers(adeps_of.iter()),
+                    format_numbers(deps_of.iter())
+                )
+            }
+            PrintableError::CannotMergeRecurrences { recurring } => {
+                format!(
+                    "Cannot merge: tasks {} repeat on different schedules",
+                    format_numbers(recurring.iter()),
+                )
+            }
+            PrintableError::UnknownConfigKey { key, valid_keys } => {
+                format!(
+                    "Unknown config key {}.\n{}: Valid keys are {}",
+                    key.white().bold(),
+                    "note".white().bold().dim(),
+                    valid_keys.join(", "),
+                )
+            }
+            PrintableError::CannotParseConfigValue {
+                key,
+                value,
+                explanation,
+            } => {
+                format!(
+                    "Cannot parse value \"{}\" for config key {}: {}",
+                    value,
+                    key.white().bold(),
                     explanation,
-                } => format!(
-                    "Could not parse line: \"{}\"; {}",
-                    malformed_line,
+                )
+            }
+            PrintableError::NoSuchBackup { index } => {
+                format!(
+                    "No backup numbered {}.\n{}: {}",
+                    index.to_string().white().bold(),
+                    "note".white().bold().dim(),
+                    "Run 'todo recover' to see the available backups."
+                )
+            }
+            PrintableError::CannotReadBackup { index } => {
+                format!(
+                    "Backup {} is not a valid list",
+                    index.to_string().white().bold(),
+                )
+            }
+            PrintableError::CannotParseQuery { query, explanation } => {
+                format!(
+                    "Cannot parse query {}: {}",
+                    query.white().bold(),
                     explanation,
-                ),
-                PrintableError::FailedToUseTextEditor => {
-                    "Failed to open text editor".to_string()
-                }
-                PrintableError::NoMatchForKeys{ keys } => {
-                    format!(
-                        "No match for keys {}",
-                        format_keys(keys),
-                    )
-                }
-                PrintableError::EmptyDate{ flag } => {
-                    match flag {
-                        Some(flag) => format!(
-                            "Empty date for flag {}",
-                            flag.white().bold(),
-                        ),
-                        None => "Empty date".to_string(),
-                    }
-                }
-                PrintableError::CannotParseDueDate { cannot_parse } => {
-                    format!(
-                        "Cannot parse due date: {}",
-                        cannot_parse.white().bold(),
-                    )
-                }
-                PrintableError::CannotParseDuration { cannot_parse } => {
-                    format!(
-                        "Cannot parse duration: {}",
-                        cannot_parse.white().bold(),
-                    )
-                }
-                PrintableError::DurationIsTooLong { duration, string_repr } => {
-                    format!(
-                        "Time budget is too long: {} (from {}).\n{}: {}",
-                        format!("{duration} secs").white().bold(),
-                        string_repr.white().bold(),
-                        "note".white().bold().dim(),
-                        "Must be less than ~136 years, or 2^32 seconds."
-                    )
-                }
-                PrintableError::ConflictingArgs((a, b)) => {
-                    format!(
-                        "Cannot pass {} and {} at the same time",
-                        a.white().bold(),
-                        b.white().bold(),
-                    )
-                }
-                PrintableError::CannotMerge {
-                    cycle_through,
-                    adeps_of,
-                    deps_of
-                } => {
-                    format!(
-                        "Cannot merge: tasks {} are adeps of {} but deps of {}",
-                        format_numbers(cycle_through.iter()),
-                        format_numbers(adeps_of.iter()),
-                        format_numbers(deps_of.iter())
-                    )
-                }
+                )
+            }
+            PrintableError::CannotReadFile { path, explanation } => {
+                format!("Cannot read {}: {}", path.white().bold(), explanation,)
+            }
+            PrintableError::CannotParseFile { path, explanation } => {
+                format!("Cannot parse {}: {}", path.white().bold(), explanation,)
+            }
+            PrintableError::CannotUseTerminal { explanation } => {
+                format!("Cannot use the terminal: {explanation}")
+            }
+            PrintableError::CannotParseBatchCommand { explanation } => {
+                format!("Cannot parse command: {explanation}")
+            }
+            PrintableError::UnknownBatchName { name } => {
+                format!("No task was named ${name} earlier in the batch")
+            }
+            PrintableError::CannotNameBatchTasks { name, created } => {
+                format!(
+                    "Cannot name {created} new tasks ${name}; '--as' \
+                     needs a command that creates exactly one task"
+                )
+            }
+            PrintableError::BatchCommandFailed { line, command } => {
+                format!(
+                    "Stopped at line {line} of the batch ({}); nothing \
+                     was changed",
+                    command.white().bold(),
+                )
+            }
+            PrintableError::AlreadyInShell => {
+                "Already in the shell".to_string()
             }
-        )
+        }
     }
 }
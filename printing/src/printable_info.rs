@@ -1,11 +1,42 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use serde_derive::Serialize;
 use yansi::Paint;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "details", rename_all = "snake_case")]
 pub enum PrintableInfo {
-    Removed { desc: String },
+    Removed {
+        desc: String,
+    },
+    ConfigValue {
+        key: String,
+        value: String,
+    },
+    Undid {
+        command: String,
+    },
+    Redid {
+        command: String,
+    },
+    UndoableCommand {
+        command: String,
+        time: DateTime<Utc>,
+    },
+    Backup {
+        index: usize,
+        modified: DateTime<Utc>,
+        // None if the backup could not be read.
+        num_tasks: Option<usize>,
+    },
+    Recovered {
+        index: usize,
+    },
+    DryRun,
 }
 
 impl Display for PrintableInfo {
@@ -14,6 +45,42 @@ impl Display for PrintableInfo {
         write!(f, "{}: ", "info".white().bold().dim())?;
         match self {
             Removed { desc } => write!(f, "Removed \"{}\"", desc),
+            ConfigValue { key, value } => {
+                write!(f, "{} = {}", key.white().bold(), value)
+            }
+            Undid { command } => write!(f, "Undid \"{}\"", command),
+            Redid { command } => write!(f, "Redid \"{}\"", command),
+            UndoableCommand { command, time } => write!(
+                f,
+                "{} {}",
+                time.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+                command
+            ),
+            Backup {
+                index,
+                modified,
+                num_tasks,
+            } => {
+                write!(
+                    f,
+                    "{} {} ",
+                    format!("{index})").white().bold(),
+                    modified.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+                )?;
+                match num_tasks {
+                    Some(1) => write!(f, "(1 task)"),
+                    Some(n) => write!(f, "({n} tasks)"),
+                    None => write!(f, "{}", "(unreadable)".red()),
+                }
+            }
+            Recovered { index } => {
+                write!(f, "Recovered the list from backup {index}")
+            }
+            DryRun => write!(
+                f,
+                "{} Nothing will be saved",
+                "(dry run)".yellow().bold()
+            ),
         }
     }
 }
@@ -0,0 +1,9 @@
+use serde_json::Value;
+
+/// A report, like the one printed by 'todo stats', with a table for the
+/// terminal and the same numbers as a JSON object for scripts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintableReport {
+    pub text: String,
+    pub json: Value,
+}
@@ -4,10 +4,13 @@ use std::fmt::Formatter;
 
 use chrono::DateTime;
 use chrono::Duration;
+use chrono::NaiveDate;
 use chrono::Utc;
+use serde_derive::Serialize;
 use yansi::Paint;
 
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Status {
     #[default]
     Incomplete,
@@ -15,7 +18,8 @@ pub enum Status {
     Blocked,
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Action {
     #[default]
     None,
@@ -29,6 +33,8 @@ pub enum Action {
     Punt,
     Snooze,
     Unsnooze,
+    Start,
+    Stop,
 }
 
 impl Display for Action {
@@ -46,6 +52,8 @@ impl Display for Action {
             Punt => write!(f, " ⏎ "),
             Snooze => write!(f, "{}", "ZZZ".blue()),
             Unsnooze => write!(f, " {}", "⏰".magenta()),
+            Start => write!(f, " {} ", "▶".green()),
+            Stop => write!(f, " {} ", "■".yellow()),
         }
     }
 }
@@ -74,16 +82,59 @@ impl Display for LogDate {
     }
 }
 
+/// The day a task is listed under in 'todo agenda', in the local time zone.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum AgendaHeading {
+    Overdue,
+    Today,
+    Tomorrow,
+    Day(NaiveDate),
+    Later,
+}
+
+impl Display for AgendaHeading {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AgendaHeading::Overdue => write!(f, "Overdue"),
+            AgendaHeading::Today => write!(f, "Today"),
+            AgendaHeading::Tomorrow => write!(f, "Tomorrow"),
+            AgendaHeading::Day(date) => {
+                write!(f, "{}", date.format("%A %b %-d"))
+            }
+            AgendaHeading::Later => write!(f, "Later"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Plicit<T> {
     Implicit(T),
     Explicit(T),
 }
 
+/// Where a task appears in a tree of tasks, as printed by 'todo tree'.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize)]
+pub struct TreePosition {
+    /// For each level between the root and the task, whether the task's
+    /// ancestor at that level (or the task itself, for the last entry) is the
+    /// last of its siblings. Empty for roots.
+    pub is_last: Vec<bool>,
+    /// Whether the task already appeared earlier in the tree. Its deps or
+    /// adeps are only shown the first time.
+    pub back_reference: bool,
+}
+
+impl TreePosition {
+    pub fn depth(&self) -> usize {
+        self.is_last.len()
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct PrintableTask<'a> {
     pub desc: &'a str,
     pub number: i32,
+    pub short_id: Option<&'a str>,
     pub status: Status,
     pub action: Action,
     pub log_date: Option<LogDate>,
@@ -97,6 +148,31 @@ pub struct PrintableTask<'a> {
     pub is_explicit_tag: bool,
     pub implicit_tags: Vec<&'a str>,
     pub truncate_tags_if_needed: bool,
+    pub tree_position: Option<TreePosition>,
+    /// The task's notes, if it has any.
+    pub notes: Option<&'a str>,
+    /// Whether to print the notes under the task, rather than just a marker
+    /// showing that it has some.
+    pub show_notes: bool,
+    /// The time recorded in the task's work log, not counting the running
+    /// timer, if any time has been recorded.
+    pub time_spent: Option<Duration>,
+    /// When the timer on the task was started, if it's running.
+    pub timer_start: Option<DateTime<Utc>>,
+    /// The time spent on the task and all its transitive deps, as shown by
+    /// 'todo time'.
+    pub time_spent_with_deps: Option<Duration>,
+    /// The earliest the task can be done, as shown by 'todo schedule'.
+    pub earliest_finish: Option<DateTime<Utc>>,
+    /// How much the task can be delayed without missing a due date, which is
+    /// negative if one will be missed.
+    pub slack: Option<Duration>,
+    /// Whether the task is on the critical path, i.e. delaying it would
+    /// delay the tasks that 'todo schedule' was asked about.
+    pub is_critical: bool,
+    /// The heading to print above the task, for the first task under each
+    /// heading in 'todo agenda'.
+    pub agenda_heading: Option<AgendaHeading>,
 }
 
 impl<'a> PrintableTask<'a> {
@@ -109,6 +185,11 @@ impl<'a> PrintableTask<'a> {
         }
     }
 
+    pub fn short_id(mut self, short_id: &'a str) -> Self {
+        self.short_id = Some(short_id);
+        self
+    }
+
     pub fn action(mut self, action: Action) -> Self {
         self.action = action;
         self
@@ -168,4 +249,54 @@ impl<'a> PrintableTask<'a> {
         self.truncate_tags_if_needed = truncate;
         self
     }
+
+    pub fn tree_position(mut self, tree_position: TreePosition) -> Self {
+        self.tree_position = Some(tree_position);
+        self
+    }
+
+    pub fn notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    pub fn show_notes(mut self, show_notes: bool) -> Self {
+        self.show_notes = show_notes;
+        self
+    }
+
+    pub fn time_spent(mut self, time_spent: Duration) -> Self {
+        self.time_spent = Some(time_spent);
+        self
+    }
+
+    pub fn timer_start(mut self, timer_start: DateTime<Utc>) -> Self {
+        self.timer_start = Some(timer_start);
+        self
+    }
+
+    pub fn time_spent_with_deps(mut self, time_spent: Duration) -> Self {
+        self.time_spent_with_deps = Some(time_spent);
+        self
+    }
+
+    pub fn earliest_finish(mut self, earliest_finish: DateTime<Utc>) -> Self {
+        self.earliest_finish = Some(earliest_finish);
+        self
+    }
+
+    pub fn slack(mut self, slack: Duration) -> Self {
+        self.slack = Some(slack);
+        self
+    }
+
+    pub fn critical(mut self, is_critical: bool) -> Self {
+        self.is_critical = is_critical;
+        self
+    }
+
+    pub fn agenda_heading(mut self, heading: AgendaHeading) -> Self {
+        self.agenda_heading = Some(heading);
+        self
+    }
 }
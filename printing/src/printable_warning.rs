@@ -4,16 +4,20 @@ use std::fmt::Formatter;
 
 use chrono::DateTime;
 use chrono::Utc;
+use serde_derive::Serialize;
 use todo_lookup_key::Key;
 use yansi::Paint;
 
 use crate::format_util::format_key;
 use crate::format_util::format_numbers;
 use crate::BriefPrintableTask;
+use crate::PrintableError;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "details", rename_all = "snake_case")]
 pub enum PrintableWarning {
     NoMatchFoundForKey {
+        #[serde(serialize_with = "crate::json_todo_printer::serialize_key")]
         requested_key: Key,
     },
     CannotCheckBecauseAlreadyComplete {
@@ -53,10 +57,31 @@ pub enum PrintableWarning {
     },
     CannotUnsnoozeBecauseNotSnoozed(BriefPrintableTask),
     AmbiguousKey {
+        #[serde(serialize_with = "crate::json_todo_printer::serialize_key")]
         key: Key,
         matches: Vec<BriefPrintableTask>,
     },
     NoPathFoundBetween(BriefPrintableTask, BriefPrintableTask),
+    NothingToUndo,
+    NothingToRedo,
+    NoBackups,
+    CannotStartTimerBecauseComplete {
+        cannot_start: BriefPrintableTask,
+    },
+    TimerAlreadyRunning {
+        timed_task: BriefPrintableTask,
+    },
+    NoTimerRunning,
+    CannotMeetDueDate {
+        late_task: BriefPrintableTask,
+        latest_finish: DateTime<Utc>,
+        earliest_finish: DateTime<Utc>,
+    },
+    SkippedBatchCommand {
+        line: usize,
+        command: String,
+        errors: Vec<PrintableError>,
+    },
 }
 
 impl Display for PrintableWarning {
@@ -143,6 +168,43 @@ impl Display for PrintableWarning {
             NoPathFoundBetween(from, to) => {
                 write!(f, "No path found between {} and {}", from, to)
             }
+            NothingToUndo => write!(f, "Nothing to undo"),
+            NothingToRedo => write!(f, "Nothing to redo"),
+            NoBackups => write!(f, "No backups found"),
+            CannotStartTimerBecauseComplete { cannot_start } => write!(
+                f,
+                "Cannot start a timer on {} because it is complete",
+                cannot_start
+            ),
+            TimerAlreadyRunning { timed_task } => {
+                write!(f, "The timer is already running on {}", timed_task)
+            }
+            NoTimerRunning => write!(f, "No timer is running"),
+            CannotMeetDueDate {
+                late_task,
+                latest_finish,
+                earliest_finish,
+            } => write!(
+                f,
+                "{} must be done by {} to meet due dates, but can't be done \
+                 until {}",
+                late_task, latest_finish, earliest_finish
+            ),
+            SkippedBatchCommand {
+                line,
+                command,
+                errors,
+            } => write!(
+                f,
+                "Skipped line {} of the batch ({}): {}",
+                line,
+                command.white().bold(),
+                errors
+                    .iter()
+                    .map(PrintableError::message)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
         }
     }
 }
@@ -2,6 +2,7 @@ use std::io::Write;
 
 use crate::PrintableError;
 use crate::PrintableInfo;
+use crate::PrintableReport;
 use crate::PrintableTask;
 use crate::PrintableWarning;
 use crate::TodoPrinter;
@@ -10,7 +11,13 @@ pub struct ScriptingTodoPrinter;
 
 impl<'a> TodoPrinter<'a> for ScriptingTodoPrinter {
     fn print_task(&mut self, task: &PrintableTask<'a>) {
-        writeln!(std::io::stdout(), "{}", task.number).unwrap_or_default();
+        match task.short_id {
+            Some(short_id) => {
+                writeln!(std::io::stdout(), "{}\t#{}", task.number, short_id)
+            }
+            None => writeln!(std::io::stdout(), "{}", task.number),
+        }
+        .unwrap_or_default();
     }
 
     fn print_info(&mut self, info: &PrintableInfo) {
@@ -24,4 +31,12 @@ impl<'a> TodoPrinter<'a> for ScriptingTodoPrinter {
     fn print_error(&mut self, error: &PrintableError) {
         writeln!(std::io::stderr(), "{}", error).unwrap_or_default();
     }
+
+    fn print_document(&mut self, document: &str) {
+        write!(std::io::stdout(), "{}", document).unwrap_or_default();
+    }
+
+    fn print_report(&mut self, report: &PrintableReport) {
+        write!(std::io::stdout(), "{}", report.text).unwrap_or_default();
+    }
 }
@@ -18,12 +18,16 @@ use yansi::Style;
 use crate::format_util::format_number;
 use crate::truncate::truncation_indices;
 use crate::truncate::TruncationIndices;
+use crate::AgendaHeading;
 use crate::Plicit;
 use crate::PrintableError;
 use crate::PrintableInfo;
+use crate::PrintableReport;
 use crate::PrintableTask;
 use crate::PrintableWarning;
+use crate::Status;
 use crate::TodoPrinter;
+use crate::TreePosition;
 
 pub struct PrintingContext {
     /// The number of digits that task numbers may have, including a minus sign.
@@ -32,6 +36,8 @@ pub struct PrintingContext {
     pub width: usize,
     /// The current time.
     pub now: DateTime<Utc>,
+    /// Whether to show each task's short ID before its description.
+    pub show_ids: bool,
 }
 
 pub struct SimpleTodoPrinter<Out: Write> {
@@ -85,6 +91,46 @@ fn get_initial_indent(
     }
 }
 
+// Draws the branches that connect a task to its parent in a tree, e.g.
+// "│   ├── " for a task that isn't the last child of its parent, and whose
+// parent isn't the last child of the root.
+fn fmt_tree_branches(position: &TreePosition, out: &mut String) {
+    let Some((&is_last, ancestors)) = position.is_last.split_last() else {
+        return;
+    };
+    let mut branches = String::new();
+    for &ancestor_is_last in ancestors {
+        branches.push_str(if ancestor_is_last { "    " } else { "│   " });
+    }
+    branches.push_str(if is_last { "└── " } else { "├── " });
+    out.push_str(&branches.dim().to_string());
+}
+
+// Continues the vertical lines of a tree for the wrapped lines of a task's
+// description.
+fn fmt_tree_continuation(position: &TreePosition, out: &mut String) {
+    let mut branches = String::new();
+    for &is_last in &position.is_last {
+        branches.push_str(if is_last { "    " } else { "│   " });
+    }
+    out.push_str(&branches.dim().to_string());
+}
+
+fn fmt_back_reference(out: &mut String) {
+    out.push(' ');
+    out.push_str(&"(see above)".dim().to_string());
+}
+
+fn fmt_notes_marker(out: &mut String) {
+    out.push(' ');
+    out.push_str(&"📝".dim().to_string());
+}
+
+fn fmt_short_id(short_id: &str, out: &mut String) {
+    out.push_str(&format!("#{short_id}").white().dim().to_string());
+    out.push(' ');
+}
+
 fn fmt_snooze_date(snooze_duration: Duration, out: &mut String) {
     if snooze_duration > chrono::Duration::zero() {
         out.push_str(
@@ -163,6 +209,108 @@ fn fmt_punctuality(punctuality: Duration, out: &mut String) {
     out.push(' ');
 }
 
+// Shows time spent in hours and minutes, which is more precise than
+// format_duration_laconic(), since it's compared against budgets.
+fn format_time_spent(time_spent: Duration) -> String {
+    let hours = time_spent.num_hours();
+    let minutes = time_spent.num_minutes() % 60;
+    if hours > 0 && minutes == 0 {
+        format!("{hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+// Shows how long was spent on the task, and its budget if it has one. The
+// time is red if it's over budget, and green if a complete task stayed
+// within its budget. A running timer is shown with a '▶'.
+fn fmt_time_spent(
+    task: &PrintableTask,
+    context: &PrintingContext,
+    out: &mut String,
+) {
+    let running = task
+        .timer_start
+        .map(|start| (context.now - start).max(Duration::zero()));
+    let time_spent = task.time_spent.unwrap_or_else(Duration::zero)
+        + running.unwrap_or_else(Duration::zero);
+    let verb = if task.status == Status::Complete {
+        "Took"
+    } else {
+        "Spent"
+    };
+    let spent = format_time_spent(time_spent);
+    let mut text = format!("{verb} {spent}");
+    if let Some(budget) = task.budget {
+        text.push_str(&format!(" of {}", format_time_spent(budget)));
+    }
+    // Only show the time with deps if it would look any different.
+    if let Some(with_deps) = task
+        .time_spent_with_deps
+        .map(format_time_spent)
+        .filter(|with_deps| *with_deps != spent)
+    {
+        text.push_str(&format!(" ({with_deps} with deps)"));
+    }
+    if running.is_some() {
+        text = format!("▶ {text}");
+    }
+    let style = match task.budget {
+        Some(budget) if time_spent > budget => Color::Red.bold(),
+        Some(_) if task.status == Status::Complete => Color::Green.bold(),
+        _ => Color::Cyan.bold(),
+    };
+    out.push_str(&text.paint(style).to_string());
+    out.push(' ');
+}
+
+// Shows when the task can be done at the earliest, and how much time there is
+// to spare before a due date is missed. Critical tasks are yellow, and tasks
+// that will miss a due date are red.
+fn fmt_schedule(
+    earliest_finish: DateTime<Utc>,
+    task: &PrintableTask,
+    context: &PrintingContext,
+    out: &mut String,
+) {
+    let mut text = if earliest_finish > context.now {
+        format!(
+            "Can be done in {}",
+            ::todo_time_format::format_duration_laconic(
+                earliest_finish - context.now
+            )
+        )
+    } else {
+        "Can be done now".to_string()
+    };
+    match task.slack {
+        Some(slack) if slack < Duration::zero() => text.push_str(&format!(
+            ", {} too late",
+            ::todo_time_format::format_duration_laconic(-slack)
+        )),
+        Some(slack) if slack == Duration::zero() => {
+            text.push_str(", no time to spare")
+        }
+        Some(slack) => text.push_str(&format!(
+            ", {} to spare",
+            ::todo_time_format::format_duration_laconic(slack)
+        )),
+        None => {}
+    }
+    if task.is_critical {
+        text = format!("Critical: {text}");
+    }
+    let style = match task.slack {
+        Some(slack) if slack < Duration::zero() => Color::Red.bold(),
+        _ if task.is_critical => Color::Yellow.bold(),
+        _ => Color::Green.bold(),
+    };
+    out.push_str(&text.paint(style).to_string());
+    out.push(' ');
+}
+
 // If the task has deps, show a lock icon, followed by the number of incomplete
 // deps and the number of total deps, as a fraction. E.g. if the task has 3
 // deps, 2 of which are incomplete, show "🔓 2/3".
@@ -282,6 +430,12 @@ fn get_body(
     prefix_length: usize,
 ) -> String {
     let mut body = String::new();
+    if let Some(position) = &task.tree_position {
+        fmt_tree_branches(position, &mut body);
+    }
+    if let Some(short_id) = task.short_id.filter(|_| context.show_ids) {
+        fmt_short_id(short_id, &mut body);
+    }
     if let Some(start_date) = task.start_date {
         fmt_snooze_date(start_date - context.now, &mut body);
     }
@@ -302,6 +456,15 @@ fn get_body(
     if let Some(punctuality) = task.punctuality {
         fmt_punctuality(punctuality, &mut body);
     }
+    if task.time_spent.is_some()
+        || task.timer_start.is_some()
+        || task.time_spent_with_deps.is_some()
+    {
+        fmt_time_spent(task, context, &mut body);
+    }
+    if let Some(earliest_finish) = task.earliest_finish {
+        fmt_schedule(earliest_finish, task, context, &mut body);
+    }
     use TruncationParams::NoTruncation;
     use TruncationParams::TruncateIfNeeded;
     let truncation_params = if task.truncate_tags_if_needed {
@@ -320,6 +483,16 @@ fn get_body(
     } else {
         body.push_str(task.desc);
     }
+    if task
+        .tree_position
+        .as_ref()
+        .is_some_and(|position| position.back_reference)
+    {
+        fmt_back_reference(&mut body);
+    }
+    if task.notes.is_some() && !task.show_notes {
+        fmt_notes_marker(&mut body);
+    }
     body
 }
 
@@ -334,11 +507,22 @@ fn get_subsequent_indent(
     };
     let total_offset =
         context.max_index_digits + SELECTOR_OFFSET + maybe_log_date_offset;
-    " ".repeat(total_offset)
+    let mut indent = " ".repeat(total_offset);
+    if let Some(position) = &task.tree_position {
+        fmt_tree_continuation(position, &mut indent);
+    }
+    indent
 }
 
 impl Display for PrintableTaskWithContext<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.task.agenda_heading {
+            Some(heading @ AgendaHeading::Overdue) => {
+                writeln!(f, "{}", heading.red().bold())?
+            }
+            Some(heading) => writeln!(f, "{}", heading.bold())?,
+            None => {}
+        }
         let start = get_initial_indent(self.task, self.context);
         let body = get_body(
             self.task,
@@ -356,7 +540,25 @@ impl Display for PrintableTaskWithContext<'_> {
                 .break_words(false)
                 .word_separator(textwrap::WordSeparator::AsciiSpace)
                 .subsequent_indent(&subsequent_indent),
-        ))
+        ))?;
+        if let Some(notes) = self.task.notes.filter(|_| self.task.show_notes) {
+            // Each line of the notes is wrapped on its own, lined up under
+            // the description.
+            for line in notes.lines() {
+                f.write_str("\n")?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                f.write_str(&textwrap::fill(
+                    line,
+                    textwrap::Options::new(self.context.width)
+                        .initial_indent(&subsequent_indent)
+                        .break_words(false)
+                        .subsequent_indent(&subsequent_indent),
+                ))?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -381,4 +583,11 @@ impl<'a, Out: Write> TodoPrinter<'a> for SimpleTodoPrinter<Out> {
     fn print_error(&mut self, error: &PrintableError) {
         writeln!(self.out, "{}", error).unwrap_or_default();
     }
+    fn print_document(&mut self, document: &str) {
+        write!(self.out, "{}", document).unwrap_or_default();
+    }
+
+    fn print_report(&mut self, report: &PrintableReport) {
+        write!(self.out, "{}", report.text).unwrap_or_default();
+    }
 }
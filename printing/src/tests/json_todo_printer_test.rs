@@ -0,0 +1,332 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Duration;
+use serde_json::json;
+use serde_json::Value;
+use todo_lookup_key::Key;
+use todo_testing::ymdhms;
+
+use crate::Action;
+use crate::AgendaHeading;
+use crate::BriefPrintableTask;
+use crate::JsonFormat;
+use crate::JsonTodoPrinter;
+use crate::LogDate;
+use crate::Plicit;
+use crate::PrintableError;
+use crate::PrintableInfo;
+use crate::PrintableReport;
+use crate::PrintableTask;
+use crate::PrintableWarning;
+use crate::Status;
+use crate::TodoPrinter;
+use crate::TreePosition;
+
+fn print_json(
+    format: JsonFormat,
+    mutated: bool,
+    print: impl FnOnce(&mut JsonTodoPrinter<&mut Vec<u8>>),
+) -> String {
+    let mut out = Vec::new();
+    let mut printer = JsonTodoPrinter::new(&mut out, format);
+    print(&mut printer);
+    printer.finish(mutated);
+    String::from_utf8(out).unwrap()
+}
+
+fn parse_lines(s: &str) -> Vec<Value> {
+    s.lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn empty_json() {
+    let out = print_json(JsonFormat::Json, false, |_| {});
+    assert_eq!(
+        serde_json::from_str::<Value>(&out).unwrap(),
+        json!({
+            "mutated": false,
+            "tasks": [],
+            "infos": [],
+            "warnings": [],
+            "errors": [],
+            "documents": [],
+            "reports": [],
+        })
+    );
+}
+
+#[test]
+fn empty_jsonl() {
+    let out = print_json(JsonFormat::Jsonl, true, |_| {});
+    assert_eq!(
+        parse_lines(&out),
+        vec![json!({"type": "result", "mutated": true})]
+    );
+}
+
+#[test]
+fn minimal_task() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_task(&PrintableTask::new("a", 1, Status::Incomplete));
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(
+        value["tasks"],
+        json!([{
+            "number": 1,
+            "id": null,
+            "desc": "a",
+            "status": "incomplete",
+            "action": "none",
+            "log_date": null,
+            "priority": null,
+            "due_date": null,
+            "punctuality_secs": null,
+            "budget_secs": null,
+            "start_date": null,
+            "deps": {"immediate": 0, "total": 0},
+            "adeps": {"immediate": 0, "total": 0},
+            "is_tag": false,
+            "tags": [],
+            "tree": null,
+            "notes": null,
+            "time_spent_secs": null,
+            "timer_start": null,
+            "time_spent_with_deps_secs": null,
+            "earliest_finish": null,
+            "slack_secs": null,
+            "is_critical": false,
+            "agenda": null,
+        }])
+    );
+}
+
+#[test]
+fn task_with_every_field() {
+    let out = print_json(JsonFormat::Json, true, |printer| {
+        printer.print_task(
+            &PrintableTask::new("b", -2, Status::Complete)
+                .short_id("a3f9")
+                .action(Action::Check)
+                .log_date(LogDate::YearMonthDay(2021, 05, 01))
+                .priority(Plicit::Explicit(2))
+                .due_date(Plicit::Implicit(ymdhms(2021, 05, 02, 17, 00, 00)))
+                .punctuality(-Duration::hours(1))
+                .budget(Duration::minutes(30))
+                .start_date(ymdhms(2021, 04, 30, 00, 00, 00))
+                .deps_stats(1, 3)
+                .adeps_stats(2, 4)
+                .as_tag()
+                .tag("chores")
+                .notes("line 1\nline 2")
+                .time_spent(Duration::minutes(10))
+                .timer_start(ymdhms(2021, 05, 01, 09, 00, 00))
+                .time_spent_with_deps(Duration::minutes(20))
+                .earliest_finish(ymdhms(2021, 05, 02, 12, 00, 00))
+                .slack(-Duration::hours(2))
+                .critical(true)
+                .agenda_heading(AgendaHeading::Today),
+        );
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["mutated"], json!(true));
+    assert_eq!(
+        value["tasks"],
+        json!([{
+            "number": -2,
+            "id": "a3f9",
+            "desc": "b",
+            "status": "complete",
+            "action": "check",
+            "log_date": "2021-05-01",
+            "priority": {"value": 2, "explicit": true},
+            "due_date": {"value": "2021-05-02T17:00:00Z", "explicit": false},
+            "punctuality_secs": -3600,
+            "budget_secs": 1800,
+            "start_date": "2021-04-30T00:00:00Z",
+            "deps": {"immediate": 1, "total": 3},
+            "adeps": {"immediate": 2, "total": 4},
+            "is_tag": true,
+            "tags": ["chores"],
+            "tree": null,
+            "notes": "line 1\nline 2",
+            "time_spent_secs": 600,
+            "timer_start": "2021-05-01T09:00:00Z",
+            "time_spent_with_deps_secs": 1200,
+            "earliest_finish": "2021-05-02T12:00:00Z",
+            "slack_secs": -7200,
+            "is_critical": true,
+            "agenda": "today",
+        }])
+    );
+}
+
+#[test]
+fn invisible_log_date_is_null() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_task(
+            &PrintableTask::new("a", 0, Status::Complete)
+                .log_date(LogDate::Invisible),
+        );
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["tasks"][0]["log_date"], Value::Null);
+}
+
+#[test]
+fn agenda_day_is_date() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_task(
+            &PrintableTask::new("a", 1, Status::Incomplete).agenda_heading(
+                AgendaHeading::Day(
+                    chrono::NaiveDate::from_ymd_opt(2021, 05, 04).unwrap(),
+                ),
+            ),
+        );
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["tasks"][0]["agenda"], json!("2021-05-04"));
+}
+
+#[test]
+fn task_in_tree() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_task(
+            &PrintableTask::new("a", 1, Status::Incomplete).tree_position(
+                TreePosition {
+                    is_last: vec![false, true],
+                    back_reference: true,
+                },
+            ),
+        );
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(
+        value["tasks"][0]["tree"],
+        json!({"depth": 2, "back_reference": true})
+    );
+}
+
+#[test]
+fn info_with_fields() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_info(&PrintableInfo::ConfigValue {
+            key: "undo_limit".to_string(),
+            value: "20".to_string(),
+        });
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(
+        value["infos"],
+        json!([{
+            "kind": "config_value",
+            "details": {"key": "undo_limit", "value": "20"},
+            "message": "undo_limit = 20",
+        }])
+    );
+}
+
+#[test]
+fn warning_without_fields() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_warning(&PrintableWarning::NothingToUndo);
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["warnings"][0]["kind"], json!("nothing_to_undo"));
+    assert_eq!(value["warnings"][0].get("details"), None);
+}
+
+#[test]
+fn warning_with_key_and_tasks() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_warning(&PrintableWarning::AmbiguousKey {
+            key: Key::ByName("a".to_string()),
+            matches: vec![
+                BriefPrintableTask::new(1, Status::Incomplete),
+                BriefPrintableTask::new(2, Status::Blocked),
+            ],
+        });
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["warnings"][0]["kind"], json!("ambiguous_key"));
+    assert_eq!(
+        value["warnings"][0]["details"],
+        json!({
+            "key": "a",
+            "matches": [
+                {"number": 1, "status": "incomplete"},
+                {"number": 2, "status": "blocked"},
+            ],
+        })
+    );
+}
+
+#[test]
+fn error_with_keys() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_error(&PrintableError::NoMatchForKeys {
+            keys: vec![
+                Key::ByNumber(10),
+                Key::ByName("b".to_string()),
+                Key::ByRange(1, 3),
+                Key::ById("a3f9".to_string()),
+            ],
+        });
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(
+        value["errors"],
+        json!([{
+            "kind": "no_match_for_keys",
+            "details": {"keys": ["10", "b", "1..3", "#a3f9"]},
+            "message":
+                "No match for keys \"10\", \"b\", range(1..3), \"#a3f9\"",
+        }])
+    );
+}
+
+#[test]
+fn document() {
+    let out = print_json(JsonFormat::Json, false, |printer| {
+        printer.print_document("digraph {}\n");
+    });
+    let value = serde_json::from_str::<Value>(&out).unwrap();
+    assert_eq!(value["documents"], json!([{"content": "digraph {}\n"}]));
+}
+
+#[test]
+fn report() {
+    let out = print_json(JsonFormat::Jsonl, false, |printer| {
+        printer.print_report(&PrintableReport {
+            text: "Created  1\n".to_string(),
+            json: json!({"created": 1}),
+        });
+    });
+    let lines = out
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines[0], json!({"type": "report", "created": 1}));
+}
+
+#[test]
+fn jsonl_prints_one_record_per_line() {
+    let out = print_json(JsonFormat::Jsonl, true, |printer| {
+        printer.print_warning(&PrintableWarning::NothingToRedo);
+        printer.print_info(&PrintableInfo::Removed {
+            desc: "a".to_string(),
+        });
+        printer.print_task(&PrintableTask::new("b", 1, Status::Incomplete));
+    });
+    let values = parse_lines(&out);
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[0]["type"], json!("warning"));
+    assert_eq!(values[0]["kind"], json!("nothing_to_redo"));
+    assert_eq!(values[1]["type"], json!("info"));
+    assert_eq!(values[1]["message"], json!("Removed \"a\""));
+    assert_eq!(values[2]["type"], json!("task"));
+    assert_eq!(values[2]["desc"], json!("b"));
+    assert_eq!(values[3], json!({"type": "result", "mutated": true}));
+}
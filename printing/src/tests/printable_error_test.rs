@@ -95,3 +95,195 @@ fn display_failed_to_use_text_editor_error() {
         "\u{1b}[1;31merror\u{1b}[0m: Failed to open text editor"
     );
 }
+
+#[test]
+fn display_cannot_parse_config_value_error() {
+    let fmt = format!(
+        "{}",
+        CannotParseConfigValue {
+            key: "paginator_cmd".to_string(),
+            value: "\"less".to_string(),
+            explanation: "unbalanced quotes".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: ",
+            "Cannot parse value \"\"less\" for config key ",
+            "\u{1b}[1;37mpaginator_cmd\u{1b}[0m: unbalanced quotes"
+        )
+    );
+}
+
+#[test]
+fn display_cannot_parse_query_error() {
+    let fmt = format!(
+        "{}",
+        CannotParseQuery {
+            query: "due<someday".to_string(),
+            explanation: "cannot parse date \"someday\"".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: Cannot parse query ",
+            "\u{1b}[1;37mdue<someday\u{1b}[0m: cannot parse date \"someday\""
+        )
+    );
+}
+
+#[test]
+fn display_cannot_read_file_error() {
+    let fmt = format!(
+        "{}",
+        CannotReadFile {
+            path: "todo.txt".to_string(),
+            explanation: "No such file or directory".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: Cannot read ",
+            "\u{1b}[1;37mtodo.txt\u{1b}[0m: No such file or directory"
+        )
+    );
+}
+
+#[test]
+fn display_cannot_parse_file_error() {
+    let fmt = format!(
+        "{}",
+        CannotParseFile {
+            path: "tasks.json".to_string(),
+            explanation: "EOF while parsing a list".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: Cannot parse ",
+            "\u{1b}[1;37mtasks.json\u{1b}[0m: EOF while parsing a list"
+        )
+    );
+}
+
+#[test]
+fn display_cannot_use_terminal_error() {
+    let fmt = format!(
+        "{}",
+        CannotUseTerminal {
+            explanation: "not a terminal".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        "\u{1b}[1;31merror\u{1b}[0m: Cannot use the terminal: not a terminal"
+    );
+}
+
+#[test]
+fn display_cannot_parse_recurrence_error() {
+    let fmt = format!(
+        "{}",
+        CannotParseRecurrence {
+            cannot_parse: "sometimes".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: ",
+            "Cannot parse recurrence: \u{1b}[1;37msometimes\u{1b}[0m.\n",
+            "\u{1b}[1;2;37mnote\u{1b}[0m: Try something like \"daily\", ",
+            "\"every weekday\", \"every 2 weeks\", or \"monthly on the 1st\"."
+        )
+    );
+}
+
+#[test]
+fn display_cannot_merge_recurrences_error() {
+    let fmt = format!(
+        "{}",
+        CannotMergeRecurrences {
+            recurring: vec![
+                BriefPrintableTask::new(1, Incomplete),
+                BriefPrintableTask::new(2, Incomplete)
+            ],
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: Cannot merge: tasks ",
+            "\u{1b}[33m1)\u{1b}[0m, \u{1b}[33m2)\u{1b}[0m repeat on ",
+            "different schedules"
+        )
+    );
+}
+
+#[test]
+fn display_cannot_parse_since_date_error() {
+    let fmt = format!(
+        "{}",
+        CannotParseSinceDate {
+            cannot_parse: "whenever".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: ",
+            "Cannot parse start of period: \u{1b}[1;37mwhenever\u{1b}[0m"
+        )
+    );
+}
+
+#[test]
+fn display_no_such_backup_error() {
+    let fmt = format!("{}", NoSuchBackup { index: 7 });
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: No backup numbered ",
+            "\u{1b}[1;37m7\u{1b}[0m.\n",
+            "\u{1b}[1;2;37mnote\u{1b}[0m: ",
+            "Run 'todo recover' to see the available backups."
+        )
+    );
+}
+
+#[test]
+fn display_cannot_read_backup_error() {
+    let fmt = format!("{}", CannotReadBackup { index: 2 });
+    assert_eq!(
+        fmt,
+        "\u{1b}[1;31merror\u{1b}[0m: Backup \u{1b}[1;37m2\u{1b}[0m is not a valid list"
+    );
+}
+
+#[test]
+fn display_batch_command_failed_error() {
+    let fmt = format!(
+        "{}",
+        BatchCommandFailed {
+            line: 3,
+            command: "check 9".to_string(),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;31merror\u{1b}[0m: Stopped at line 3 of the batch ",
+            "(\u{1b}[1;37mcheck 9\u{1b}[0m); nothing was changed"
+        )
+    );
+}
+
+#[test]
+fn display_already_in_shell_error() {
+    let fmt = format!("{}", AlreadyInShell);
+    assert_eq!(fmt, "\u{1b}[1;31merror\u{1b}[0m: Already in the shell");
+}
@@ -1,3 +1,7 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use todo_testing::ymdhms;
+
 use crate::PrintableInfo::*;
 
 #[test]
@@ -10,3 +14,74 @@ fn display_info_removed() {
         "\u{1b}[1;2;37minfo\u{1b}[0m: Removed \"foo\""
     );
 }
+
+#[test]
+fn display_info_config_value() {
+    let info = ConfigValue {
+        key: "text_editor_cmd".to_string(),
+        value: "vim".to_string(),
+    };
+    assert_eq!(
+        format!("{}", info),
+        "\u{1b}[1;2;37minfo\u{1b}[0m: \u{1b}[1;37mtext_editor_cmd\u{1b}[0m = vim"
+    );
+}
+
+#[test]
+fn display_info_backup() {
+    let info = Backup {
+        index: 2,
+        modified: ymdhms(2024, 03, 01, 09, 30, 00),
+        num_tasks: Some(12),
+    };
+    assert_eq!(
+        format!("{}", info),
+        "\u{1b}[1;2;37minfo\u{1b}[0m: \u{1b}[1;37m2)\u{1b}[0m 2024-03-01 09:30 (12 tasks)"
+    );
+}
+
+#[test]
+fn display_info_backup_with_one_task() {
+    let info = Backup {
+        index: 1,
+        modified: ymdhms(2024, 03, 01, 09, 30, 00),
+        num_tasks: Some(1),
+    };
+    assert_eq!(
+        format!("{}", info),
+        "\u{1b}[1;2;37minfo\u{1b}[0m: \u{1b}[1;37m1)\u{1b}[0m 2024-03-01 09:30 (1 task)"
+    );
+}
+
+#[test]
+fn display_info_unreadable_backup() {
+    let info = Backup {
+        index: 1,
+        modified: ymdhms(2024, 03, 01, 09, 30, 00),
+        num_tasks: None,
+    };
+    assert_eq!(
+        format!("{}", info),
+        "\u{1b}[1;2;37minfo\u{1b}[0m: \u{1b}[1;37m1)\u{1b}[0m 2024-03-01 09:30 \u{1b}[31m(unreadable)\u{1b}[0m"
+    );
+}
+
+#[test]
+fn display_info_recovered() {
+    let info = Recovered { index: 3 };
+    assert_eq!(
+        format!("{}", info),
+        "\u{1b}[1;2;37minfo\u{1b}[0m: Recovered the list from backup 3"
+    );
+}
+
+#[test]
+fn display_info_dry_run() {
+    assert_eq!(
+        format!("{}", DryRun),
+        concat!(
+            "\u{1b}[1;2;37minfo\u{1b}[0m: \u{1b}[1;33m(dry run)\u{1b}[0m ",
+            "Nothing will be saved"
+        )
+    );
+}
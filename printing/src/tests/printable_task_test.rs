@@ -5,6 +5,7 @@ use chrono::Utc;
 use todo_testing::ymdhms;
 
 use crate::Action::*;
+use crate::AgendaHeading;
 use crate::LogDate::*;
 use crate::Plicit::*;
 use crate::PrintableTask;
@@ -12,12 +13,14 @@ use crate::PrintingContext;
 use crate::SimpleTodoPrinter;
 use crate::Status::*;
 use crate::TodoPrinter;
+use crate::TreePosition;
 
 fn make_printing_context() -> PrintingContext {
     PrintingContext {
         max_index_digits: 3,
         width: 80,
         now: Utc::now(),
+        show_ids: false,
     }
 }
 
@@ -26,6 +29,7 @@ fn now_context(now: DateTime<Utc>) -> PrintingContext {
         max_index_digits: 3,
         width: 80,
         now,
+        show_ids: false,
     }
 }
 
@@ -74,6 +78,99 @@ fn fmt_blocked_task() {
     assert_eq!(fmt, "      \u{1b}[31m2)\u{1b}[0m c\n");
 }
 
+#[test]
+fn fmt_task_hides_short_id_by_default() {
+    let fmt =
+        print_task(&PrintableTask::new("a", 1, Incomplete).short_id("a3f9"));
+    assert_eq!(fmt, "      \u{1b}[33m1)\u{1b}[0m a\n");
+}
+
+#[test]
+fn fmt_task_with_short_id() {
+    let fmt = print_task_with_context(
+        PrintingContext {
+            show_ids: true,
+            ..make_printing_context()
+        },
+        &PrintableTask::new("a", 1, Incomplete).short_id("a3f9"),
+    );
+    assert_eq!(
+        fmt,
+        "      \u{1b}[33m1)\u{1b}[0m \u{1b}[2;37m#a3f9\u{1b}[0m a\n"
+    );
+}
+
+#[test]
+fn fmt_tree_root() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Incomplete)
+            .tree_position(TreePosition::default()),
+    );
+    assert_eq!(fmt, "      \u{1b}[33m1)\u{1b}[0m a\n");
+}
+
+#[test]
+fn fmt_tree_children() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 2, Incomplete).tree_position(TreePosition {
+            is_last: vec![false],
+            back_reference: false,
+        }),
+    );
+    assert_eq!(fmt, "      \u{1b}[33m2)\u{1b}[0m \u{1b}[2m├── \u{1b}[0ma\n");
+    let fmt = print_task(
+        &PrintableTask::new("a", 2, Incomplete).tree_position(TreePosition {
+            is_last: vec![false, true, true],
+            back_reference: false,
+        }),
+    );
+    assert_eq!(
+        fmt,
+        "      \u{1b}[33m2)\u{1b}[0m \u{1b}[2m│       └── \u{1b}[0ma\n"
+    );
+}
+
+#[test]
+fn fmt_tree_back_reference() {
+    let fmt = print_task(&PrintableTask::new("a", 2, Blocked).tree_position(
+        TreePosition {
+            is_last: vec![true],
+            back_reference: true,
+        },
+    ));
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[31m2)\u{1b}[0m \u{1b}[2m└── \u{1b}[0ma ",
+            "\u{1b}[2m(see above)\u{1b}[0m\n"
+        )
+    );
+}
+
+#[test]
+fn fmt_task_with_notes() {
+    let fmt = print_task(&PrintableTask::new("a", 1, Incomplete).notes("b"));
+    assert_eq!(fmt, "      \u{1b}[33m1)\u{1b}[0m a \u{1b}[2m📝\u{1b}[0m\n");
+}
+
+#[test]
+fn fmt_task_showing_notes() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Incomplete)
+            .notes("first line\n\nthird line")
+            .show_notes(true),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m a\n",
+            "         first line\n",
+            "\n",
+            "         third line\n"
+        )
+    );
+}
+
 #[test]
 fn fmt_double_digit_number_in_max_four_digit_environment() {
     let fmt = print_task_with_context(
@@ -81,6 +178,7 @@ fn fmt_double_digit_number_in_max_four_digit_environment() {
             max_index_digits: 4,
             width: 80,
             now: Utc::now(),
+            show_ids: false,
         },
         &PrintableTask::new("hello", 99, Blocked),
     );
@@ -94,6 +192,7 @@ fn fmt_triple_digit_number_in_max_four_digit_environment() {
             max_index_digits: 4,
             width: 80,
             now: Utc::now(),
+            show_ids: false,
         },
         &PrintableTask::new("hello", 100, Blocked),
     );
@@ -123,6 +222,7 @@ fn text_wrapping() {
         max_index_digits: 3,
         width: 24,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -148,6 +248,7 @@ fn text_wrapping_with_log_date() {
         max_index_digits: 3,
         width: 34,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -207,6 +308,44 @@ fn invisible_log_date() {
     );
 }
 
+#[test]
+fn agenda_heading_above_task() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Incomplete)
+            .agenda_heading(AgendaHeading::Tomorrow),
+    );
+    assert_eq!(
+        fmt,
+        "\u{1b}[1mTomorrow\u{1b}[0m\n      \u{1b}[33m1)\u{1b}[0m a\n"
+    );
+}
+
+#[test]
+fn overdue_agenda_heading_is_red() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Incomplete)
+            .agenda_heading(AgendaHeading::Overdue),
+    );
+    assert_eq!(
+        fmt,
+        "\u{1b}[1;31mOverdue\u{1b}[0m\n      \u{1b}[33m1)\u{1b}[0m a\n"
+    );
+}
+
+#[test]
+fn agenda_heading_for_day_shows_weekday() {
+    let fmt =
+        print_task(&PrintableTask::new("a", 1, Incomplete).agenda_heading(
+            AgendaHeading::Day(
+                chrono::NaiveDate::from_ymd_opt(2021, 05, 04).unwrap(),
+            ),
+        ));
+    assert_eq!(
+        fmt,
+        "\u{1b}[1mTuesday May 4\u{1b}[0m\n      \u{1b}[33m1)\u{1b}[0m a\n"
+    );
+}
+
 #[test]
 fn show_implicit_priority_on_task() {
     let fmt = print_task(
@@ -632,6 +771,7 @@ fn do_not_split_url() {
         max_index_digits: 3,
         width: 40,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -656,6 +796,7 @@ fn truncate_tags_if_they_do_not_fit_on_line() {
         max_index_digits: 3,
         width: 45,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -684,6 +825,7 @@ fn do_not_truncate_tags_if_not_opted_in() {
         max_index_digits: 3,
         width: 45,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -714,6 +856,7 @@ fn start_description_on_new_line_if_tags_are_truncated() {
         // separate the tags from the description.
         width: 47,
         now: Utc::now(),
+        show_ids: false,
     };
     let fmt = print_task_with_context(
         context,
@@ -734,3 +877,167 @@ fn start_description_on_new_line_if_tags_are_truncated() {
         )
     );
 }
+
+#[test]
+fn show_time_spent_without_budget() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Incomplete)
+            .time_spent(chrono::Duration::minutes(90)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m ",
+            "\u{1b}[1;36mSpent 1h 30m\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_time_taken_within_budget() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 0, Complete)
+            .time_spent(chrono::Duration::minutes(45))
+            .budget(chrono::Duration::hours(1)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[32m0)\u{1b}[0m ",
+            "\u{1b}[1;32mTook 45m of 1h\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_time_taken_over_budget() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 0, Complete)
+            .time_spent(chrono::Duration::minutes(75))
+            .budget(chrono::Duration::hours(1)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[32m0)\u{1b}[0m ",
+            "\u{1b}[1;31mTook 1h 15m of 1h\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_running_timer() {
+    let now = ymdhms(2024, 03, 01, 10, 00, 00);
+    let fmt = print_task_with_context(
+        now_context(now),
+        &PrintableTask::new("a", 1, Incomplete)
+            .time_spent(chrono::Duration::minutes(10))
+            .timer_start(ymdhms(2024, 03, 01, 09, 30, 00)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m ",
+            "\u{1b}[1;36m▶ Spent 40m\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_time_spent_with_deps() {
+    let fmt = print_task(
+        &PrintableTask::new("a", 1, Blocked)
+            .time_spent(chrono::Duration::minutes(10))
+            .time_spent_with_deps(chrono::Duration::hours(2)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[31m1)\u{1b}[0m ",
+            "\u{1b}[1;36mSpent 10m (2h with deps)\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_start_and_stop_actions() {
+    let fmt = print_task(&PrintableTask::new("a", 1, Incomplete).action(Start));
+    assert_eq!(fmt, " \u{1b}[32m▶\u{1b}[0m    \u{1b}[33m1)\u{1b}[0m a\n");
+    let fmt = print_task(&PrintableTask::new("a", 1, Incomplete).action(Stop));
+    assert_eq!(fmt, " \u{1b}[33m■\u{1b}[0m    \u{1b}[33m1)\u{1b}[0m a\n");
+}
+
+#[test]
+fn show_schedule_with_time_to_spare() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let fmt = print_task_with_context(
+        now_context(now),
+        &PrintableTask::new("a", 1, Incomplete)
+            .earliest_finish(ymdhms(2024, 03, 01, 12, 00, 00))
+            .slack(chrono::Duration::days(2)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m ",
+            "\u{1b}[1;32mCan be done in 3 hours, 2 days to spare\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_schedule_on_critical_path() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let fmt = print_task_with_context(
+        now_context(now),
+        &PrintableTask::new("a", 1, Incomplete)
+            .earliest_finish(now)
+            .slack(chrono::Duration::zero())
+            .critical(true),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m ",
+            "\u{1b}[1;33mCritical: Can be done now, no time to spare\u{1b}[0m ",
+            "a\n"
+        )
+    );
+}
+
+#[test]
+fn show_schedule_too_late() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let fmt = print_task_with_context(
+        now_context(now),
+        &PrintableTask::new("a", 1, Blocked)
+            .earliest_finish(ymdhms(2024, 03, 02, 09, 00, 00))
+            .slack(-chrono::Duration::hours(5))
+            .critical(true),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[31m1)\u{1b}[0m ",
+            "\u{1b}[1;31mCritical: Can be done in 1 day, 5 hours too late",
+            "\u{1b}[0m a\n"
+        )
+    );
+}
+
+#[test]
+fn show_schedule_without_due_date() {
+    let now = ymdhms(2024, 03, 01, 09, 00, 00);
+    let fmt = print_task_with_context(
+        now_context(now),
+        &PrintableTask::new("a", 1, Incomplete)
+            .earliest_finish(ymdhms(2024, 03, 01, 10, 00, 00)),
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "      \u{1b}[33m1)\u{1b}[0m ",
+            "\u{1b}[1;32mCan be done in 1 hour\u{1b}[0m a\n"
+        )
+    );
+}
@@ -1,6 +1,10 @@
+#![allow(clippy::zero_prefixed_literal)]
+
 use todo_lookup_key::Key::*;
+use todo_testing::ymdhms;
 
 use crate::BriefPrintableTask;
+use crate::PrintableError;
 use crate::PrintableWarning::*;
 use crate::Status::*;
 
@@ -147,3 +151,92 @@ fn display_cannot_unsnooze_because_not_snoozed() {
         )
     );
 }
+
+#[test]
+fn display_no_backups_warning() {
+    let fmt = format!("{}", NoBackups);
+    assert_eq!(fmt, "\u{1b}[1;33mwarning\u{1b}[0m: No backups found");
+}
+
+#[test]
+fn display_cannot_start_timer_because_complete_warning() {
+    let fmt = format!(
+        "{}",
+        CannotStartTimerBecauseComplete {
+            cannot_start: BriefPrintableTask::new(-1, Complete),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;33mwarning\u{1b}[0m: ",
+            "Cannot start a timer on \u{1b}[32m-1)\u{1b}[0m because it is ",
+            "complete"
+        )
+    );
+}
+
+#[test]
+fn display_timer_already_running_warning() {
+    let fmt = format!(
+        "{}",
+        TimerAlreadyRunning {
+            timed_task: BriefPrintableTask::new(2, Incomplete),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;33mwarning\u{1b}[0m: ",
+            "The timer is already running on \u{1b}[33m2)\u{1b}[0m"
+        )
+    );
+}
+
+#[test]
+fn display_no_timer_running_warning() {
+    let fmt = format!("{}", NoTimerRunning);
+    assert_eq!(fmt, "\u{1b}[1;33mwarning\u{1b}[0m: No timer is running");
+}
+
+#[test]
+fn display_cannot_meet_due_date_warning() {
+    let fmt = format!(
+        "{}",
+        CannotMeetDueDate {
+            late_task: BriefPrintableTask::new(3, Blocked),
+            latest_finish: ymdhms(2024, 03, 01, 17, 00, 00),
+            earliest_finish: ymdhms(2024, 03, 01, 19, 00, 00),
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;33mwarning\u{1b}[0m: \u{1b}[31m3)\u{1b}[0m must be done ",
+            "by 2024-03-01 17:00:00 UTC to meet due dates, but can't be done ",
+            "until 2024-03-01 19:00:00 UTC"
+        )
+    );
+}
+
+#[test]
+fn display_skipped_batch_command_warning() {
+    let fmt = format!(
+        "{}",
+        SkippedBatchCommand {
+            line: 2,
+            command: "check $x".to_string(),
+            errors: vec![PrintableError::UnknownBatchName {
+                name: "x".to_string(),
+            }],
+        }
+    );
+    assert_eq!(
+        fmt,
+        concat!(
+            "\u{1b}[1;33mwarning\u{1b}[0m: Skipped line 2 of the batch ",
+            "(\u{1b}[1;37mcheck $x\u{1b}[0m): ",
+            "No task was named $x earlier in the batch"
+        )
+    );
+}
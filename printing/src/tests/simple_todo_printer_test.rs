@@ -2,12 +2,14 @@
 
 use std::io::Write;
 
+use serde_json::json;
 use todo_lookup_key::Key;
 use todo_testing::ymdhms;
 
 use crate::BriefPrintableTask;
 use crate::PrintableError;
 use crate::PrintableInfo;
+use crate::PrintableReport;
 use crate::PrintableTask;
 use crate::PrintableWarning;
 use crate::PrintingContext;
@@ -22,6 +24,7 @@ fn create_printer_to_vec() -> SimpleTodoPrinter<Vec<u8>> {
             max_index_digits: 3,
             width: 80,
             now: ymdhms(2022, 02, 22, 2, 22, 22),
+            show_ids: false,
         },
     }
 }
@@ -51,6 +54,7 @@ fn create_printer_to_broken_pipe() -> SimpleTodoPrinter<BrokenPipe> {
             max_index_digits: 3,
             width: 80,
             now: ymdhms(2022, 02, 22, 2, 22, 22),
+            show_ids: false,
         },
     }
 }
@@ -78,6 +82,25 @@ fn print_multiple_tasks() {
     );
 }
 
+#[test]
+fn print_document_verbatim() {
+    let mut printer = create_printer_to_vec();
+    printer.print_document("digraph {\n}\n");
+    let out = String::from_utf8(printer.out).unwrap();
+    assert_eq!(out, "digraph {\n}\n");
+}
+
+#[test]
+fn print_report_as_text() {
+    let mut printer = create_printer_to_vec();
+    printer.print_report(&PrintableReport {
+        text: "Created  1\n".to_string(),
+        json: json!({"created": 1}),
+    });
+    let out = String::from_utf8(printer.out).unwrap();
+    assert_eq!(out, "Created  1\n");
+}
+
 #[test]
 fn print_task_to_broken_pipe() {
     let mut printer = create_printer_to_broken_pipe();
@@ -112,3 +135,9 @@ fn print_info_to_broken_pipe() {
         desc: "a".to_string(),
     });
 }
+
+#[test]
+fn print_document_to_broken_pipe() {
+    let mut printer = create_printer_to_broken_pipe();
+    printer.print_document("digraph {\n}\n");
+}
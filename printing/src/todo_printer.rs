@@ -5,4 +5,8 @@ pub trait TodoPrinter<'a> {
     fn print_info(&mut self, info: &PrintableInfo);
     fn print_warning(&mut self, warning: &PrintableWarning);
     fn print_error(&mut self, error: &PrintableError);
+    /// Prints a document, like an exported file, exactly as given.
+    fn print_document(&mut self, document: &str);
+    /// Prints a report as text, or as JSON if the printer prints JSON.
+    fn print_report(&mut self, report: &PrintableReport);
 }
@@ -0,0 +1,236 @@
+//! A small expression language for selecting tasks.
+//!
+//! A query is made of filters combined with `and`, `or`, `not`, and
+//! parentheses, e.g. `due<friday and (priority>=2 or tag:work)`. `not` binds
+//! tighter than `and`, which binds tighter than `or`.
+//!
+//! A filter is either a status (`blocked`, `complete` or `done`,
+//! `incomplete`, `snoozed`, or `tag`), or a field, an operator, and a value:
+//!
+//! - `due`, `created`, `completed`, and `start` compare dates, which are parsed
+//!   like the dates given to 'todo due', e.g. `due<friday` or
+//!   `created>last monday`. A date covers a whole day unless it has a time of
+//!   day, so `due=today` matches anything due today, `due<today` matches
+//!   anything due before today, and `due<=today` includes today.
+//! - `priority` compares numbers, e.g. `priority>=2`.
+//! - `budget` compares durations, e.g. `budget>2h`.
+//! - `desc` matches descriptions: `desc:milk` matches descriptions containing
+//!   "milk", `desc=milk` matches only "milk", and `desc~/^buy/` matches a
+//!   regular expression. The first two ignore case.
+//! - `tag` matches the names of the tags a task is in (or the task's own name,
+//!   if it is a tag), with the same operators as `desc`. `tag:work` matches
+//!   tasks tagged "work".
+//! - `id` matches short IDs, e.g. `id:a3f9`.
+//! - `status` matches the statuses above, e.g. `status=blocked`.
+//!
+//! The operators are `<`, `<=`, `>`, `>=`, `=`, `!=`, `:`, and `~`. For
+//! fields other than `desc`, `:` is the same as `=`. A value extends up to the
+//! next `and`, `or`, or parenthesis, so it can contain spaces; to use one of
+//! those words in a value, put the value in double quotes.
+
+use chrono::DateTime;
+use chrono::Utc;
+use regex::Regex;
+use todo_model::TaskId;
+use todo_model::TaskStatus;
+use todo_model::TodoList;
+
+mod parse;
+
+pub use self::parse::parse;
+pub use self::parse::ParseQueryError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusFilter {
+    Incomplete,
+    Blocked,
+    Complete,
+    Snoozed,
+    Tag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateField {
+    Due,
+    Created,
+    Completed,
+    Start,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextField {
+    Desc,
+    Tag,
+    Id,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+#[derive(Debug, Clone)]
+pub enum TextMatch {
+    Contains(String),
+    Equals(String),
+    NotEquals(String),
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Status(StatusFilter),
+    /// Compares a date against a range of times. A date without a time of day
+    /// covers the whole day; a precise time is a range with one instant.
+    Date {
+        field: DateField,
+        comparison: Comparison,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    Priority(Comparison, i32),
+    Budget(Comparison, u64),
+    Text(TextField, TextMatch),
+}
+
+fn compare<T: PartialOrd>(comparison: Comparison, a: T, b: T) -> bool {
+    match comparison {
+        Comparison::Less => a < b,
+        Comparison::LessOrEqual => a <= b,
+        Comparison::Greater => a > b,
+        Comparison::GreaterOrEqual => a >= b,
+        Comparison::Equal => a == b,
+        Comparison::NotEqual => a != b,
+    }
+}
+
+fn compare_date(
+    comparison: Comparison,
+    date: DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> bool {
+    match comparison {
+        Comparison::Less => date < start,
+        Comparison::LessOrEqual => date <= end,
+        Comparison::Greater => date > end,
+        Comparison::GreaterOrEqual => date >= start,
+        Comparison::Equal => start <= date && date <= end,
+        Comparison::NotEqual => date < start || end < date,
+    }
+}
+
+impl TextMatch {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            TextMatch::Contains(s) => {
+                text.to_lowercase().contains(&s.to_lowercase())
+            }
+            TextMatch::Equals(s) => text.eq_ignore_ascii_case(s),
+            TextMatch::NotEquals(s) => !text.eq_ignore_ascii_case(s),
+            TextMatch::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+fn matches_status(list: &TodoList, id: TaskId, filter: StatusFilter) -> bool {
+    let (Some(task), Some(status)) = (list.get(id), list.status(id)) else {
+        return false;
+    };
+    match filter {
+        StatusFilter::Incomplete => status == TaskStatus::Incomplete,
+        StatusFilter::Blocked => status == TaskStatus::Blocked,
+        StatusFilter::Complete => status == TaskStatus::Complete,
+        StatusFilter::Snoozed => {
+            status != TaskStatus::Complete
+                && task.start_date > task.creation_time
+        }
+        StatusFilter::Tag => task.tag,
+    }
+}
+
+impl Query {
+    /// Returns whether the task with the given id matches the query.
+    pub fn matches(&self, list: &TodoList, id: TaskId) -> bool {
+        let Some(task) = list.get(id) else {
+            return false;
+        };
+        match self {
+            Query::And(a, b) => a.matches(list, id) && b.matches(list, id),
+            Query::Or(a, b) => a.matches(list, id) || b.matches(list, id),
+            Query::Not(a) => !a.matches(list, id),
+            Query::Status(filter) => matches_status(list, id, *filter),
+            Query::Date {
+                field,
+                comparison,
+                start,
+                end,
+            } => {
+                let date = match field {
+                    DateField::Due => task.implicit_due_date,
+                    DateField::Created => Some(task.creation_time),
+                    DateField::Completed => task.completion_time,
+                    DateField::Start => Some(task.start_date),
+                };
+                date.is_some_and(|date| {
+                    compare_date(*comparison, date, *start, *end)
+                })
+            }
+            Query::Priority(comparison, priority) => {
+                compare(*comparison, task.implicit_priority, *priority)
+            }
+            Query::Budget(comparison, secs) => {
+                compare(*comparison, task.budget.0 as u64, *secs)
+            }
+            Query::Text(TextField::Desc, text_match) => {
+                text_match.matches(&task.desc)
+            }
+            Query::Text(TextField::Id, text_match) => {
+                text_match.matches(&task.short_id)
+            }
+            Query::Text(TextField::Tag, text_match) => {
+                let mut tags = task
+                    .implicit_tags
+                    .iter()
+                    .filter_map(|&tag| list.get(tag))
+                    .chain(task.tag.then_some(task))
+                    .map(|tag| &tag.desc);
+                match text_match {
+                    // A task is not in a tag if none of its tags match.
+                    TextMatch::NotEquals(s) => {
+                        tags.all(|tag| !tag.eq_ignore_ascii_case(s))
+                    }
+                    _ => tags.any(|tag| text_match.matches(tag)),
+                }
+            }
+        }
+    }
+
+    /// Whether the query refers to completed tasks, in which case complete
+    /// tasks should be searched even if they would be hidden otherwise.
+    pub fn mentions_completion(&self) -> bool {
+        match self {
+            Query::And(a, b) | Query::Or(a, b) => {
+                a.mentions_completion() || b.mentions_completion()
+            }
+            Query::Not(a) => a.mentions_completion(),
+            Query::Status(StatusFilter::Complete) => true,
+            Query::Date {
+                field: DateField::Completed,
+                ..
+            } => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
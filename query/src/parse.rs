@@ -0,0 +1,401 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use regex::Regex;
+use thiserror::Error;
+use todo_time_format::parse_duration;
+use todo_time_format::parse_time;
+use todo_time_format::Snap;
+
+use crate::Comparison;
+use crate::DateField;
+use crate::Query;
+use crate::StatusFilter;
+use crate::TextField;
+use crate::TextMatch;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ParseQueryError {
+    #[error("the query is empty")]
+    Empty,
+    #[error("the query ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("unexpected {0:?}")]
+    UnexpectedToken(String),
+    #[error("missing closing parenthesis")]
+    UnclosedParen,
+    #[error("missing closing quote")]
+    UnclosedQuote,
+    #[error("missing closing '/' after regular expression")]
+    UnclosedRegex,
+    #[error("unknown filter {0:?}")]
+    UnknownFilter(String),
+    #[error("{field:?} can't be compared with {op:?}")]
+    InvalidOperator { field: String, op: String },
+    #[error("missing value for {0:?}")]
+    MissingValue(String),
+    #[error("cannot parse date {0:?}")]
+    InvalidDate(String),
+    #[error("cannot parse duration {0:?}")]
+    InvalidDuration(String),
+    #[error("cannot parse number {0:?}")]
+    InvalidNumber(String),
+    #[error("unknown status {0:?}")]
+    InvalidStatus(String),
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LParen,
+    RParen,
+    // Words that were quoted, in whole or in part, are never keywords.
+    Word { text: String, quoted: bool },
+}
+
+const OPERATORS: [&str; 8] = ["<=", ">=", "!=", "<", ">", "=", ":", "~"];
+const OPERATOR_CHARS: [char; 6] = ['<', '>', '!', '=', ':', '~'];
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseQueryError> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    let flush = |tokens: &mut Vec<Token>, word: &mut String, quoted| {
+        if !word.is_empty() || quoted {
+            tokens.push(Token::Word {
+                text: std::mem::take(word),
+                quoted,
+            });
+        }
+    };
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                flush(&mut tokens, &mut word, quoted);
+                quoted = false;
+            }
+            '(' | ')' => {
+                flush(&mut tokens, &mut word, quoted);
+                quoted = false;
+                tokens.push(if c == '(' {
+                    Token::LParen
+                } else {
+                    Token::RParen
+                });
+            }
+            '"' => {
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(ParseQueryError::UnclosedQuote),
+                    }
+                }
+            }
+            // A regular expression may contain spaces and parentheses, so
+            // read it all the way to the closing slash. "\/" is a slash that
+            // doesn't end the expression.
+            '/' if word.ends_with('~') => {
+                quoted = true;
+                word.push('/');
+                loop {
+                    match chars.next() {
+                        Some('\\') if chars.peek() == Some(&'/') => {
+                            chars.next();
+                            word.push('/');
+                        }
+                        Some('/') => break word.push('/'),
+                        Some(c) => word.push(c),
+                        None => return Err(ParseQueryError::UnclosedRegex),
+                    }
+                }
+            }
+            c => word.push(c),
+        }
+    }
+    flush(&mut tokens, &mut word, quoted);
+    Ok(tokens)
+}
+
+fn parse_status(s: &str) -> Option<StatusFilter> {
+    match s.to_lowercase().as_str() {
+        "incomplete" => Some(StatusFilter::Incomplete),
+        "blocked" => Some(StatusFilter::Blocked),
+        "complete" | "done" => Some(StatusFilter::Complete),
+        "snoozed" => Some(StatusFilter::Snoozed),
+        "tag" => Some(StatusFilter::Tag),
+        _ => None,
+    }
+}
+
+fn parse_comparison(
+    field: &str,
+    op: &str,
+) -> Result<Comparison, ParseQueryError> {
+    match op {
+        "<" => Ok(Comparison::Less),
+        "<=" => Ok(Comparison::LessOrEqual),
+        ">" => Ok(Comparison::Greater),
+        ">=" => Ok(Comparison::GreaterOrEqual),
+        "=" | ":" => Ok(Comparison::Equal),
+        "!=" => Ok(Comparison::NotEqual),
+        _ => Err(ParseQueryError::InvalidOperator {
+            field: field.to_string(),
+            op: op.to_string(),
+        }),
+    }
+}
+
+fn parse_text_match(
+    field: &str,
+    op: &str,
+    value: &str,
+    contains: bool,
+) -> Result<TextMatch, ParseQueryError> {
+    match op {
+        ":" if contains => Ok(TextMatch::Contains(value.to_string())),
+        ":" | "=" => Ok(TextMatch::Equals(value.to_string())),
+        "!=" => Ok(TextMatch::NotEquals(value.to_string())),
+        "~" => {
+            let pattern = value
+                .strip_prefix('/')
+                .and_then(|value| value.strip_suffix('/'))
+                .unwrap_or(value);
+            Regex::new(pattern)
+                .map(TextMatch::Regex)
+                .map_err(|e| ParseQueryError::InvalidRegex(e.to_string()))
+        }
+        _ => Err(ParseQueryError::InvalidOperator {
+            field: field.to_string(),
+            op: op.to_string(),
+        }),
+    }
+}
+
+struct Parser {
+    tokens: Peekable<IntoIter<Token>>,
+    now: DateTime<Local>,
+}
+
+impl Parser {
+    fn next_is_keyword(&mut self, keyword: &str) -> bool {
+        matches!(
+            self.tokens.peek(),
+            Some(Token::Word { text, quoted: false })
+                if text.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    fn parse_or(&mut self) -> Result<Query, ParseQueryError> {
+        let mut query = self.parse_and()?;
+        while self.next_is_keyword("or") {
+            self.tokens.next();
+            query = Query::Or(Box::new(query), Box::new(self.parse_and()?));
+        }
+        Ok(query)
+    }
+
+    fn parse_and(&mut self) -> Result<Query, ParseQueryError> {
+        let mut query = self.parse_unary()?;
+        while self.next_is_keyword("and") {
+            self.tokens.next();
+            query = Query::And(Box::new(query), Box::new(self.parse_unary()?));
+        }
+        Ok(query)
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, ParseQueryError> {
+        if self.next_is_keyword("not") {
+            self.tokens.next();
+            return Ok(Query::Not(Box::new(self.parse_unary()?)));
+        }
+        match self.tokens.next() {
+            Some(Token::LParen) => {
+                let query = self.parse_or()?;
+                match self.tokens.next() {
+                    Some(Token::RParen) => Ok(query),
+                    _ => Err(ParseQueryError::UnclosedParen),
+                }
+            }
+            Some(Token::RParen) => {
+                Err(ParseQueryError::UnexpectedToken(")".to_string()))
+            }
+            Some(Token::Word { text, quoted }) => {
+                if !quoted
+                    && ["and", "or"]
+                        .iter()
+                        .any(|k| text.eq_ignore_ascii_case(k))
+                {
+                    return Err(ParseQueryError::UnexpectedToken(text));
+                }
+                self.parse_filter(text)
+            }
+            None => Err(ParseQueryError::UnexpectedEnd),
+        }
+    }
+
+    fn parse_filter(&mut self, text: String) -> Result<Query, ParseQueryError> {
+        if let Some(i) = text.find(OPERATOR_CHARS) {
+            let (field, rest) = text.split_at(i);
+            return self.parse_field(field, rest);
+        }
+        if let Some(status) = parse_status(&text) {
+            return Ok(Query::Status(status));
+        }
+        // Allow spaces around the operator, e.g. "due < friday".
+        match self.tokens.peek() {
+            Some(Token::Word { text: next, .. })
+                if next.starts_with(OPERATOR_CHARS) =>
+            {
+                let Some(Token::Word { text: rest, .. }) = self.tokens.next()
+                else {
+                    unreachable!()
+                };
+                self.parse_field(&text, &rest)
+            }
+            _ => Err(ParseQueryError::UnknownFilter(text)),
+        }
+    }
+
+    // Reads the value of a filter, which is everything up to the next
+    // keyword or parenthesis.
+    fn parse_value(&mut self, start: &str) -> String {
+        let mut words = Vec::new();
+        if !start.is_empty() {
+            words.push(start.to_string());
+        }
+        while !self.next_is_keyword("and") && !self.next_is_keyword("or") {
+            match self.tokens.peek() {
+                Some(Token::Word { .. }) => {
+                    if let Some(Token::Word { text, .. }) = self.tokens.next() {
+                        words.push(text);
+                    }
+                }
+                _ => break,
+            }
+        }
+        words.join(" ")
+    }
+
+    fn parse_date(
+        &self,
+        value: &str,
+    ) -> Result<(DateTime<Utc>, DateTime<Utc>), ParseQueryError> {
+        let parse = |snap| {
+            parse_time(Local, self.now, value, snap)
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|_| ParseQueryError::InvalidDate(value.to_string()))
+        };
+        Ok((parse(Snap::ToStart)?, parse(Snap::ToEnd)?))
+    }
+
+    fn parse_field(
+        &mut self,
+        field: &str,
+        rest: &str,
+    ) -> Result<Query, ParseQueryError> {
+        let op = OPERATORS
+            .iter()
+            .find(|op| rest.starts_with(*op))
+            .ok_or_else(|| ParseQueryError::UnknownFilter(field.to_string()))?;
+        let value = self.parse_value(&rest[op.len()..]);
+        if value.is_empty() {
+            return Err(ParseQueryError::MissingValue(field.to_string()));
+        }
+        let date_field = |date_field| -> Result<Query, ParseQueryError> {
+            let comparison = parse_comparison(field, op)?;
+            let (start, end) = self.parse_date(&value)?;
+            Ok(Query::Date {
+                field: date_field,
+                comparison,
+                start,
+                end,
+            })
+        };
+        match field.to_lowercase().as_str() {
+            "due" => date_field(DateField::Due),
+            "created" => date_field(DateField::Created),
+            "completed" => date_field(DateField::Completed),
+            "start" => date_field(DateField::Start),
+            "priority" => Ok(Query::Priority(
+                parse_comparison(field, op)?,
+                value
+                    .parse()
+                    .map_err(|_| ParseQueryError::InvalidNumber(value))?,
+            )),
+            "budget" => Ok(Query::Budget(
+                parse_comparison(field, op)?,
+                match value.as_str() {
+                    "0" => 0,
+                    _ => parse_duration(&value)
+                        .map_err(|_| ParseQueryError::InvalidDuration(value))?
+                        .as_secs(),
+                },
+            )),
+            "desc" => Ok(Query::Text(
+                TextField::Desc,
+                parse_text_match(field, op, &value, true)?,
+            )),
+            "tag" => Ok(Query::Text(
+                TextField::Tag,
+                parse_text_match(field, op, &value, false)?,
+            )),
+            "id" => Ok(Query::Text(
+                TextField::Id,
+                parse_text_match(
+                    field,
+                    op,
+                    value.strip_prefix('#').unwrap_or(&value),
+                    false,
+                )?,
+            )),
+            "status" => {
+                let status = parse_status(&value)
+                    .ok_or(ParseQueryError::InvalidStatus(value))?;
+                match parse_comparison(field, op)? {
+                    Comparison::Equal => Ok(Query::Status(status)),
+                    Comparison::NotEqual => {
+                        Ok(Query::Not(Box::new(Query::Status(status))))
+                    }
+                    _ => Err(ParseQueryError::InvalidOperator {
+                        field: field.to_string(),
+                        op: op.to_string(),
+                    }),
+                }
+            }
+            _ => Err(ParseQueryError::UnknownFilter(field.to_string())),
+        }
+    }
+}
+
+/// Parses a query. Relative dates in the query, like "friday", are relative
+/// to |now| in the local timezone.
+pub fn parse(s: &str, now: DateTime<Utc>) -> Result<Query, ParseQueryError> {
+    let tokens = tokenize(s)?;
+    if tokens.is_empty() {
+        return Err(ParseQueryError::Empty);
+    }
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+        now: now.with_timezone(&Local),
+    };
+    let query = parser.parse_or()?;
+    match parser.tokens.next() {
+        None => Ok(query),
+        Some(Token::LParen) => {
+            Err(ParseQueryError::UnexpectedToken("(".to_string()))
+        }
+        Some(Token::RParen) => {
+            Err(ParseQueryError::UnexpectedToken(")".to_string()))
+        }
+        Some(Token::Word { text, .. }) => {
+            Err(ParseQueryError::UnexpectedToken(text))
+        }
+    }
+}
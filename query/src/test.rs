@@ -0,0 +1,335 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::DateTime;
+use chrono::Utc;
+use todo_model::NewOptions;
+use todo_model::TaskId;
+use todo_model::TodoList;
+use todo_testing::ymdhms;
+
+use crate::parse;
+use crate::ParseQueryError;
+
+// Saturday.
+fn now() -> DateTime<Utc> {
+    ymdhms(2000, 01, 01, 12, 00, 00)
+}
+
+fn matching(list: &TodoList, query: &str) -> Vec<TaskId> {
+    let query = parse(query, now()).unwrap();
+    list.all_tasks()
+        .filter(|&id| query.matches(list, id))
+        .collect()
+}
+
+fn parse_error(query: &str) -> ParseQueryError {
+    parse(query, now()).unwrap_err()
+}
+
+#[test]
+fn empty_query_is_an_error() {
+    assert_eq!(parse_error(""), ParseQueryError::Empty);
+    assert_eq!(parse_error("   "), ParseQueryError::Empty);
+}
+
+#[test]
+fn status_filters() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    let c = list.add("c");
+    let d = list.add(NewOptions::new().desc("d").as_tag());
+    list.block(b).on(a).unwrap();
+    list.check(c).unwrap();
+    assert_eq!(matching(&list, "incomplete"), [a, d]);
+    assert_eq!(matching(&list, "blocked"), [b]);
+    assert_eq!(matching(&list, "complete"), [c]);
+    assert_eq!(matching(&list, "done"), [c]);
+    assert_eq!(matching(&list, "tag"), [d]);
+    assert_eq!(matching(&list, "status=blocked"), [b]);
+    assert_eq!(matching(&list, "status!=blocked"), [c, a, d]);
+    assert_eq!(matching(&list, "BLOCKED"), [b]);
+}
+
+#[test]
+fn snoozed_filter() {
+    let mut list = TodoList::default();
+    let a = list.add(NewOptions::new().desc("a").creation_time(now()));
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .creation_time(now())
+            .start_date(ymdhms(2000, 01, 05, 00, 00, 00)),
+    );
+    assert_eq!(matching(&list, "snoozed"), [b]);
+    assert_eq!(matching(&list, "not snoozed"), [a]);
+}
+
+#[test]
+fn priority_comparisons() {
+    let mut list = TodoList::default();
+    let a = list.add(NewOptions::new().desc("a").priority(1));
+    let b = list.add(NewOptions::new().desc("b").priority(2));
+    let c = list.add(NewOptions::new().desc("c").priority(3));
+    let d = list.add("d");
+    assert_eq!(matching(&list, "priority>=2"), [c, b]);
+    assert_eq!(matching(&list, "priority>2"), [c]);
+    assert_eq!(matching(&list, "priority<2"), [a, d]);
+    assert_eq!(matching(&list, "priority<=2"), [b, a, d]);
+    assert_eq!(matching(&list, "priority=1"), [a]);
+    assert_eq!(matching(&list, "priority!=1"), [c, b, d]);
+    assert_eq!(matching(&list, "priority: 3"), [c]);
+}
+
+#[test]
+fn priority_is_implicit() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(NewOptions::new().desc("b").priority(2));
+    list.block(b).on(a).unwrap();
+    assert_eq!(matching(&list, "priority>=2"), [a, b]);
+}
+
+#[test]
+fn due_date_comparisons() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .due_date(ymdhms(2000, 01, 06, 23, 59, 59)),
+    );
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .due_date(ymdhms(2000, 01, 07, 23, 59, 59)),
+    );
+    let c = list.add(
+        NewOptions::new()
+            .desc("c")
+            .due_date(ymdhms(2000, 01, 08, 23, 59, 59)),
+    );
+    let d = list.add("d");
+    // Friday is 2000-01-07.
+    assert_eq!(matching(&list, "due<friday"), [a]);
+    assert_eq!(matching(&list, "due<=friday"), [a, b]);
+    assert_eq!(matching(&list, "due=friday"), [b]);
+    assert_eq!(matching(&list, "due>friday"), [c]);
+    assert_eq!(matching(&list, "due>=friday"), [b, c]);
+    assert_eq!(matching(&list, "due!=friday"), [a, c]);
+    assert_eq!(matching(&list, "not due<friday"), [b, c, d]);
+}
+
+#[test]
+fn due_date_is_implicit() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .due_date(ymdhms(2000, 01, 03, 23, 59, 59)),
+    );
+    list.block(b).on(a).unwrap();
+    assert_eq!(matching(&list, "due<friday"), [a, b]);
+}
+
+#[test]
+fn date_with_spaces() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .creation_time(ymdhms(1999, 12, 20, 12, 00, 00)),
+    );
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .creation_time(ymdhms(1999, 12, 30, 12, 00, 00)),
+    );
+    assert_eq!(matching(&list, "created>last monday"), [b]);
+    assert_eq!(matching(&list, "created < last monday"), [a]);
+}
+
+#[test]
+fn completed_date() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let b = list.add("b");
+    list.check(todo_model::CheckOptions {
+        id: a,
+        now: ymdhms(1999, 12, 31, 12, 00, 00),
+    })
+    .unwrap();
+    assert_eq!(matching(&list, "completed=last friday"), [a]);
+    assert_eq!(matching(&list, "completed<today"), [a]);
+    assert_eq!(matching(&list, "not completed<today"), [b]);
+}
+
+#[test]
+fn budget_comparisons() {
+    let mut list = TodoList::default();
+    let a = list.add(
+        NewOptions::new()
+            .desc("a")
+            .budget(chrono::Duration::hours(1)),
+    );
+    let b = list.add(
+        NewOptions::new()
+            .desc("b")
+            .budget(chrono::Duration::hours(3)),
+    );
+    let c = list.add("c");
+    assert_eq!(matching(&list, "budget>2h"), [b]);
+    assert_eq!(matching(&list, "budget<=1h"), [a, c]);
+    assert_eq!(matching(&list, "budget=0"), [c]);
+    assert_eq!(matching(&list, "budget>=1 hour"), [a, b]);
+}
+
+#[test]
+fn desc_matches() {
+    let mut list = TodoList::default();
+    let a = list.add("buy milk");
+    let b = list.add("Buy bread");
+    let c = list.add("milk");
+    assert_eq!(matching(&list, "desc:milk"), [a, c]);
+    assert_eq!(matching(&list, "desc:BUY"), [a, b]);
+    assert_eq!(matching(&list, "desc=milk"), [c]);
+    assert_eq!(matching(&list, "desc!=milk"), [a, b]);
+    assert_eq!(matching(&list, "desc~/^buy/"), [a]);
+    assert_eq!(matching(&list, "desc~/(?i)^buy/"), [a, b]);
+    assert_eq!(matching(&list, "desc:buy milk"), [a]);
+}
+
+#[test]
+fn quoted_value_may_contain_keywords() {
+    let mut list = TodoList::default();
+    let a = list.add("bread and butter");
+    let _b = list.add("bread");
+    assert_eq!(matching(&list, "desc:\"bread and butter\""), [a]);
+    assert_eq!(matching(&list, "desc:\"and\""), [a]);
+}
+
+#[test]
+fn regex_may_contain_spaces_and_slashes() {
+    let mut list = TodoList::default();
+    let a = list.add("a (b) c");
+    let b = list.add("a/b");
+    assert_eq!(matching(&list, "desc~/\\(b\\) c/"), [a]);
+    assert_eq!(matching(&list, "desc~/a\\/b/"), [b]);
+}
+
+#[test]
+fn tag_matches() {
+    let mut list = TodoList::default();
+    let work = list.add(NewOptions::new().desc("work").as_tag());
+    let a = list.add("a");
+    let b = list.add("b");
+    list.block(work).on(a).unwrap();
+    assert_eq!(matching(&list, "tag:work"), [a, work]);
+    assert_eq!(matching(&list, "tag:WORK"), [a, work]);
+    assert_eq!(matching(&list, "tag!=work"), [b]);
+    assert_eq!(matching(&list, "tag~/^wo/"), [a, work]);
+}
+
+#[test]
+fn id_matches() {
+    let mut list = TodoList::default();
+    let a = list.add("a");
+    let _b = list.add("b");
+    let short_id = list.get(a).unwrap().short_id.clone();
+    assert_eq!(matching(&list, &format!("id:{short_id}")), [a]);
+    assert_eq!(matching(&list, &format!("id=#{short_id}")), [a]);
+}
+
+#[test]
+fn boolean_operators() {
+    let mut list = TodoList::default();
+    let work = list.add(NewOptions::new().desc("work").as_tag());
+    let a = list.add(NewOptions::new().desc("a").priority(2));
+    let b = list.add("b");
+    let c = list.add(NewOptions::new().desc("c").priority(1));
+    list.block(work).on(a).unwrap();
+    list.block(work).on(b).unwrap();
+    list.block(b).on(c).unwrap();
+    assert_eq!(matching(&list, "tag:work and not blocked"), [a, c]);
+    assert_eq!(matching(&list, "priority>=2 or desc=b"), [a, b]);
+    assert_eq!(matching(&list, "NOT tag AND (desc=b OR desc=c)"), [c, b]);
+    assert_eq!(matching(&list, "desc=a or desc=b and desc=c"), [a]);
+    assert_eq!(matching(&list, "(desc=a or desc=b) and desc=c"), []);
+    assert_eq!(matching(&list, "not not desc=c"), [c]);
+}
+
+#[test]
+fn mentions_completion() {
+    let mentions = |query| parse(query, now()).unwrap().mentions_completion();
+    assert!(mentions("done"));
+    assert!(mentions("status=complete"));
+    assert!(mentions("desc:a or completed<today"));
+    assert!(mentions("not complete"));
+    assert!(!mentions("desc:a"));
+    assert!(!mentions("incomplete"));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse_error("bogus"),
+        ParseQueryError::UnknownFilter("bogus".to_string())
+    );
+    assert_eq!(
+        parse_error("size>2"),
+        ParseQueryError::UnknownFilter("size".to_string())
+    );
+    assert_eq!(
+        parse_error("priority>high"),
+        ParseQueryError::InvalidNumber("high".to_string())
+    );
+    assert_eq!(
+        parse_error("budget>forever"),
+        ParseQueryError::InvalidDuration("forever".to_string())
+    );
+    assert_eq!(
+        parse_error("due<someday"),
+        ParseQueryError::InvalidDate("someday".to_string())
+    );
+    assert_eq!(
+        parse_error("priority~2"),
+        ParseQueryError::InvalidOperator {
+            field: "priority".to_string(),
+            op: "~".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error("desc<a"),
+        ParseQueryError::InvalidOperator {
+            field: "desc".to_string(),
+            op: "<".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error("status=lost"),
+        ParseQueryError::InvalidStatus("lost".to_string())
+    );
+    assert_eq!(
+        parse_error("desc:"),
+        ParseQueryError::MissingValue("desc".to_string())
+    );
+    assert_eq!(
+        parse_error("desc:a and (blocked"),
+        ParseQueryError::UnclosedParen
+    );
+    assert_eq!(
+        parse_error("desc:a)"),
+        ParseQueryError::UnexpectedToken(")".to_string())
+    );
+    assert_eq!(parse_error("desc:a and"), ParseQueryError::UnexpectedEnd);
+    assert_eq!(
+        parse_error("and blocked"),
+        ParseQueryError::UnexpectedToken("and".to_string())
+    );
+    assert_eq!(parse_error("desc:\"a"), ParseQueryError::UnclosedQuote);
+    assert_eq!(parse_error("desc~/a"), ParseQueryError::UnclosedRegex);
+    assert!(matches!(
+        parse_error("desc~/(/"),
+        ParseQueryError::InvalidRegex(_)
+    ));
+}
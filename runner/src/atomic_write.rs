@@ -0,0 +1,47 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Writes to a temporary file next to |path| and syncs it to disk, returning
+/// the path of the temporary file. Renaming the temporary file over |path|
+/// then replaces the file atomically, so that a crash or a full disk never
+/// leaves a partially-written file behind. If writing fails, the temporary
+/// file is cleaned up and |path| is left untouched.
+pub fn write_temp_file<E, F>(path: &Path, write: F) -> Result<PathBuf, E>
+where
+    E: From<std::io::Error>,
+    F: FnOnce(&mut BufWriter<&File>) -> Result<(), E>,
+{
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+    let result = File::create(&tmp_path).map_err(E::from).and_then(|file| {
+        let mut writer = BufWriter::new(&file);
+        write(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        file.sync_all()?;
+        Ok(())
+    });
+    match result {
+        Ok(()) => Ok(tmp_path),
+        Err(e) => {
+            // Best effort; a stray temporary file is harmless.
+            let _ = std::fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
+
+/// Replaces the file at |path| with whatever |write| writes, atomically.
+pub fn write_atomically<E, F>(path: &Path, write: F) -> Result<(), E>
+where
+    E: From<std::io::Error>,
+    F: FnOnce(&mut BufWriter<&File>) -> Result<(), E>,
+{
+    let tmp_path = write_temp_file(path, write)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use super::atomic_write::*;
+
+#[test]
+fn write_atomically_creates_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    write_atomically(&path, |writer| writer.write_all(b"new")).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+}
+
+#[test]
+fn write_atomically_replaces_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    std::fs::write(&path, "old").unwrap();
+    write_atomically(&path, |writer| writer.write_all(b"new")).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn failed_write_leaves_file_untouched() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    std::fs::write(&path, "old").unwrap();
+    let result = write_atomically(&path, |writer| {
+        writer.write_all(b"partial")?;
+        Err(std::io::Error::other("disk full"))
+    });
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+    // The temporary file is cleaned up.
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn write_temp_file_does_not_touch_original() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    std::fs::write(&path, "old").unwrap();
+    let tmp_path = write_temp_file::<std::io::Error, _>(&path, |writer| {
+        writer.write_all(b"new")
+    })
+    .unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "old");
+    assert_eq!(std::fs::read_to_string(tmp_path).unwrap(), "new");
+}
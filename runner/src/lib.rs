@@ -1,11 +1,17 @@
 use std::fs::File;
-use std::io::BufWriter;
 use std::io::IsTerminal;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use thiserror::Error;
 use todo_app::Application;
+use todo_backups::FileBackups;
 use todo_clock::Clock;
 use todo_clock::SystemClock;
+use todo_journal::Journal;
+use todo_model::TodoList;
+use todo_printing::JsonTodoPrinter;
 use todo_printing::Printable;
 use todo_printing::PrintingContext;
 use todo_printing::ScriptingTodoPrinter;
@@ -43,31 +49,68 @@ pub enum TodoError {
     Save(#[from] SaveError),
     #[error("Config error")]
     LoadConfig(#[from] todo_config::LoadError),
+    #[error("Could not save config")]
+    SaveConfig(#[from] todo_config::SaveError),
+    #[error("Could not load undo journal")]
+    LoadJournal(#[from] todo_journal::LoadError),
+    #[error("Could not save undo journal")]
+    SaveJournal(#[from] todo_journal::SaveError),
+    #[error("Could not open lock file")]
+    CouldNotOpenLockFile(std::io::Error),
+    #[error(
+        "Timed out after {timeout:?} waiting for another todo process to \
+         release {path:?}; raise 'lock_timeout_secs' with 'todo config' to \
+         wait longer"
+    )]
+    LockTimedOut { path: PathBuf, timeout: Duration },
+    #[error("Could not read commands for the shell")]
+    Shell(#[from] rustyline::error::ReadlineError),
 }
 
+mod atomic_write;
 mod less;
+mod lock;
+mod shell;
+
+use atomic_write::write_atomically;
+use atomic_write::write_temp_file;
+use lock::LockError;
+use lock::LockMode;
+pub use shell::run_shell;
 
 pub type TodoResult = Result<(), TodoError>;
 
-pub fn run(app: impl Application) -> TodoResult {
+// A command line as the user might have typed it, for display in the undo
+// journal.
+fn format_command_line(args: &[String]) -> String {
+    let args = shlex::try_join(args.iter().map(|arg| arg.as_str()))
+        .unwrap_or_else(|_| args.join(" "));
+    format!("todo {args}").trim_end().to_string()
+}
+
+fn command_line() -> String {
+    format_command_line(&std::env::args().skip(1).collect::<Vec<_>>())
+}
+
+struct Paths {
+    config: PathBuf,
+    data: PathBuf,
+    journal: PathBuf,
+}
+
+fn paths() -> Result<Paths, TodoError> {
     let project_dirs = directories::ProjectDirs::from("", "", "todo")
         .ok_or(TodoError::NoDataDirectory)?;
 
-    let config = {
-        let mut config_path = project_dirs.config_dir().to_path_buf();
+    let mut config_path = project_dirs.config_dir().to_path_buf();
 
-        // If the directory does not exist, create it.
-        if !config_path.exists() {
-            std::fs::create_dir_all(&config_path)
-                .map_err(TodoError::CouldNotCreateConfigDirectory)?;
-        }
+    // If the directory does not exist, create it.
+    if !config_path.exists() {
+        std::fs::create_dir_all(&config_path)
+            .map_err(TodoError::CouldNotCreateConfigDirectory)?;
+    }
 
-        config_path.push("config.json");
-        File::open(&config_path).map_or_else(
-            |_| Ok(todo_config::Config::default()),
-            todo_config::load,
-        )?
-    };
+    config_path.push("config.json");
 
     let mut data_path = project_dirs.data_dir().to_path_buf();
 
@@ -77,29 +120,101 @@ pub fn run(app: impl Application) -> TodoResult {
             .map_err(TodoError::CouldNotCreateDataDirectory)?;
     }
 
+    let mut journal_path = data_path.clone();
     data_path.push("data.json");
+    journal_path.push("journal.json");
+    Ok(Paths {
+        config: config_path,
+        data: data_path,
+        journal: journal_path,
+    })
+}
 
-    let read_file_result = std::fs::read_to_string(&data_path);
-    let mut model = match &read_file_result {
-        Ok(s) => serde_json::from_str(s).map_err(LoadError::from)?,
-        Err(_) => todo_model::TodoList::default(),
-    };
+fn load_config(
+    config_path: &Path,
+) -> Result<todo_config::ConfigModel, TodoError> {
+    Ok(File::open(config_path).map_or_else(
+        |_| Ok(todo_config::ConfigModel::default()),
+        todo_config::load,
+    )?)
+}
 
-    let mutated = if std::io::stdout().is_terminal() {
+// Holds the lock until the returned file is dropped, so that concurrent
+// invocations can't read the list while it's being changed or overwrite each
+// other's changes.
+fn lock_list(
+    data_path: &Path,
+    mode: LockMode,
+    config: &todo_config::Config,
+) -> Result<File, TodoError> {
+    lock::lock(data_path, mode, config.lock_timeout).map_err(|e| match e {
+        LockError::IoError(e) => TodoError::CouldNotOpenLockFile(e),
+        LockError::TimedOut => TodoError::LockTimedOut {
+            path: lock::lock_path(data_path),
+            timeout: config.lock_timeout,
+        },
+    })
+}
+
+// Runs the application and prints its result in the format the user asked
+// for. Returns whether the list was modified.
+fn run_and_print<'ser>(
+    app: impl Application,
+    model: &mut TodoList<'ser>,
+    journal: &mut Journal<'ser>,
+    config_model: &mut todo_config::ConfigModel,
+    config: &todo_config::Config,
+    data_path: &Path,
+    paginate: bool,
+) -> bool {
+    let interactive = std::io::stdout().is_terminal();
+    if let Some(format) = app.output_format() {
+        let result = if interactive {
+            app.run(
+                model,
+                journal,
+                config_model,
+                &FileBackups(data_path),
+                &ScrawlTextEditor(&config.text_editor_cmd),
+                &SystemClock,
+            )
+        } else {
+            app.run(
+                model,
+                journal,
+                config_model,
+                &FileBackups(data_path),
+                &FakeTextEditor::no_user_output(),
+                &SystemClock,
+            )
+        };
+        let mut printer = JsonTodoPrinter::new(std::io::stdout(), format);
+        let mutated = result.print(&mut printer);
+        printer.finish(mutated);
+        mutated
+    } else if interactive {
         use either::Left;
         use either::Right;
         let paginator_cmd = &config.paginator_cmd;
-        let out = match less::Less::new(paginator_cmd) {
-            Ok(paginator) => Left(paginator),
-            Err(less::CouldNotSpawnPaginator(e)) => {
-                eprintln!(
-                    "Could not spawn paginator using {paginator_cmd:?}: {e:?}"
-                );
-                Right(std::io::stdout())
+        let out = if !paginate || app.is_full_screen() {
+            Right(std::io::stdout())
+        } else {
+            match less::Less::new(paginator_cmd) {
+                Ok(paginator) => Left(paginator),
+                Err(less::CouldNotSpawnPaginator(e)) => {
+                    eprintln!(
+                        "Could not spawn paginator using {paginator_cmd:?}: \
+                         {e:?}"
+                    );
+                    Right(std::io::stdout())
+                }
             }
         };
         let result = app.run(
-            &mut model,
+            model,
+            journal,
+            config_model,
+            &FileBackups(data_path),
             &ScrawlTextEditor(&config.text_editor_cmd),
             &SystemClock,
         );
@@ -111,22 +226,112 @@ pub fn run(app: impl Application) -> TodoResult {
                     .map(|(terminal_size::Width(w), _)| w)
                     .unwrap_or(80) as usize,
                 now: SystemClock.now(),
+                show_ids: config.show_ids,
             },
         };
         result.print(&mut printer)
     } else {
         let result = app.run(
-            &mut model,
+            model,
+            journal,
+            config_model,
+            &FileBackups(data_path),
             &FakeTextEditor::no_user_output(),
             &SystemClock,
         );
         let mut printer = ScriptingTodoPrinter;
         result.print(&mut printer)
+    }
+}
+
+fn save_list(
+    paths: &Paths,
+    model: &TodoList,
+    journal: &Journal,
+    config: &todo_config::Config,
+) -> TodoResult {
+    // Only rotate the backups once the new version is safely on disk, so
+    // that a failed save doesn't push out an old backup.
+    let tmp_path = write_temp_file(&paths.data, |writer| {
+        serde_json::to_writer(writer, model).map_err(SaveError::from)
+    })?;
+    todo_backups::rotate_backups(&paths.data, config.backup_count)
+        .map_err(SaveError::from)?;
+    std::fs::rename(tmp_path, &paths.data).map_err(SaveError::from)?;
+    write_atomically(&paths.journal, |writer| {
+        todo_journal::save(writer, journal)
+    })?;
+    Ok(())
+}
+
+fn save_config(
+    paths: &Paths,
+    config_model: &todo_config::ConfigModel,
+) -> TodoResult {
+    write_atomically(&paths.config, |writer| {
+        todo_config::save(writer, config_model)
+    })?;
+    Ok(())
+}
+
+pub fn run(app: impl Application) -> TodoResult {
+    let paths = paths()?;
+    let original_config_model = load_config(&paths.config)?;
+    let mut config_model = original_config_model.clone();
+    let config = todo_config::Config::new(&config_model);
+
+    // A dry run never saves, so it can run alongside other readers.
+    let dry_run = app.is_dry_run();
+    let mode = if app.is_read_only() || dry_run {
+        LockMode::Shared
+    } else {
+        LockMode::Exclusive
+    };
+    let _lock = lock_list(&paths.data, mode, &config)?;
+
+    let read_file_result = std::fs::read_to_string(&paths.data);
+    let mut model = match &read_file_result {
+        Ok(s) => serde_json::from_str(s).map_err(LoadError::from)?,
+        Err(_) => todo_model::TodoList::default(),
+    };
+    let before = model.clone();
+
+    let read_journal_result = std::fs::read_to_string(&paths.journal);
+    let mut journal = match &read_journal_result {
+        Ok(s) => todo_journal::load(s)?,
+        Err(_) => todo_journal::Journal::default(),
     };
+
+    let mutated = run_and_print(
+        app,
+        &mut model,
+        &mut journal,
+        &mut config_model,
+        &config,
+        &paths.data,
+        true,
+    );
+    if dry_run {
+        return Ok(());
+    }
     if mutated {
-        let file = File::create(&data_path).map_err(SaveError::from)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer(writer, &model).map_err(SaveError::from)?;
+        journal.record(
+            command_line(),
+            SystemClock.now(),
+            before,
+            config.undo_limit,
+        );
+        save_list(&paths, &model, &journal, &config)?;
+    }
+    if config_model != original_config_model {
+        save_config(&paths, &config_model)?;
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod atomic_write_test;
+#[cfg(test)]
+mod lock_test;
+#[cfg(test)]
+mod shell_test;
@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::fs::TryLockError;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use thiserror::Error;
+
+// How often to retry while another process holds the lock.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockMode {
+    /// Any number of shared locks can be held at once.
+    Shared,
+    /// Excludes all other locks, shared or exclusive.
+    Exclusive,
+}
+
+#[derive(Debug, Error)]
+pub enum LockError {
+    #[error("Could not open lock file")]
+    IoError(#[from] std::io::Error),
+    #[error("Timed out waiting for lock")]
+    TimedOut,
+}
+
+/// Returns the path of the lock file that guards the file at |path|.
+///
+/// The data file itself is replaced by renaming a new file over it on every
+/// save, so a lock on it would be tied to an inode that is no longer the
+/// current list. The lock file is never replaced, so every process agrees on
+/// which file to lock.
+pub fn lock_path(path: &Path) -> PathBuf {
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    PathBuf::from(lock_path)
+}
+
+/// Takes an advisory lock on the lock file for |path|, waiting up to
+/// |timeout| for other processes to release theirs, or forever if the timeout
+/// is too long to represent. The lock is released when the returned file is
+/// dropped, or when the process exits.
+pub fn lock(
+    path: &Path,
+    mode: LockMode,
+    timeout: Duration,
+) -> Result<File, LockError> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(lock_path(path))?;
+    let deadline = Instant::now().checked_add(timeout);
+    loop {
+        let result = match mode {
+            LockMode::Shared => file.try_lock_shared(),
+            LockMode::Exclusive => file.try_lock(),
+        };
+        match result {
+            Ok(()) => return Ok(file),
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+            Err(TryLockError::WouldBlock) => {
+                let now = Instant::now();
+                let interval = match deadline {
+                    Some(deadline) if now >= deadline => {
+                        return Err(LockError::TimedOut);
+                    }
+                    Some(deadline) => POLL_INTERVAL.min(deadline - now),
+                    None => POLL_INTERVAL,
+                };
+                std::thread::sleep(interval);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use super::lock::*;
+
+const SHORT: Duration = Duration::from_millis(100);
+
+#[test]
+fn lock_file_is_next_to_data_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let _lock = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+    assert!(dir.path().join("data.json.lock").exists());
+    assert!(!path.exists());
+}
+
+#[test]
+fn shared_locks_do_not_conflict() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let _a = lock(&path, LockMode::Shared, SHORT).unwrap();
+    let _b = lock(&path, LockMode::Shared, SHORT).unwrap();
+}
+
+#[test]
+fn exclusive_lock_excludes_shared_lock() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let _a = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+    assert!(matches!(
+        lock(&path, LockMode::Shared, SHORT),
+        Err(LockError::TimedOut)
+    ));
+}
+
+#[test]
+fn shared_lock_excludes_exclusive_lock() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let _a = lock(&path, LockMode::Shared, SHORT).unwrap();
+    assert!(matches!(
+        lock(&path, LockMode::Exclusive, SHORT),
+        Err(LockError::TimedOut)
+    ));
+}
+
+#[test]
+fn exclusive_lock_excludes_exclusive_lock() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let _a = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+    assert!(matches!(
+        lock(&path, LockMode::Exclusive, Duration::ZERO),
+        Err(LockError::TimedOut)
+    ));
+}
+
+#[test]
+fn lock_is_released_when_dropped() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    drop(lock(&path, LockMode::Exclusive, SHORT).unwrap());
+    let _b = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+}
+
+#[test]
+fn waits_for_lock_to_be_released() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let a = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(100));
+        drop(a);
+    });
+    let _b = lock(&path, LockMode::Exclusive, Duration::from_secs(10)).unwrap();
+    release.join().unwrap();
+}
+
+#[test]
+fn waits_forever_if_timeout_is_too_long() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.json");
+    let a = lock(&path, LockMode::Exclusive, SHORT).unwrap();
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(100));
+        drop(a);
+    });
+    let _b = lock(&path, LockMode::Exclusive, Duration::MAX).unwrap();
+    release.join().unwrap();
+}
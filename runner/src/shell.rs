@@ -0,0 +1,334 @@
+use std::fs::File;
+
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::Context;
+use rustyline::Editor;
+use rustyline::Helper;
+use todo_app::Application;
+use todo_clock::Clock;
+use todo_clock::SystemClock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
+use todo_model::TodoList;
+
+use crate::format_command_line;
+use crate::load_config;
+use crate::lock_list;
+use crate::paths;
+use crate::run_and_print;
+use crate::save_config;
+use crate::save_list;
+use crate::LoadError;
+use crate::LockMode;
+use crate::Paths;
+use crate::TodoError;
+use crate::TodoResult;
+
+const PROMPT: &str = "todo> ";
+
+// Splits a line typed into the shell into arguments like a shell would,
+// dropping a leading 'todo' so that commands can be pasted from elsewhere.
+// Returns None if the quotes are unbalanced.
+pub(crate) fn split_line(line: &str) -> Option<Vec<String>> {
+    let mut args = shlex::split(line)?;
+    if args.first().is_some_and(|arg| arg == "todo") {
+        args.remove(0);
+    }
+    Some(args)
+}
+
+// Finds the start of the word that ends at the end of |line|, which may be
+// inside quotes, and returns it with its quotes removed.
+fn current_word(line: &str) -> (usize, String) {
+    let mut start = 0;
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, ' ' | '\t') => start = i + 1,
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => {}
+        }
+    }
+    let word = line[start..]
+        .chars()
+        .filter(|&c| c != '"' && c != '\'')
+        .collect();
+    (start, word)
+}
+
+/// Completes the word before |pos|: the first word is completed with the
+/// names of subcommands, and later words with task descriptions, quoted if
+/// needed. Returns where the word starts and the possible replacements.
+pub(crate) fn complete(
+    line: &str,
+    pos: usize,
+    commands: &[String],
+    descriptions: &[String],
+) -> (usize, Vec<String>) {
+    let (start, word) = current_word(&line[..pos]);
+    let is_first_word = split_line(&line[..start])
+        .is_some_and(|preceding| preceding.is_empty());
+    let candidates = if is_first_word {
+        commands
+            .iter()
+            .filter(|command| command.starts_with(&word))
+            .cloned()
+            .collect()
+    } else if word.starts_with('-') {
+        Vec::new()
+    } else {
+        descriptions
+            .iter()
+            .filter(|desc| desc.starts_with(&word))
+            .map(|desc| {
+                shlex::try_quote(desc)
+                    .map(|quoted| quoted.into_owned())
+                    .unwrap_or_else(|_| desc.clone())
+            })
+            .collect()
+    };
+    (start, candidates)
+}
+
+struct ShellHelper {
+    commands: Vec<String>,
+    // The descriptions of the tasks in the list, sorted and without
+    // duplicates.
+    descriptions: Vec<String>,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete(line, pos, &self.commands, &self.descriptions))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+fn descriptions(list: &TodoList) -> Vec<String> {
+    let mut descriptions: Vec<_> = list
+        .all_tasks()
+        .filter_map(|id| list.get(id))
+        .map(|task| task.desc.to_string())
+        .collect();
+    descriptions.sort();
+    descriptions.dedup();
+    descriptions
+}
+
+// The state of the shell between commands.
+pub(crate) struct Session<'ser> {
+    paths: Paths,
+    _lock: File,
+    model: TodoList<'ser>,
+    journal: Journal<'ser>,
+    config_model: ConfigModel,
+    saved_config_model: ConfigModel,
+    // Whether the list was changed since it was last saved.
+    unsaved: bool,
+    // Whether to forget all changes when the shell exits, as with
+    // 'todo --dry-run shell'.
+    dry_run: bool,
+}
+
+impl<'ser> Session<'ser> {
+    pub(crate) fn start(
+        paths: Paths,
+        read_file: &'ser mut String,
+        read_journal: &'ser mut String,
+        dry_run: bool,
+    ) -> Result<Self, TodoError> {
+        let config_model = load_config(&paths.config)?;
+        let mode = if dry_run {
+            LockMode::Shared
+        } else {
+            LockMode::Exclusive
+        };
+        let lock = lock_list(
+            &paths.data,
+            mode,
+            &todo_config::Config::new(&config_model),
+        )?;
+        *read_file = std::fs::read_to_string(&paths.data).unwrap_or_default();
+        let model = if read_file.is_empty() {
+            TodoList::default()
+        } else {
+            serde_json::from_str(read_file).map_err(LoadError::from)?
+        };
+        *read_journal =
+            std::fs::read_to_string(&paths.journal).unwrap_or_default();
+        let journal = if read_journal.is_empty() {
+            Journal::default()
+        } else {
+            todo_journal::load(read_journal)?
+        };
+        Ok(Session {
+            paths,
+            _lock: lock,
+            model,
+            journal,
+            saved_config_model: config_model.clone(),
+            config_model,
+            unsaved: false,
+            dry_run,
+        })
+    }
+
+    pub(crate) fn list(&self) -> &TodoList<'ser> {
+        &self.model
+    }
+
+    /// Runs |app|, which was parsed from |args|, and saves what it changed
+    /// unless the session is a dry run.
+    pub(crate) fn run(
+        &mut self,
+        args: &[String],
+        app: impl Application,
+    ) -> TodoResult {
+        let config = todo_config::Config::new(&self.config_model);
+        let before = self.model.clone();
+        let dry_run = app.is_dry_run();
+        let config_model_before = self.config_model.clone();
+        let journal_before = dry_run.then(|| self.journal.clone());
+        let mutated = run_and_print(
+            app,
+            &mut self.model,
+            &mut self.journal,
+            &mut self.config_model,
+            &config,
+            &self.paths.data,
+            false,
+        );
+        if let Some(journal_before) = journal_before {
+            // Forget what the command did, as if it had never run.
+            self.model = before;
+            self.config_model = config_model_before;
+            self.journal = journal_before;
+            return Ok(());
+        }
+        if mutated {
+            self.journal.record(
+                format_command_line(args),
+                SystemClock.now(),
+                before,
+                config.undo_limit,
+            );
+            self.unsaved = true;
+        }
+        if self.dry_run {
+            return Ok(());
+        }
+        let config = todo_config::Config::new(&self.config_model);
+        if self.unsaved && config.shell_autosave {
+            self.save()?;
+        }
+        if self.config_model != self.saved_config_model {
+            save_config(&self.paths, &self.config_model)?;
+            self.saved_config_model = self.config_model.clone();
+        }
+        Ok(())
+    }
+
+    fn save(&mut self) -> TodoResult {
+        let config = todo_config::Config::new(&self.config_model);
+        save_list(&self.paths, &self.model, &self.journal, &config)?;
+        self.unsaved = false;
+        Ok(())
+    }
+
+    /// Saves any changes that were kept in memory, and releases the lock.
+    pub(crate) fn finish(mut self) -> TodoResult {
+        if self.unsaved && !self.dry_run {
+            self.save()?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads commands from the terminal and runs them against the list, which is
+/// loaded once and kept in memory. Each line is parsed into an application
+/// with |parse|, given the arguments after 'todo'. |commands| are the names
+/// of subcommands, for tab completion.
+///
+/// The list is locked until the shell exits. It is saved after every command
+/// that changes it, or only when the shell exits if 'shell_autosave' is off.
+/// With |dry_run|, nothing the commands do is saved.
+pub fn run_shell<A: Application>(
+    commands: &[String],
+    dry_run: bool,
+    parse: impl Fn(Vec<String>) -> Result<A, clap::Error>,
+) -> TodoResult {
+    let paths = paths()?;
+    let history_path = paths.data.with_file_name("shell_history.txt");
+    let (mut read_file, mut read_journal) = (String::new(), String::new());
+    let mut session =
+        Session::start(paths, &mut read_file, &mut read_journal, dry_run)?;
+
+    let mut editor = Editor::new()?;
+    editor.set_helper(Some(ShellHelper {
+        commands: commands.to_vec(),
+        descriptions: Vec::new(),
+    }));
+    // There is no history the first time the shell runs.
+    editor.load_history(&history_path).ok();
+
+    let result = loop {
+        if let Some(helper) = editor.helper_mut() {
+            helper.descriptions = descriptions(session.list());
+        }
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            // Ctrl-C discards the line, and Ctrl-D exits.
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break Ok(()),
+            Err(e) => break Err(e),
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str()).ok();
+        let Some(args) = split_line(&line) else {
+            eprintln!("Unbalanced quotes in {line:?}");
+            continue;
+        };
+        if matches!(args.as_slice(), [cmd] if cmd == "exit" || cmd == "quit") {
+            break Ok(());
+        }
+        let app = match parse(
+            std::iter::once("todo".to_string())
+                .chain(args.iter().cloned())
+                .collect(),
+        ) {
+            Ok(app) => app,
+            Err(e) => {
+                // This also prints the help for '--help'.
+                e.print().ok();
+                continue;
+            }
+        };
+        session.run(&args, app)?;
+    };
+    session.finish()?;
+    editor.save_history(&history_path).ok();
+    Ok(result?)
+}
@@ -0,0 +1,204 @@
+use std::path::Path;
+
+use todo_app::Application;
+use todo_backups::Backups;
+use todo_clock::Clock;
+use todo_config::ConfigModel;
+use todo_journal::Journal;
+use todo_model::NewOptions;
+use todo_model::TodoList;
+use todo_printing::PrintableAppSuccess;
+use todo_printing::PrintableResult;
+use todo_text_editing::TextEditor;
+
+use crate::shell::complete;
+use crate::shell::split_line;
+use crate::shell::Session;
+use crate::Paths;
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn split_line_with_quotes() {
+    assert_eq!(
+        split_line("new 'walk the dog' --priority 1"),
+        Some(strings(&["new", "walk the dog", "--priority", "1"]))
+    );
+}
+
+#[test]
+fn split_line_drops_leading_todo() {
+    assert_eq!(split_line("todo check 1"), Some(strings(&["check", "1"])));
+}
+
+#[test]
+fn split_line_unbalanced_quotes() {
+    assert_eq!(split_line("new \"a"), None);
+}
+
+#[test]
+fn complete_subcommand() {
+    let commands = strings(&["check", "config", "new"]);
+    assert_eq!(
+        complete("c", 1, &commands, &[]),
+        (0, strings(&["check", "config"]))
+    );
+}
+
+#[test]
+fn complete_subcommand_after_todo() {
+    let commands = strings(&["check", "new"]);
+    assert_eq!(
+        complete("todo ch", 7, &commands, &[]),
+        (5, strings(&["check"]))
+    );
+}
+
+#[test]
+fn complete_description_with_spaces() {
+    let descriptions = strings(&["walk the dog", "wash car"]);
+    assert_eq!(
+        complete("check wa", 8, &[], &descriptions),
+        (6, strings(&["'walk the dog'", "'wash car'"]))
+    );
+}
+
+#[test]
+fn complete_description_inside_quotes() {
+    let descriptions = strings(&["walk the dog", "wash car"]);
+    assert_eq!(
+        complete("check \"walk t", 13, &[], &descriptions),
+        (6, strings(&["'walk the dog'"]))
+    );
+}
+
+#[test]
+fn complete_nothing_for_flags() {
+    let descriptions = strings(&["-a"]);
+    assert_eq!(complete("new a -", 7, &[], &descriptions), (6, Vec::new()));
+}
+
+// Adds a task named "a", as 'todo new a' would.
+struct AddTask;
+
+impl Application for AddTask {
+    type Result<'a> = PrintableResult<'a>;
+
+    fn run<'a, 'ser>(
+        self,
+        list: &'a mut TodoList<'ser>,
+        _: &mut Journal<'ser>,
+        _: &mut ConfigModel,
+        _: &impl Backups,
+        _: &impl TextEditor,
+        _: &impl Clock,
+    ) -> PrintableResult<'a> {
+        list.add(NewOptions::new().desc("a"));
+        Ok(PrintableAppSuccess {
+            mutated: true,
+            ..Default::default()
+        })
+    }
+}
+
+// Undoes the most recent command, as 'todo undo' would.
+struct UndoLast {
+    dry_run: bool,
+}
+
+impl Application for UndoLast {
+    type Result<'a> = PrintableResult<'a>;
+
+    fn run<'a, 'ser>(
+        self,
+        list: &'a mut TodoList<'ser>,
+        journal: &mut Journal<'ser>,
+        _: &mut ConfigModel,
+        _: &impl Backups,
+        _: &impl TextEditor,
+        _: &impl Clock,
+    ) -> PrintableResult<'a> {
+        journal.undo(list);
+        Ok(PrintableAppSuccess {
+            mutated: true,
+            ..Default::default()
+        })
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+fn temp_paths(dir: &Path) -> Paths {
+    Paths {
+        config: dir.join("config.json"),
+        data: dir.join("data.json"),
+        journal: dir.join("journal.json"),
+    }
+}
+
+#[test]
+fn shell_saves_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut read_file, mut read_journal) = (String::new(), String::new());
+    let mut session = Session::start(
+        temp_paths(dir.path()),
+        &mut read_file,
+        &mut read_journal,
+        false,
+    )
+    .unwrap();
+    session.run(&strings(&["new", "a"]), AddTask).unwrap();
+    session.finish().unwrap();
+    assert!(dir.path().join("data.json").exists());
+}
+
+#[test]
+fn dry_run_shell_saves_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut read_file, mut read_journal) = (String::new(), String::new());
+    let mut session = Session::start(
+        temp_paths(dir.path()),
+        &mut read_file,
+        &mut read_journal,
+        true,
+    )
+    .unwrap();
+    session.run(&strings(&["new", "a"]), AddTask).unwrap();
+    // The task is kept in memory for the rest of the session.
+    assert_eq!(session.list().all_tasks().count(), 1);
+    session.finish().unwrap();
+    assert!(!dir.path().join("data.json").exists());
+    assert!(!dir.path().join("journal.json").exists());
+    assert!(!dir.path().join("config.json").exists());
+}
+
+#[test]
+fn dry_run_undo_leaves_journal_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let (mut read_file, mut read_journal) = (String::new(), String::new());
+    let mut session = Session::start(
+        temp_paths(dir.path()),
+        &mut read_file,
+        &mut read_journal,
+        true,
+    )
+    .unwrap();
+    session.run(&strings(&["new", "a"]), AddTask).unwrap();
+    session
+        .run(&strings(&["undo", "--dry-run"]), UndoLast { dry_run: true })
+        .unwrap();
+    assert_eq!(session.list().all_tasks().count(), 1);
+    // The next command is still recorded, so undoing it goes back to one
+    // task.
+    session.run(&strings(&["new", "a"]), AddTask).unwrap();
+    assert_eq!(session.list().all_tasks().count(), 2);
+    session
+        .run(&strings(&["undo"]), UndoLast { dry_run: false })
+        .unwrap();
+    assert_eq!(session.list().all_tasks().count(), 1);
+    session.finish().unwrap();
+}
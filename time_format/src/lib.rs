@@ -2,6 +2,10 @@ use chrono::DateTime;
 use chrono::Datelike;
 use chrono::TimeZone;
 
+mod recurrence;
+
+pub use self::recurrence::*;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ParseTimeError {
     LocalError(chrono::format::ParseError),
@@ -330,13 +334,20 @@ fn parse_year_month_day<'a, Tz: TimeZone>(
     }
 }
 
+/// Parses a duration like "90 min", "1h 30m", or "2 weeks".
+pub fn parse_duration(
+    s: &str,
+) -> Result<std::time::Duration, humantime::DurationError> {
+    humantime::parse_duration(s)
+}
+
 pub fn parse_time<Tz: TimeZone>(
     tz: Tz,
     now: DateTime<Tz>,
     s: &str,
     snap: Snap,
 ) -> Result<DateTime<Tz>, ParseTimeError> {
-    humantime::parse_duration(s)
+    parse_duration(s)
         .map(|duration: std::time::Duration| {
             let mut datetime = now.clone()
                 + chrono::Duration::milliseconds(duration.as_millis() as i64);
@@ -441,5 +452,8 @@ pub fn display_relative_time<Tz: TimeZone>(
     }
 }
 
+#[cfg(test)]
+mod recurrence_test;
+
 #[cfg(test)]
 mod test;
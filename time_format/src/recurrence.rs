@@ -0,0 +1,221 @@
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Days;
+use chrono::Months;
+use chrono::NaiveDate;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono::Weekday;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum RecurrenceUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// A rule describing when a recurring task should come back after it has been
+/// completed.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum Recurrence {
+    /// Every |n| days, weeks, months, or years.
+    Every(u32, RecurrenceUnit),
+    /// Every Monday through Friday.
+    Weekdays,
+    /// On the given day of every month. Months that are too short for the day
+    /// use their last day instead.
+    MonthlyOnDay(u32),
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    (28..=31)
+        .rev()
+        .find(|&day| NaiveDate::from_ymd_opt(year, month, day).is_some())
+        .unwrap()
+}
+
+fn with_day_clamped(date: NaiveDate, day: u32) -> NaiveDate {
+    let day = day.min(last_day_of_month(date.year(), date.month()));
+    date.with_day(day).unwrap()
+}
+
+impl Recurrence {
+    /// Returns the next occurrence after the given time. The time of day is
+    /// kept the same, in the timezone of the given time.
+    pub fn next_after<Tz: TimeZone>(
+        &self,
+        datetime: DateTime<Tz>,
+    ) -> DateTime<Tz> {
+        let local = datetime.naive_local();
+        let (date, time) = (local.date(), local.time());
+        // A count of zero would never move forward, so treat it as one.
+        let next_date = match *self {
+            Recurrence::Every(n, RecurrenceUnit::Day) => {
+                date + Days::new(n.max(1) as u64)
+            }
+            Recurrence::Every(n, RecurrenceUnit::Week) => {
+                date + Days::new(7 * n.max(1) as u64)
+            }
+            Recurrence::Every(n, RecurrenceUnit::Month) => {
+                date + Months::new(n.max(1))
+            }
+            Recurrence::Every(n, RecurrenceUnit::Year) => {
+                date + Months::new(12 * n.max(1))
+            }
+            Recurrence::Weekdays => date
+                .iter_days()
+                .skip(1)
+                .find(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+                .unwrap(),
+            Recurrence::MonthlyOnDay(day) => {
+                let this_month = with_day_clamped(date, day);
+                if this_month > date {
+                    this_month
+                } else {
+                    with_day_clamped(
+                        date.with_day(1).unwrap() + Months::new(1),
+                        day,
+                    )
+                }
+            }
+        };
+        let timezone = datetime.timezone();
+        timezone
+            .from_local_datetime(&next_date.and_time(time))
+            .earliest()
+            // The time of day doesn't exist on the next date because of a
+            // daylight saving transition; keep the same offset from UTC.
+            .unwrap_or_else(|| {
+                let offset = chrono::Duration::seconds(
+                    datetime.offset().fix().local_minus_utc() as i64,
+                );
+                timezone.from_utc_datetime(&(next_date.and_time(time) - offset))
+            })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseRecurrenceError {
+    Empty,
+    InvalidCount(std::num::ParseIntError),
+    CountIsZero,
+    InvalidUnit(String),
+    InvalidDayOfMonth(String),
+    UnexpectedWord(String),
+}
+
+fn parse_recurrence_unit(
+    s: &str,
+) -> Result<RecurrenceUnit, ParseRecurrenceError> {
+    match s {
+        "day" | "days" => Ok(RecurrenceUnit::Day),
+        "week" | "weeks" => Ok(RecurrenceUnit::Week),
+        "month" | "months" => Ok(RecurrenceUnit::Month),
+        "year" | "years" => Ok(RecurrenceUnit::Year),
+        _ => Err(ParseRecurrenceError::InvalidUnit(s.to_string())),
+    }
+}
+
+// Parses "1st", "2nd", "15th", or a plain number like "15".
+fn parse_day_of_month(s: &str) -> Result<u32, ParseRecurrenceError> {
+    let digits = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &s[digits.len()..];
+    match (digits.parse::<u32>(), suffix) {
+        (Ok(day @ 1..=31), "" | "st" | "nd" | "rd" | "th") => Ok(day),
+        _ => Err(ParseRecurrenceError::InvalidDayOfMonth(s.to_string())),
+    }
+}
+
+fn expect_end<'a>(
+    mut chunks: impl Iterator<Item = &'a str>,
+) -> Result<(), ParseRecurrenceError> {
+    match chunks.next() {
+        Some(chunk) => {
+            Err(ParseRecurrenceError::UnexpectedWord(chunk.to_string()))
+        }
+        None => Ok(()),
+    }
+}
+
+// Parses an optional "on the 1st" suffix for monthly recurrences.
+fn parse_monthly<'a>(
+    n: u32,
+    mut chunks: impl Iterator<Item = &'a str>,
+) -> Result<Recurrence, ParseRecurrenceError> {
+    match chunks.next() {
+        None => Ok(Recurrence::Every(n, RecurrenceUnit::Month)),
+        Some("on") if n == 1 => {
+            let day = match chunks.next() {
+                Some("the") => chunks.next(),
+                day => day,
+            };
+            let day = day.ok_or_else(|| {
+                ParseRecurrenceError::InvalidDayOfMonth(String::new())
+            })?;
+            let day = parse_day_of_month(day)?;
+            expect_end(chunks)?;
+            Ok(Recurrence::MonthlyOnDay(day))
+        }
+        Some(chunk) => {
+            Err(ParseRecurrenceError::UnexpectedWord(chunk.to_string()))
+        }
+    }
+}
+
+fn parse_every<'a>(
+    mut chunks: impl Iterator<Item = &'a str>,
+) -> Result<Recurrence, ParseRecurrenceError> {
+    let chunk = chunks.next().ok_or(ParseRecurrenceError::Empty)?;
+    if chunk == "weekday" {
+        expect_end(chunks)?;
+        return Ok(Recurrence::Weekdays);
+    }
+    let (n, unit) = match chunk.parse::<u32>() {
+        Ok(0) => return Err(ParseRecurrenceError::CountIsZero),
+        Ok(n) => (n, chunks.next().ok_or(ParseRecurrenceError::Empty)?),
+        Err(e) if chunk.starts_with(|c: char| c.is_ascii_digit()) => {
+            return Err(ParseRecurrenceError::InvalidCount(e))
+        }
+        Err(_) => (1, chunk),
+    };
+    match parse_recurrence_unit(unit)? {
+        RecurrenceUnit::Month => parse_monthly(n, chunks),
+        unit => {
+            expect_end(chunks)?;
+            Ok(Recurrence::Every(n, unit))
+        }
+    }
+}
+
+/// Parses a human-readable recurrence rule, like "every weekday", "every 2
+/// weeks", "daily", or "monthly on the 1st".
+pub fn parse_recurrence(s: &str) -> Result<Recurrence, ParseRecurrenceError> {
+    let lowercase = s.to_lowercase();
+    let mut chunks = lowercase.split_whitespace();
+    match chunks.next() {
+        None => Err(ParseRecurrenceError::Empty),
+        Some("every") => parse_every(chunks),
+        Some("weekdays") => {
+            expect_end(chunks)?;
+            Ok(Recurrence::Weekdays)
+        }
+        Some("monthly") => parse_monthly(1, chunks),
+        Some(chunk) => {
+            let unit = match chunk {
+                "daily" => RecurrenceUnit::Day,
+                "weekly" => RecurrenceUnit::Week,
+                "yearly" | "annually" => RecurrenceUnit::Year,
+                _ => {
+                    return Err(ParseRecurrenceError::UnexpectedWord(
+                        chunk.to_string(),
+                    ))
+                }
+            };
+            expect_end(chunks)?;
+            Ok(Recurrence::Every(1, unit))
+        }
+    }
+}
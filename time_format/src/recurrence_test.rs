@@ -0,0 +1,195 @@
+#![allow(clippy::zero_prefixed_literal)]
+
+use chrono::Local;
+use chrono::TimeZone;
+
+use super::*;
+
+#[test]
+fn every_day() {
+    let start = Local.with_ymd_and_hms(2024, 01, 31, 09, 30, 00).unwrap();
+    assert_eq!(
+        Recurrence::Every(1, RecurrenceUnit::Day).next_after(start),
+        Local.with_ymd_and_hms(2024, 02, 01, 09, 30, 00).unwrap()
+    );
+}
+
+#[test]
+fn every_two_weeks() {
+    let start = Local.with_ymd_and_hms(2024, 01, 05, 09, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Every(2, RecurrenceUnit::Week).next_after(start),
+        Local.with_ymd_and_hms(2024, 01, 19, 09, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn every_month_clamps_to_end_of_month() {
+    let start = Local.with_ymd_and_hms(2024, 01, 31, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Every(1, RecurrenceUnit::Month).next_after(start),
+        Local.with_ymd_and_hms(2024, 02, 29, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn every_year() {
+    let start = Local.with_ymd_and_hms(2023, 06, 15, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Every(1, RecurrenceUnit::Year).next_after(start),
+        Local.with_ymd_and_hms(2024, 06, 15, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn every_zero_days_still_moves_forward() {
+    let start = Local.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Every(0, RecurrenceUnit::Day).next_after(start),
+        Local.with_ymd_and_hms(2024, 01, 02, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn weekdays_from_midweek() {
+    // Wednesday
+    let start = Local.with_ymd_and_hms(2024, 01, 03, 08, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Weekdays.next_after(start),
+        Local.with_ymd_and_hms(2024, 01, 04, 08, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn weekdays_skips_weekend() {
+    // Friday
+    let start = Local.with_ymd_and_hms(2024, 01, 05, 08, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::Weekdays.next_after(start),
+        Local.with_ymd_and_hms(2024, 01, 08, 08, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn monthly_on_day_later_this_month() {
+    let start = Local.with_ymd_and_hms(2024, 01, 10, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::MonthlyOnDay(15).next_after(start),
+        Local.with_ymd_and_hms(2024, 01, 15, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn monthly_on_day_next_month() {
+    let start = Local.with_ymd_and_hms(2024, 01, 01, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::MonthlyOnDay(1).next_after(start),
+        Local.with_ymd_and_hms(2024, 02, 01, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn monthly_on_day_clamps_to_short_month() {
+    let start = Local.with_ymd_and_hms(2023, 01, 31, 00, 00, 00).unwrap();
+    assert_eq!(
+        Recurrence::MonthlyOnDay(31).next_after(start),
+        Local.with_ymd_and_hms(2023, 02, 28, 00, 00, 00).unwrap()
+    );
+}
+
+#[test]
+fn parse_recurrence_daily() {
+    assert_eq!(
+        parse_recurrence("daily"),
+        Ok(Recurrence::Every(1, RecurrenceUnit::Day))
+    );
+    assert_eq!(
+        parse_recurrence("every day"),
+        Ok(Recurrence::Every(1, RecurrenceUnit::Day))
+    );
+}
+
+#[test]
+fn parse_recurrence_every_two_weeks() {
+    assert_eq!(
+        parse_recurrence("every 2 weeks"),
+        Ok(Recurrence::Every(2, RecurrenceUnit::Week))
+    );
+}
+
+#[test]
+fn parse_recurrence_yearly() {
+    assert_eq!(
+        parse_recurrence("annually"),
+        Ok(Recurrence::Every(1, RecurrenceUnit::Year))
+    );
+    assert_eq!(
+        parse_recurrence("every 3 years"),
+        Ok(Recurrence::Every(3, RecurrenceUnit::Year))
+    );
+}
+
+#[test]
+fn parse_recurrence_every_weekday() {
+    assert_eq!(parse_recurrence("every weekday"), Ok(Recurrence::Weekdays));
+    assert_eq!(parse_recurrence("Weekdays"), Ok(Recurrence::Weekdays));
+}
+
+#[test]
+fn parse_recurrence_monthly() {
+    assert_eq!(
+        parse_recurrence("monthly"),
+        Ok(Recurrence::Every(1, RecurrenceUnit::Month))
+    );
+    assert_eq!(
+        parse_recurrence("every 6 months"),
+        Ok(Recurrence::Every(6, RecurrenceUnit::Month))
+    );
+}
+
+#[test]
+fn parse_recurrence_monthly_on_day() {
+    assert_eq!(
+        parse_recurrence("monthly on the 1st"),
+        Ok(Recurrence::MonthlyOnDay(1))
+    );
+    assert_eq!(
+        parse_recurrence("every month on the 22nd"),
+        Ok(Recurrence::MonthlyOnDay(22))
+    );
+    assert_eq!(
+        parse_recurrence("monthly on 15"),
+        Ok(Recurrence::MonthlyOnDay(15))
+    );
+}
+
+#[test]
+fn parse_recurrence_errors() {
+    assert_eq!(parse_recurrence(""), Err(ParseRecurrenceError::Empty));
+    assert_eq!(
+        parse_recurrence("every 0 days"),
+        Err(ParseRecurrenceError::CountIsZero)
+    );
+    assert_eq!(
+        parse_recurrence("every 2 fortnights"),
+        Err(ParseRecurrenceError::InvalidUnit("fortnights".to_string()))
+    );
+    assert_eq!(
+        parse_recurrence("monthly on the 32nd"),
+        Err(ParseRecurrenceError::InvalidDayOfMonth("32nd".to_string()))
+    );
+    assert_eq!(
+        parse_recurrence("every 2 months on the 1st"),
+        Err(ParseRecurrenceError::UnexpectedWord("on".to_string()))
+    );
+    assert_eq!(
+        parse_recurrence("daily please"),
+        Err(ParseRecurrenceError::UnexpectedWord("please".to_string()))
+    );
+    assert_eq!(
+        parse_recurrence("sometimes"),
+        Err(ParseRecurrenceError::UnexpectedWord(
+            "sometimes".to_string()
+        ))
+    );
+}
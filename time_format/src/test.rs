@@ -531,3 +531,20 @@ fn relative_time_in_11_months() {
     let actual = display_relative_time(now, then);
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn durations() {
+    assert_eq!(
+        parse_duration("90 min").unwrap(),
+        std::time::Duration::from_secs(90 * 60)
+    );
+    assert_eq!(
+        parse_duration("1h 30m").unwrap(),
+        std::time::Duration::from_secs(90 * 60)
+    );
+    assert_eq!(
+        parse_duration("2 weeks").unwrap(),
+        std::time::Duration::from_secs(14 * 24 * 60 * 60)
+    );
+    assert!(parse_duration("soon").is_err());
+}